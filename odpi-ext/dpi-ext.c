@@ -2,6 +2,7 @@
 #include "dpi-ext.h"
 
 #define OCI_ATTR_SQLFNCODE 10
+#define OCI_ATTR_PREFETCH_ROWS 11
 
 /* Copied from dpiConn.c */
 static int dpiConn__check(dpiConn *conn, const char *fnName, dpiError *error)
@@ -35,3 +36,30 @@ int dpi_ext_dpiConn_getServerStatus(dpiConn *conn, uint32_t *server_status)
     return dpiGen__endPublicFn(conn, status, &error);
 }
 
+
+int dpi_ext_dpiStmt_setPrefetchRows(dpiStmt *stmt, uint32_t num_rows)
+{
+    dpiError error;
+    int status;
+
+    if (dpiGen__startPublicFn(stmt, DPI_HTYPE_STMT, __func__, &error) < 0)
+        return dpiGen__endPublicFn(stmt, DPI_FAILURE, &error);
+    status = dpiOci__attrSet(stmt->handle, DPI_OCI_HTYPE_STMT, &num_rows, 0,
+            OCI_ATTR_PREFETCH_ROWS, "set prefetch rows", &error);
+    return dpiGen__endPublicFn(stmt, status, &error);
+}
+
+/*
+ * Removes the statement from the statement cache when it is closed or
+ * released. ODPI-C sets the same flag by itself when a statement fails
+ * to execute and then releases the statement with OCI_STRLS_CACHE_DELETE.
+ */
+int dpi_ext_dpiStmt_deleteFromCache(dpiStmt *stmt)
+{
+    dpiError error;
+
+    if (dpiGen__startPublicFn(stmt, DPI_HTYPE_STMT, __func__, &error) < 0)
+        return dpiGen__endPublicFn(stmt, DPI_FAILURE, &error);
+    stmt->deleteFromCache = 1;
+    return dpiGen__endPublicFn(stmt, DPI_SUCCESS, &error);
+}
@@ -9,5 +9,7 @@
 
 int dpi_ext_dpiStmt_getFnCode(dpiStmt *stmt, uint16_t *sqlfncode);
 int dpi_ext_dpiConn_getServerStatus(dpiConn *conn, uint32_t *status);
+int dpi_ext_dpiStmt_setPrefetchRows(dpiStmt *stmt, uint32_t num_rows);
+int dpi_ext_dpiStmt_deleteFromCache(dpiStmt *stmt);
 
 #endif
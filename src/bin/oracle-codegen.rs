@@ -0,0 +1,130 @@
+// Rust-oracle - Rust binding for Oracle database
+//
+// URL: https://github.com/kubo/rust-oracle
+//
+//-----------------------------------------------------------------------------
+// Copyright (c) 2017-2018 Kubo Takehiro <kubo@jiubao.org>. All rights reserved.
+// This program is free software: you can modify it and/or redistribute it
+// under the terms of:
+//
+// (i)  the Universal Permissive License v 1.0 or at your option, any
+//      later version (http://oss.oracle.com/licenses/upl); and/or
+//
+// (ii) the Apache License v 2.0. (http://www.apache.org/licenses/LICENSE-2.0)
+//-----------------------------------------------------------------------------
+
+extern crate oracle;
+
+use std::env;
+use std::io;
+use std::io::BufRead;
+use std::process;
+
+use oracle::codegen::StructDef;
+use oracle::Connection;
+
+const USAGE: &str = "\
+Usage: oracle-codegen USERNAME CONNECT_STRING TARGET...
+
+Prints Rust structs corresponding to Oracle object types or query columns.
+
+The password is read from the ORACLE_PASSWORD environment variable, or from
+the first line of the standard input when the variable isn't set.
+
+TARGET:
+  --type TYPE_NAME     a struct implementing FromSql and ToSql for an object type
+  --query NAME SQL     a struct named NAME implementing RowValue for rows of SQL
+";
+
+enum Target {
+    Type(String),
+    Query(String, String),
+}
+
+fn usage_error(msg: &str) -> ! {
+    eprintln!("{}\n\n{}", msg, USAGE);
+    process::exit(2);
+}
+
+// The password isn't taken from the command line, where other users
+// can see it in the process list.
+fn read_password() -> io::Result<String> {
+    if let Ok(password) = env::var("ORACLE_PASSWORD") {
+        return Ok(password);
+    }
+    let stdin = io::stdin();
+    let mut line = String::new();
+    stdin.lock().read_line(&mut line)?;
+    Ok(line
+        .trim_end_matches(|c| c == '\r' || c == '\n')
+        .to_string())
+}
+
+fn main() {
+    let args: Vec<String> = env::args().skip(1).collect();
+    if args.iter().any(|arg| arg == "-h" || arg == "--help") {
+        print!("{}", USAGE);
+        return;
+    }
+    if args.len() < 2 {
+        usage_error("USERNAME and CONNECT_STRING are required.");
+    }
+    let mut targets = Vec::new();
+    let mut rest = args[2..].iter();
+    while let Some(arg) = rest.next() {
+        match arg.as_str() {
+            "--type" => match rest.next() {
+                Some(name) => targets.push(Target::Type(name.clone())),
+                None => usage_error("--type requires TYPE_NAME."),
+            },
+            "--query" => match (rest.next(), rest.next()) {
+                (Some(name), Some(sql)) => targets.push(Target::Query(name.clone(), sql.clone())),
+                _ => usage_error("--query requires NAME and SQL."),
+            },
+            _ => usage_error(&format!("unknown argument: {}", arg)),
+        }
+    }
+    if targets.is_empty() {
+        usage_error("No TARGET is specified.");
+    }
+
+    let password = match read_password() {
+        Ok(password) => password,
+        Err(err) => {
+            eprintln!("Failed to read the password: {}", err);
+            process::exit(1);
+        }
+    };
+    let conn = match Connection::connect(&args[0], &password, &args[1]) {
+        Ok(conn) => conn,
+        Err(err) => {
+            eprintln!("Failed to connect: {}", err);
+            process::exit(1);
+        }
+    };
+    for (idx, target) in targets.iter().enumerate() {
+        let res = match *target {
+            Target::Type(ref name) => conn
+                .object_type(name)
+                .and_then(|objtype| StructDef::from_object_type(&objtype)),
+            Target::Query(ref name, ref sql) => StructDef::from_query(&conn, name, sql),
+        };
+        match res {
+            Ok(def) => {
+                if idx != 0 {
+                    println!();
+                }
+                print!("{}", def.to_rust_code());
+            }
+            Err(err) => {
+                match *target {
+                    Target::Type(ref name) => eprintln!("Failed to read type {}: {}", name, err),
+                    Target::Query(ref name, _) => {
+                        eprintln!("Failed to read query {}: {}", name, err)
+                    }
+                }
+                process::exit(1);
+            }
+        }
+    }
+}
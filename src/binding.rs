@@ -4843,3 +4843,12 @@ extern "C" {
         status: *mut u32,
     ) -> ::std::os::raw::c_int;
 }
+extern "C" {
+    pub fn dpi_ext_dpiStmt_setPrefetchRows(
+        stmt: *mut dpiStmt,
+        num_rows: u32,
+    ) -> ::std::os::raw::c_int;
+}
+extern "C" {
+    pub fn dpi_ext_dpiStmt_deleteFromCache(stmt: *mut dpiStmt) -> ::std::os::raw::c_int;
+}
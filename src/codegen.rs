@@ -0,0 +1,607 @@
+// Rust-oracle - Rust binding for Oracle database
+//
+// URL: https://github.com/kubo/rust-oracle
+//
+//-----------------------------------------------------------------------------
+// Copyright (c) 2017-2018 Kubo Takehiro <kubo@jiubao.org>. All rights reserved.
+// This program is free software: you can modify it and/or redistribute it
+// under the terms of:
+//
+// (i)  the Universal Permissive License v 1.0 or at your option, any
+//      later version (http://oss.oracle.com/licenses/upl); and/or
+//
+// (ii) the Apache License v 2.0. (http://www.apache.org/licenses/LICENSE-2.0)
+//-----------------------------------------------------------------------------
+
+//! Generates Rust source code from Oracle object types and query columns
+//!
+//! [StructDef][] describes a Rust struct corresponding to an Oracle object type
+//! or to rows of a query. [StructDef.to_rust_code()][] returns the source code
+//! of the struct and its trait implementations:
+//!
+//! * [FromSql][], [ToSql][] and [ToSqlNull][] for object types.
+//! * [RowValue][] for queries.
+//!
+//! The `oracle-codegen` command in this crate is a thin wrapper of this module.
+//!
+//! ```no_run
+//! # use oracle::*; use oracle::codegen::StructDef; fn try_main() -> Result<()> {
+//! let conn = Connection::connect("scott", "tiger", "")?;
+//!
+//! let objtype = conn.object_type("MDSYS.SDO_POINT_TYPE")?;
+//! println!("{}", StructDef::from_object_type(&objtype)?.to_rust_code());
+//!
+//! let def = StructDef::from_query(&conn, "Emp", "select * from emp")?;
+//! println!("{}", def.to_rust_code());
+//! # Ok(())} fn main() { try_main().unwrap(); }
+//! ```
+//!
+//! The first `println!` prints the following code.
+//!
+//! ```ignore
+//! /// Rust type for MDSYS.SDO_POINT_TYPE
+//! #[derive(Clone, Debug, PartialEq)]
+//! pub struct SdoPointType {
+//!     pub x: Option<oracle::sql_type::OracleNumber>,
+//!     pub y: Option<oracle::sql_type::OracleNumber>,
+//!     pub z: Option<oracle::sql_type::OracleNumber>,
+//! }
+//!
+//! impl oracle::sql_type::FromSql for SdoPointType {
+//!     ...
+//! ```
+//!
+//! [StructDef]: struct.StructDef.html
+//! [StructDef.to_rust_code()]: struct.StructDef.html#method.to_rust_code
+//! [FromSql]: ../sql_type/trait.FromSql.html
+//! [ToSql]: ../sql_type/trait.ToSql.html
+//! [ToSqlNull]: ../sql_type/trait.ToSqlNull.html
+//! [RowValue]: ../trait.RowValue.html
+
+use std::fmt::Write;
+
+use crate::sql_type::ObjectType;
+use crate::sql_type::OracleType;
+use crate::ColumnInfo;
+use crate::Connection;
+use crate::Error;
+use crate::Result;
+
+// Rust keywords which cannot be used as field names without `r#`.
+const KEYWORDS: &[&str] = &[
+    "as", "async", "await", "break", "const", "continue", "crate", "dyn", "else", "enum", "extern",
+    "false", "fn", "for", "if", "impl", "in", "let", "loop", "match", "mod", "move", "mut", "pub",
+    "ref", "return", "static", "struct", "trait", "true", "type", "unsafe", "use", "where",
+    "while", "abstract", "become", "box", "do", "final", "macro", "override", "priv", "try",
+    "typeof", "unsized", "virtual", "yield",
+];
+
+/// Where the fields of a [StructDef][] come from
+///
+/// [StructDef]: struct.StructDef.html
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum StructSource {
+    /// An object type. The value is the type name such as `SCHEMA.TYPE_NAME`.
+    ObjectType(String),
+    /// Query columns. The value is the SQL statement if it is known.
+    Query(Option<String>),
+}
+
+/// A field of a [StructDef][]
+///
+/// [StructDef]: struct.StructDef.html
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct FieldDef {
+    name: String,
+    oracle_name: String,
+    rust_type: String,
+    nullable: bool,
+}
+
+impl FieldDef {
+    /// Gets the Rust field name
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// Gets the attribute or column name in Oracle
+    pub fn oracle_name(&self) -> &str {
+        &self.oracle_name
+    }
+
+    /// Gets the Rust type without `Option`
+    pub fn rust_type(&self) -> &str {
+        &self.rust_type
+    }
+
+    /// True when the field type is `Option<...>`
+    pub fn nullable(&self) -> bool {
+        self.nullable
+    }
+}
+
+/// Definition of a Rust struct generated from an object type or query columns
+///
+/// See [the module-level documentation](index.html).
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct StructDef {
+    name: String,
+    source: StructSource,
+    fields: Vec<FieldDef>,
+}
+
+impl StructDef {
+    /// Creates a struct definition from an object type.
+    ///
+    /// The struct name is the type name in camel case. All fields are `Option`
+    /// because attributes of object types may be null.
+    /// `Err(Error::InvalidOperation(...))` is returned for collection types.
+    pub fn from_object_type(objtype: &ObjectType) -> Result<StructDef> {
+        if objtype.is_collection() {
+            return Err(Error::InvalidOperation(format!(
+                "{} is a collection type, which is mapped to Vec<T> or Collection",
+                objtype
+            )));
+        }
+        let fields = objtype
+            .attributes()
+            .iter()
+            .map(|attr| {
+                Ok(FieldDef {
+                    name: field_name(attr.name()),
+                    oracle_name: attr.name().to_string(),
+                    rust_type: rust_type_name(attr.oracle_type())?,
+                    nullable: true,
+                })
+            })
+            .collect::<Result<Vec<_>>>()?;
+        Ok(StructDef {
+            name: struct_name(objtype.name()),
+            source: StructSource::ObjectType(objtype.to_string()),
+            fields: dedup_field_names(fields),
+        })
+    }
+
+    /// Creates a struct definition named `name` from columns of a query.
+    ///
+    /// The query is executed without bind parameters to get the column
+    /// information. Add a condition such as `where 1 = 0` when it would
+    /// fetch too many rows. Use [from_column_info][] for queries with
+    /// bind parameters.
+    ///
+    /// [from_column_info]: #method.from_column_info
+    pub fn from_query(conn: &Connection, name: &str, sql: &str) -> Result<StructDef> {
+        let mut stmt = conn.prepare(sql, &[])?;
+        let rows = stmt.query(&[])?;
+        let mut def = StructDef::from_column_info(name, rows.column_info())?;
+        def.source = StructSource::Query(Some(sql.to_string()));
+        Ok(def)
+    }
+
+    /// Creates a struct definition named `name` from column information.
+    ///
+    /// Fields of columns defined as `NOT NULL` aren't `Option`.
+    /// When column names map to the same field name, such as `ID` of two
+    /// joined tables, `_2`, `_3` and so on are appended to the later ones.
+    pub fn from_column_info(name: &str, column_info: &[ColumnInfo]) -> Result<StructDef> {
+        let fields = column_info
+            .iter()
+            .map(|info| {
+                Ok(FieldDef {
+                    name: field_name(info.name()),
+                    oracle_name: info.name().to_string(),
+                    rust_type: rust_type_name(info.oracle_type())?,
+                    nullable: info.nullable(),
+                })
+            })
+            .collect::<Result<Vec<_>>>()?;
+        Ok(StructDef {
+            name: name.to_string(),
+            source: StructSource::Query(None),
+            fields: dedup_field_names(fields),
+        })
+    }
+
+    /// Gets the struct name
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// Gets where the fields come from
+    pub fn source(&self) -> &StructSource {
+        &self.source
+    }
+
+    /// Gets the fields
+    pub fn fields(&self) -> &[FieldDef] {
+        &self.fields
+    }
+
+    /// Returns Rust source code of the struct and its trait implementations.
+    pub fn to_rust_code(&self) -> String {
+        let mut code = String::new();
+        // Writing to a String never fails.
+        let _ = self.write_rust_code(&mut code);
+        code
+    }
+
+    fn write_rust_code(&self, code: &mut String) -> std::fmt::Result {
+        match self.source {
+            StructSource::ObjectType(ref type_name) => {
+                writeln!(code, "/// Rust type for {}", type_name)?
+            }
+            StructSource::Query(Some(ref sql)) => {
+                writeln!(code, "/// A row returned by `{}`", sql.replace('\n', " "))?
+            }
+            StructSource::Query(None) => writeln!(code, "/// A row of a query")?,
+        }
+        writeln!(code, "#[derive(Clone, Debug, PartialEq)]")?;
+        writeln!(code, "pub struct {} {{", self.name)?;
+        for field in &self.fields {
+            if field.nullable {
+                writeln!(code, "    pub {}: Option<{}>,", field.name, field.rust_type)?;
+            } else {
+                writeln!(code, "    pub {}: {},", field.name, field.rust_type)?;
+            }
+        }
+        writeln!(code, "}}")?;
+        match self.source {
+            StructSource::ObjectType(ref type_name) => self.write_object_impls(code, type_name),
+            StructSource::Query(_) => self.write_row_value_impl(code),
+        }
+    }
+
+    fn write_object_impls(&self, code: &mut String, type_name: &str) -> std::fmt::Result {
+        writeln!(code)?;
+        writeln!(code, "impl oracle::sql_type::FromSql for {} {{", self.name)?;
+        writeln!(
+            code,
+            "    fn from_sql(val: &oracle::SqlValue) -> oracle::Result<{}> {{",
+            self.name
+        )?;
+        writeln!(
+            code,
+            "        let obj: oracle::sql_type::Object = val.get()?;"
+        )?;
+        writeln!(code, "        Ok({} {{", self.name)?;
+        for field in &self.fields {
+            writeln!(
+                code,
+                "            {}: obj.get({:?})?,",
+                field.name, field.oracle_name
+            )?;
+        }
+        writeln!(code, "        }})")?;
+        writeln!(code, "    }}")?;
+        writeln!(code, "}}")?;
+        writeln!(code)?;
+        writeln!(
+            code,
+            "impl oracle::sql_type::ToSqlNull for {} {{",
+            self.name
+        )?;
+        writeln!(
+            code,
+            "    fn oratype_for_null(conn: &oracle::Connection) -> oracle::Result<oracle::sql_type::OracleType> {{"
+        )?;
+        writeln!(
+            code,
+            "        Ok(oracle::sql_type::OracleType::Object(conn.object_type({:?})?))",
+            type_name
+        )?;
+        writeln!(code, "    }}")?;
+        writeln!(code, "}}")?;
+        writeln!(code)?;
+        writeln!(code, "impl oracle::sql_type::ToSql for {} {{", self.name)?;
+        writeln!(
+            code,
+            "    fn oratype(&self, conn: &oracle::Connection) -> oracle::Result<oracle::sql_type::OracleType> {{"
+        )?;
+        writeln!(
+            code,
+            "        <{} as oracle::sql_type::ToSqlNull>::oratype_for_null(conn)",
+            self.name
+        )?;
+        writeln!(code, "    }}")?;
+        writeln!(
+            code,
+            "    fn to_sql(&self, val: &mut oracle::SqlValue) -> oracle::Result<()> {{"
+        )?;
+        writeln!(code, "        let mut obj = match *val.oracle_type()? {{")?;
+        writeln!(
+            code,
+            "            oracle::sql_type::OracleType::Object(ref objtype) => objtype.new_object()?,"
+        )?;
+        writeln!(code, "            ref oratype => {{")?;
+        writeln!(
+            code,
+            "                return Err(oracle::Error::InvalidTypeConversion({:?}.to_string(), oratype.to_string()))",
+            self.name
+        )?;
+        writeln!(code, "            }}")?;
+        writeln!(code, "        }};")?;
+        for field in &self.fields {
+            writeln!(
+                code,
+                "        if let Some(ref value) = self.{} {{",
+                field.name
+            )?;
+            writeln!(
+                code,
+                "            obj.set({:?}, value)?;",
+                field.oracle_name
+            )?;
+            writeln!(code, "        }}")?;
+        }
+        writeln!(code, "        val.set(&obj)")?;
+        writeln!(code, "    }}")?;
+        writeln!(code, "}}")
+    }
+
+    fn write_row_value_impl(&self, code: &mut String) -> std::fmt::Result {
+        writeln!(code)?;
+        writeln!(code, "impl oracle::RowValue for {} {{", self.name)?;
+        writeln!(
+            code,
+            "    fn get(row: &oracle::Row) -> oracle::Result<{}> {{",
+            self.name
+        )?;
+        writeln!(code, "        Ok({} {{", self.name)?;
+        for (idx, field) in self.fields.iter().enumerate() {
+            writeln!(code, "            {}: row.get({})?,", field.name, idx)?;
+        }
+        writeln!(code, "        }})")?;
+        writeln!(code, "    }}")?;
+        writeln!(code, "}}")
+    }
+}
+
+/// Returns the Rust type name used for values of `oratype`.
+///
+/// | Oracle Type | Rust Type |
+/// | --- | --- |
+/// | character data types, rowid, clob, nclob, long and xmltype | String |
+/// | raw, blob, bfile and long raw | Vec\<u8> |
+/// | number(p, 0) where p is 1 to 4 | i16 |
+/// | number(p, 0) where p is 5 to 9 | i32 |
+/// | number(p, 0) where p is 10 to 18 | i64 |
+/// | number(p, 0) where p is 19 to 38 and number(*, 0) | i128 |
+/// | other numbers | oracle::sql_type::OracleNumber |
+/// | float and binary_double | f64 |
+/// | binary_float | f32 |
+/// | date and timestamp data types | oracle::sql_type::Timestamp |
+/// | interval day to second | oracle::sql_type::IntervalDS |
+/// | interval year to month | oracle::sql_type::IntervalYM |
+/// | boolean | bool |
+/// | object types | oracle::sql_type::Object |
+/// | collection types | oracle::sql_type::Collection |
+/// | json | oracle::sql_type::JsonValue |
+///
+/// `Err(Error::InvalidOperation(...))` is returned for ref cursors.
+pub fn rust_type_name(oratype: &OracleType) -> Result<String> {
+    let name = match *oratype {
+        OracleType::Varchar2(_)
+        | OracleType::NVarchar2(_)
+        | OracleType::Char(_)
+        | OracleType::NChar(_)
+        | OracleType::Rowid
+        | OracleType::CLOB
+        | OracleType::NCLOB
+        | OracleType::Long
+        | OracleType::Xml => "String",
+        OracleType::Raw(_) | OracleType::BLOB | OracleType::BFILE | OracleType::LongRaw => {
+            "Vec<u8>"
+        }
+        OracleType::Number(prec, 0) if 1 <= prec && prec <= 4 => "i16",
+        OracleType::Number(prec, 0) if 5 <= prec && prec <= 9 => "i32",
+        OracleType::Number(prec, 0) if 10 <= prec && prec <= 18 => "i64",
+        // number(*, 0) has 38 digits.
+        OracleType::Number(prec, 0) if prec == 0 || (19 <= prec && prec <= 38) => "i128",
+        // Scaled numbers and numbers without precision lose digits as f64.
+        OracleType::Number(_, _) => "oracle::sql_type::OracleNumber",
+        OracleType::Float(_) | OracleType::BinaryDouble => "f64",
+        OracleType::BinaryFloat => "f32",
+        OracleType::Int64 => "i64",
+        OracleType::UInt64 => "u64",
+        OracleType::Date
+        | OracleType::Timestamp(_)
+        | OracleType::TimestampTZ(_)
+        | OracleType::TimestampLTZ(_) => "oracle::sql_type::Timestamp",
+        OracleType::IntervalDS(_, _) => "oracle::sql_type::IntervalDS",
+        OracleType::IntervalYM(_) => "oracle::sql_type::IntervalYM",
+        OracleType::Boolean => "bool",
+        OracleType::Object(ref objtype) => {
+            if objtype.is_collection() {
+                "oracle::sql_type::Collection"
+            } else {
+                "oracle::sql_type::Object"
+            }
+        }
+        OracleType::Json => "oracle::sql_type::JsonValue",
+        OracleType::RefCursor => {
+            return Err(Error::InvalidOperation(format!(
+                "no Rust type is available for {}",
+                oratype
+            )))
+        }
+    };
+    Ok(name.to_string())
+}
+
+// Appends `_2`, `_3` and so on to field names which are already used
+// by preceding fields.
+fn dedup_field_names(mut fields: Vec<FieldDef>) -> Vec<FieldDef> {
+    for idx in 1..fields.len() {
+        let (preceding, rest) = fields.split_at_mut(idx);
+        let field = &mut rest[0];
+        if !preceding.iter().any(|f| f.name == field.name) {
+            continue;
+        }
+        let base = field.name.trim_start_matches("r#").to_string();
+        let mut n = 2;
+        loop {
+            let name = format!("{}_{}", base, n);
+            if !preceding.iter().any(|f| f.name == name) {
+                field.name = name;
+                break;
+            }
+            n += 1;
+        }
+    }
+    fields
+}
+
+// Converts an Oracle name such as `UDT_SUBOBJECT` to a struct name such as `UdtSubobject`.
+fn struct_name(name: &str) -> String {
+    let mut result = String::with_capacity(name.len());
+    let mut upper = true;
+    for chr in name.chars() {
+        if chr.is_alphanumeric() {
+            if upper {
+                result.extend(chr.to_uppercase());
+            } else {
+                result.extend(chr.to_lowercase());
+            }
+            upper = false;
+        } else {
+            upper = true;
+        }
+    }
+    if result.is_empty() || result.starts_with(|c: char| c.is_numeric()) {
+        result.insert(0, 'T');
+    }
+    result
+}
+
+// Converts an Oracle name such as `SUBNUMBERVALUE` or `COUNT(*)` to a field name
+// such as `subnumbervalue` or `count`.
+fn field_name(name: &str) -> String {
+    let mut result = String::with_capacity(name.len());
+    for chr in name.chars() {
+        if chr.is_alphanumeric() {
+            result.extend(chr.to_lowercase());
+        } else if !result.is_empty() && !result.ends_with('_') {
+            result.push('_');
+        }
+    }
+    while result.ends_with('_') {
+        result.pop();
+    }
+    if result.is_empty() || result.starts_with(|c: char| c.is_numeric()) {
+        result.insert(0, '_');
+    }
+    if KEYWORDS.contains(&result.as_str()) {
+        result.insert_str(0, "r#");
+    }
+    result
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn names() {
+        assert_eq!(struct_name("UDT_SUBOBJECT"), "UdtSubobject");
+        assert_eq!(struct_name("SDO_POINT_TYPE"), "SdoPointType");
+        assert_eq!(struct_name("2D_POINT"), "T2dPoint");
+        assert_eq!(field_name("SUBNUMBERVALUE"), "subnumbervalue");
+        assert_eq!(field_name("COUNT(*)"), "count");
+        assert_eq!(field_name("TYPE"), "r#type");
+        assert_eq!(field_name("1ST_VALUE"), "_1st_value");
+    }
+
+    #[test]
+    fn rust_types() {
+        assert_eq!(rust_type_name(&OracleType::Varchar2(10)).unwrap(), "String");
+        assert_eq!(rust_type_name(&OracleType::Number(4, 0)).unwrap(), "i16");
+        assert_eq!(rust_type_name(&OracleType::Number(9, 0)).unwrap(), "i32");
+        assert_eq!(rust_type_name(&OracleType::Number(18, 0)).unwrap(), "i64");
+        assert_eq!(rust_type_name(&OracleType::Number(38, 0)).unwrap(), "i128");
+        assert_eq!(rust_type_name(&OracleType::Number(0, 0)).unwrap(), "i128");
+        assert_eq!(
+            rust_type_name(&OracleType::Number(0, -127)).unwrap(),
+            "oracle::sql_type::OracleNumber"
+        );
+        assert_eq!(
+            rust_type_name(&OracleType::Number(9, 2)).unwrap(),
+            "oracle::sql_type::OracleNumber"
+        );
+        assert_eq!(rust_type_name(&OracleType::Float(126)).unwrap(), "f64");
+        assert_eq!(
+            rust_type_name(&OracleType::Date).unwrap(),
+            "oracle::sql_type::Timestamp"
+        );
+        assert!(rust_type_name(&OracleType::RefCursor).is_err());
+    }
+
+    #[test]
+    fn row_value_code() {
+        let def = StructDef {
+            name: "Emp".to_string(),
+            source: StructSource::Query(Some("select empno, ename from emp".to_string())),
+            fields: vec![
+                FieldDef {
+                    name: "empno".to_string(),
+                    oracle_name: "EMPNO".to_string(),
+                    rust_type: "i16".to_string(),
+                    nullable: false,
+                },
+                FieldDef {
+                    name: "ename".to_string(),
+                    oracle_name: "ENAME".to_string(),
+                    rust_type: "String".to_string(),
+                    nullable: true,
+                },
+            ],
+        };
+        // tests/codegen.rs compiles the expected code.
+        assert_eq!(def.to_rust_code(), include_str!("../tests/codegen/emp.rs"));
+    }
+
+    #[test]
+    fn object_code() {
+        let def = StructDef {
+            name: "UdtSubobject".to_string(),
+            source: StructSource::ObjectType("UDT_SUBOBJECT".to_string()),
+            fields: vec![
+                FieldDef {
+                    name: "subnumbervalue".to_string(),
+                    oracle_name: "SUBNUMBERVALUE".to_string(),
+                    rust_type: "oracle::sql_type::OracleNumber".to_string(),
+                    nullable: true,
+                },
+                FieldDef {
+                    name: "substringvalue".to_string(),
+                    oracle_name: "SUBSTRINGVALUE".to_string(),
+                    rust_type: "String".to_string(),
+                    nullable: true,
+                },
+            ],
+        };
+        // tests/codegen.rs compiles the expected code.
+        assert_eq!(
+            def.to_rust_code(),
+            include_str!("../tests/codegen/udt_subobject.rs")
+        );
+    }
+
+    #[test]
+    fn duplicate_field_names() {
+        let field = |name: &str| FieldDef {
+            name: name.to_string(),
+            oracle_name: name.to_uppercase(),
+            rust_type: "i32".to_string(),
+            nullable: false,
+        };
+        let fields = dedup_field_names(vec![
+            field("id"),
+            field("id"),
+            field("id_2"),
+            field("r#type"),
+            field("r#type"),
+        ]);
+        let names = fields.iter().map(|f| f.name()).collect::<Vec<_>>();
+        assert_eq!(names, ["id", "id_2", "id_2_2", "r#type", "type_2"]);
+    }
+}
@@ -0,0 +1,218 @@
+// Rust-oracle - Rust binding for Oracle database
+//
+// URL: https://github.com/kubo/rust-oracle
+//
+//-----------------------------------------------------------------------------
+// Copyright (c) 2017-2018 Kubo Takehiro <kubo@jiubao.org>. All rights reserved.
+// This program is free software: you can modify it and/or redistribute it
+// under the terms of:
+//
+// (i)  the Universal Permissive License v 1.0 or at your option, any
+//      later version (http://oss.oracle.com/licenses/upl); and/or
+//
+// (ii) the Apache License v 2.0. (http://www.apache.org/licenses/LICENSE-2.0)
+//-----------------------------------------------------------------------------
+
+use std::sync::Arc;
+
+use arrow::array::ArrayRef;
+use arrow::array::BinaryArray;
+use arrow::array::BooleanArray;
+use arrow::array::Float32Array;
+use arrow::array::Float64Array;
+use arrow::array::Int64Array;
+use arrow::array::IntervalMonthDayNanoArray;
+use arrow::array::IntervalYearMonthArray;
+use arrow::array::StringArray;
+use arrow::array::TimestampMicrosecondArray;
+use arrow::array::TimestampNanosecondArray;
+use arrow::array::UInt64Array;
+use arrow::datatypes::Field;
+use arrow::datatypes::IntervalMonthDayNano;
+use arrow::datatypes::Schema;
+use arrow::record_batch::RecordBatch;
+
+use crate::columnar::ColumnData;
+use crate::columnar::ColumnarBatch;
+use crate::sql_type::OracleType;
+use crate::sql_type::Timestamp;
+use crate::Error;
+use crate::Result;
+
+const UTC: &str = "+00:00";
+
+// Returns the number of days since 1970-01-01 in the proleptic Gregorian calendar.
+fn days_from_civil(year: i32, month: u32, day: u32) -> i64 {
+    let y = if month <= 2 { year - 1 } else { year } as i64;
+    let era = if y >= 0 { y } else { y - 399 } / 400;
+    let yoe = y - era * 400;
+    let m = month as i64;
+    let doy = (153 * (if m > 2 { m - 3 } else { m + 9 }) + 2) / 5 + day as i64 - 1;
+    let doe = yoe * 365 + yoe / 4 - yoe / 100 + doy;
+    era * 146097 + doe - 719468
+}
+
+// Returns seconds since the Unix epoch. Timestamps with time zone are converted to UTC.
+fn epoch_seconds(ts: &Timestamp) -> i64 {
+    let days = days_from_civil(ts.year(), ts.month(), ts.day());
+    let secs =
+        days * 86400 + ts.hour() as i64 * 3600 + ts.minute() as i64 * 60 + ts.second() as i64;
+    if ts.with_tz() {
+        secs - ts.tz_offset() as i64
+    } else {
+        secs
+    }
+}
+
+fn epoch_nanoseconds(ts: &Timestamp) -> Result<i64> {
+    epoch_seconds(ts)
+        .checked_mul(1_000_000_000)
+        .and_then(|nsecs| nsecs.checked_add(ts.nanosecond() as i64))
+        .ok_or_else(|| {
+            Error::OutOfRange(format!(
+                "{} cannot be represented as nanoseconds since the Unix epoch",
+                ts
+            ))
+        })
+}
+
+fn epoch_microseconds(ts: &Timestamp) -> i64 {
+    epoch_seconds(ts) * 1_000_000 + (ts.nanosecond() / 1000) as i64
+}
+
+fn timestamp_array(vec: &[Option<Timestamp>], oratype: &OracleType) -> Result<ArrayRef> {
+    let (prec, with_tz) = match *oratype {
+        OracleType::Timestamp(prec) => (prec, false),
+        OracleType::TimestampTZ(prec) | OracleType::TimestampLTZ(prec) => (prec, true),
+        _ => (0, false),
+    };
+    if prec > 6 {
+        let mut values = Vec::with_capacity(vec.len());
+        for ts in vec {
+            values.push(match *ts {
+                Some(ref ts) => Some(epoch_nanoseconds(ts)?),
+                None => None,
+            });
+        }
+        let array = TimestampNanosecondArray::from(values);
+        Ok(Arc::new(if with_tz {
+            array.with_timezone(UTC)
+        } else {
+            array
+        }))
+    } else {
+        let values: Vec<_> = vec
+            .iter()
+            .map(|ts| ts.as_ref().map(epoch_microseconds))
+            .collect();
+        let array = TimestampMicrosecondArray::from(values);
+        Ok(Arc::new(if with_tz {
+            array.with_timezone(UTC)
+        } else {
+            array
+        }))
+    }
+}
+
+fn column_array(column: &ColumnData, oratype: &OracleType) -> Result<ArrayRef> {
+    Ok(match *column {
+        ColumnData::Int64(ref vec) => Arc::new(Int64Array::from(vec.clone())),
+        ColumnData::UInt64(ref vec) => Arc::new(UInt64Array::from(vec.clone())),
+        ColumnData::Float(ref vec) => Arc::new(Float32Array::from(vec.clone())),
+        ColumnData::Double(ref vec) => Arc::new(Float64Array::from(vec.clone())),
+        ColumnData::String(ref vec) => {
+            Arc::new(vec.iter().map(Option::as_ref).collect::<StringArray>())
+        }
+        ColumnData::Bytes(ref vec) => {
+            Arc::new(vec.iter().map(Option::as_ref).collect::<BinaryArray>())
+        }
+        ColumnData::Timestamp(ref vec) => timestamp_array(vec, oratype)?,
+        ColumnData::IntervalDS(ref vec) => {
+            let values: Vec<_> = vec
+                .iter()
+                .map(|it| {
+                    it.as_ref().map(|it| {
+                        let nsecs = it.hours() as i64 * 3_600_000_000_000
+                            + it.minutes() as i64 * 60_000_000_000
+                            + it.seconds() as i64 * 1_000_000_000
+                            + it.nanoseconds() as i64;
+                        IntervalMonthDayNano::new(0, it.days(), nsecs)
+                    })
+                })
+                .collect();
+            Arc::new(IntervalMonthDayNanoArray::from(values))
+        }
+        ColumnData::IntervalYM(ref vec) => {
+            let values: Vec<_> = vec
+                .iter()
+                .map(|it| it.as_ref().map(|it| it.years() * 12 + it.months()))
+                .collect();
+            Arc::new(IntervalYearMonthArray::from(values))
+        }
+        ColumnData::Boolean(ref vec) => Arc::new(BooleanArray::from(vec.clone())),
+    })
+}
+
+impl ColumnarBatch {
+    /// Converts the batch to an Arrow [RecordBatch][].
+    ///
+    /// This method is available when `arrow` feature is enabled.
+    ///
+    /// Column values are converted as follows.
+    ///
+    /// | ColumnData | Arrow data type |
+    /// | --- | --- |
+    /// | `Int64` | `Int64` |
+    /// | `UInt64` | `UInt64` |
+    /// | `Float` | `Float32` |
+    /// | `Double` | `Float64` |
+    /// | `String` | `Utf8` |
+    /// | `Bytes` | `Binary` |
+    /// | `Timestamp` | `Timestamp(Microsecond)` or `Timestamp(Nanosecond)` when the fractional second precision is greater than 6 |
+    /// | `IntervalDS` | `Interval(MonthDayNano)` |
+    /// | `IntervalYM` | `Interval(YearMonth)` |
+    /// | `Boolean` | `Boolean` |
+    ///
+    /// Timestamps with time zone are converted to UTC and their time zone
+    /// is set to `+00:00`. Timestamps with nanosecond precision out of the
+    /// range of `i64` cause `Err(Error::OutOfRange(...))`.
+    ///
+    /// [RecordBatch]: https://docs.rs/arrow/57/arrow/record_batch/struct.RecordBatch.html
+    pub fn to_record_batch(&self) -> Result<RecordBatch> {
+        let mut fields = Vec::with_capacity(self.columns.len());
+        let mut arrays = Vec::with_capacity(self.columns.len());
+        for (info, column) in self.column_info.iter().zip(&self.columns) {
+            let array = column_array(column, info.oracle_type())?;
+            fields.push(Field::new(
+                info.name(),
+                array.data_type().clone(),
+                info.nullable(),
+            ));
+            arrays.push(array);
+        }
+        RecordBatch::try_new(Arc::new(Schema::new(fields)), arrays)
+            .map_err(|err| Error::InternalError(err.to_string()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_days_from_civil() {
+        assert_eq!(days_from_civil(1970, 1, 1), 0);
+        assert_eq!(days_from_civil(1969, 12, 31), -1);
+        assert_eq!(days_from_civil(2000, 3, 1), 11017);
+        assert_eq!(days_from_civil(2019, 8, 23), 18131);
+        assert_eq!(days_from_civil(1, 1, 1), -719162);
+    }
+
+    #[test]
+    fn test_epoch_seconds() {
+        let ts = Timestamp::new(2019, 8, 23, 12, 34, 56, 0);
+        assert_eq!(epoch_seconds(&ts), 1566563696);
+        let ts = ts.and_tz_hm_offset(9, 0);
+        assert_eq!(epoch_seconds(&ts), 1566563696 - 9 * 3600);
+    }
+}
@@ -0,0 +1,282 @@
+// Rust-oracle - Rust binding for Oracle database
+//
+// URL: https://github.com/kubo/rust-oracle
+//
+//-----------------------------------------------------------------------------
+// Copyright (c) 2017-2018 Kubo Takehiro <kubo@jiubao.org>. All rights reserved.
+// This program is free software: you can modify it and/or redistribute it
+// under the terms of:
+//
+// (i)  the Universal Permissive License v 1.0 or at your option, any
+//      later version (http://oss.oracle.com/licenses/upl); and/or
+//
+// (ii) the Apache License v 2.0. (http://www.apache.org/licenses/LICENSE-2.0)
+//-----------------------------------------------------------------------------
+
+#[cfg(feature = "arrow")]
+mod arrow;
+
+use std::sync::atomic::AtomicUsize;
+use std::sync::atomic::Ordering;
+
+use crate::binding::*;
+use crate::sql_type::IntervalDS;
+use crate::sql_type::IntervalYM;
+use crate::sql_type::NativeType;
+use crate::sql_type::Timestamp;
+use crate::to_rust_slice;
+use crate::to_rust_str;
+use crate::ColumnIndex;
+use crate::ColumnInfo;
+use crate::Error;
+use crate::Result;
+use crate::SqlValue;
+
+/// Column values fetched by [Statement.fetch_columnar][]
+///
+/// The variant is determined by the column type.
+///
+/// | Oracle type | variant |
+/// | --- | --- |
+/// | `NUMBER(p, 0)` where 0 < p < 18 | `Int64` |
+/// | other `NUMBER`, `FLOAT` | `String` |
+/// | `BINARY_FLOAT` | `Float` |
+/// | `BINARY_DOUBLE` | `Double` |
+/// | `CHAR`, `NCHAR`, `VARCHAR2`, `NVARCHAR2`, `LONG`, `CLOB`, `NCLOB` | `String` |
+/// | `RAW`, `LONG RAW`, `BLOB`, `BFILE` | `Bytes` |
+/// | `DATE`, `TIMESTAMP`, `TIMESTAMP WITH TIME ZONE`, `TIMESTAMP WITH LOCAL TIME ZONE` | `Timestamp` |
+/// | `INTERVAL DAY TO SECOND` | `IntervalDS` |
+/// | `INTERVAL YEAR TO MONTH` | `IntervalYM` |
+/// | `BOOLEAN` | `Boolean` |
+///
+/// [Statement.fetch_columnar]: struct.Statement.html#method.fetch_columnar
+#[derive(Debug, Clone, PartialEq)]
+pub enum ColumnData {
+    /// 64-bit signed integers
+    Int64(Vec<Option<i64>>),
+    /// 64-bit unsigned integers
+    UInt64(Vec<Option<u64>>),
+    /// 32-bit floating point numbers
+    Float(Vec<Option<f32>>),
+    /// 64-bit floating point numbers
+    Double(Vec<Option<f64>>),
+    /// Strings
+    String(Vec<Option<String>>),
+    /// Binary data
+    Bytes(Vec<Option<Vec<u8>>>),
+    /// Date and time
+    Timestamp(Vec<Option<Timestamp>>),
+    /// Intervals of days, hours, minutes and seconds
+    IntervalDS(Vec<Option<IntervalDS>>),
+    /// Intervals of years and months
+    IntervalYM(Vec<Option<IntervalYM>>),
+    /// Booleans
+    Boolean(Vec<Option<bool>>),
+}
+
+impl ColumnData {
+    fn new(info: &ColumnInfo, val: &SqlValue, capacity: usize) -> Result<ColumnData> {
+        Ok(match *val.native_type() {
+            NativeType::Int64 => ColumnData::Int64(Vec::with_capacity(capacity)),
+            NativeType::UInt64 => ColumnData::UInt64(Vec::with_capacity(capacity)),
+            NativeType::Float => ColumnData::Float(Vec::with_capacity(capacity)),
+            NativeType::Double => ColumnData::Double(Vec::with_capacity(capacity)),
+            NativeType::Char | NativeType::Number | NativeType::CLOB => {
+                ColumnData::String(Vec::with_capacity(capacity))
+            }
+            NativeType::Raw | NativeType::BLOB => ColumnData::Bytes(Vec::with_capacity(capacity)),
+            NativeType::Timestamp => ColumnData::Timestamp(Vec::with_capacity(capacity)),
+            NativeType::IntervalDS => ColumnData::IntervalDS(Vec::with_capacity(capacity)),
+            NativeType::IntervalYM => ColumnData::IntervalYM(Vec::with_capacity(capacity)),
+            NativeType::Boolean => ColumnData::Boolean(Vec::with_capacity(capacity)),
+            NativeType::Object(_) | NativeType::Stmt | NativeType::Rowid => {
+                return Err(Error::InvalidOperation(format!(
+                    "Could not fetch column {} of type {} into columnar buffers",
+                    info.name(),
+                    info.oracle_type()
+                )));
+            }
+        })
+    }
+
+    // Appends `num_rows` values from `start` in the fetch buffer of `val`.
+    //
+    // Values are read from dpiData directly except LOBs, which need
+    // round-trips to read them in any case.
+    fn extend(
+        &mut self,
+        val: &SqlValue,
+        start: u32,
+        num_rows: u32,
+        buffer_row_index: &AtomicUsize,
+    ) -> Result<()> {
+        let rows = start..(start + num_rows);
+        macro_rules! extend_with {
+            ($vec:expr, |$data:ident| $expr:expr) => {
+                $vec.extend(rows.map(|i| unsafe {
+                    let $data = val.data_at(i);
+                    if (*$data).isNull != 0 {
+                        None
+                    } else {
+                        Some($expr)
+                    }
+                }))
+            };
+        }
+        match *self {
+            ColumnData::Int64(ref mut vec) => extend_with!(vec, |data| dpiData_getInt64(data)),
+            ColumnData::UInt64(ref mut vec) => extend_with!(vec, |data| dpiData_getUint64(data)),
+            ColumnData::Float(ref mut vec) => extend_with!(vec, |data| dpiData_getFloat(data)),
+            ColumnData::Double(ref mut vec) => extend_with!(vec, |data| dpiData_getDouble(data)),
+            ColumnData::String(ref mut vec) => match *val.native_type() {
+                NativeType::Char | NativeType::Number => extend_with!(vec, |data| {
+                    let bytes = dpiData_getBytes(data);
+                    to_rust_str((*bytes).ptr, (*bytes).length)
+                }),
+                _ => {
+                    for i in rows {
+                        buffer_row_index.store(i as usize, Ordering::Relaxed);
+                        vec.push(val.get()?);
+                    }
+                }
+            },
+            ColumnData::Bytes(ref mut vec) => match *val.native_type() {
+                NativeType::Raw => extend_with!(vec, |data| {
+                    let bytes = dpiData_getBytes(data);
+                    to_rust_slice((*bytes).ptr, (*bytes).length).to_vec()
+                }),
+                _ => {
+                    for i in rows {
+                        buffer_row_index.store(i as usize, Ordering::Relaxed);
+                        vec.push(val.get()?);
+                    }
+                }
+            },
+            ColumnData::Timestamp(ref mut vec) => {
+                let oratype = val.oracle_type()?;
+                extend_with!(vec, |data| Timestamp::from_dpi_timestamp(
+                    &*dpiData_getTimestamp(data),
+                    oratype
+                ))
+            }
+            ColumnData::IntervalDS(ref mut vec) => {
+                let oratype = val.oracle_type()?;
+                extend_with!(vec, |data| IntervalDS::from_dpi_interval_ds(
+                    &*dpiData_getIntervalDS(data),
+                    oratype
+                ))
+            }
+            ColumnData::IntervalYM(ref mut vec) => {
+                let oratype = val.oracle_type()?;
+                extend_with!(vec, |data| IntervalYM::from_dpi_interval_ym(
+                    &*dpiData_getIntervalYM(data),
+                    oratype
+                ))
+            }
+            ColumnData::Boolean(ref mut vec) => {
+                extend_with!(vec, |data| dpiData_getBool(data) != 0)
+            }
+        }
+        Ok(())
+    }
+
+    /// Returns the number of values in the column.
+    pub fn len(&self) -> usize {
+        match *self {
+            ColumnData::Int64(ref vec) => vec.len(),
+            ColumnData::UInt64(ref vec) => vec.len(),
+            ColumnData::Float(ref vec) => vec.len(),
+            ColumnData::Double(ref vec) => vec.len(),
+            ColumnData::String(ref vec) => vec.len(),
+            ColumnData::Bytes(ref vec) => vec.len(),
+            ColumnData::Timestamp(ref vec) => vec.len(),
+            ColumnData::IntervalDS(ref vec) => vec.len(),
+            ColumnData::IntervalYM(ref vec) => vec.len(),
+            ColumnData::Boolean(ref vec) => vec.len(),
+        }
+    }
+
+    /// Returns `true` when the column has no values.
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+}
+
+/// A set of rows fetched by [Statement.fetch_columnar][] and stored column by column
+///
+/// [Statement.fetch_columnar]: struct.Statement.html#method.fetch_columnar
+#[derive(Debug, Clone)]
+pub struct ColumnarBatch {
+    column_info: Vec<ColumnInfo>,
+    column_names: Vec<String>,
+    columns: Vec<ColumnData>,
+    num_rows: usize,
+}
+
+impl ColumnarBatch {
+    pub(crate) fn new(
+        column_info: &[ColumnInfo],
+        column_values: &[SqlValue],
+        capacity: usize,
+    ) -> Result<ColumnarBatch> {
+        let mut columns = Vec::with_capacity(column_info.len());
+        for (info, val) in column_info.iter().zip(column_values) {
+            columns.push(ColumnData::new(info, val, capacity)?);
+        }
+        Ok(ColumnarBatch {
+            column_info: column_info.to_vec(),
+            column_names: column_info
+                .iter()
+                .map(|info| info.name().to_string())
+                .collect(),
+            columns,
+            num_rows: 0,
+        })
+    }
+
+    // Appends `num_rows` rows from `start` in the fetch buffers.
+    // `buffer_row_index` is the index shared by `column_values`.
+    pub(crate) fn extend(
+        &mut self,
+        column_values: &[SqlValue],
+        start: u32,
+        num_rows: u32,
+        buffer_row_index: &AtomicUsize,
+    ) -> Result<()> {
+        for (column, val) in self.columns.iter_mut().zip(column_values) {
+            column.extend(val, start, num_rows, buffer_row_index)?;
+        }
+        self.num_rows += num_rows as usize;
+        Ok(())
+    }
+
+    /// Returns the number of rows.
+    pub fn num_rows(&self) -> usize {
+        self.num_rows
+    }
+
+    /// Returns column information.
+    pub fn column_info(&self) -> &[ColumnInfo] {
+        &self.column_info
+    }
+
+    /// Returns all columns.
+    pub fn columns(&self) -> &[ColumnData] {
+        &self.columns
+    }
+
+    /// Returns the column at the specified index. The index is
+    /// a zero-based position or a case-insensitive column name.
+    pub fn column<I>(&self, colidx: I) -> Result<&ColumnData>
+    where
+        I: ColumnIndex,
+    {
+        let pos = colidx.idx(&self.column_names)?;
+        Ok(&self.columns[pos])
+    }
+
+    /// Consumes the batch and returns the columns.
+    pub fn into_columns(self) -> Vec<ColumnData> {
+        self.columns
+    }
+}
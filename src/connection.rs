@@ -13,11 +13,11 @@
 // (ii) the Apache License v 2.0. (http://www.apache.org/licenses/LICENSE-2.0)
 //-----------------------------------------------------------------------------
 
-use std::collections::HashMap;
 use std::fmt;
 use std::mem;
 use std::ptr;
-use std::sync::Arc;
+use std::sync::atomic::AtomicBool;
+use std::sync::atomic::Ordering;
 use std::sync::Mutex;
 use std::time::Duration;
 
@@ -25,8 +25,8 @@ use crate::binding::*;
 use crate::chkerr;
 use crate::error::error_from_dpi_error;
 use crate::new_odpi_str;
+use crate::object_type_cache::CachedObjectTypes;
 use crate::sql_type::ObjectType;
-use crate::sql_type::ObjectTypeInternal;
 use crate::sql_type::ToSql;
 use crate::to_odpi_str;
 use crate::to_rust_slice;
@@ -38,6 +38,7 @@ use crate::Context;
 use crate::DpiConn;
 use crate::DpiObjectType;
 use crate::Error;
+use crate::ObjectTypeCache;
 use crate::Result;
 use crate::ResultSet;
 use crate::Row;
@@ -166,6 +167,7 @@ pub struct Connector {
     events: bool,
     edition: String,
     driver_name: String,
+    object_type_cache: Option<ObjectTypeCache>,
 }
 
 impl Connector {
@@ -192,6 +194,7 @@ impl Connector {
             events: false,
             edition: "".into(),
             driver_name: "".into(),
+            object_type_cache: None,
         }
     }
 
@@ -374,6 +377,17 @@ impl Connector {
         self
     }
 
+    /// Sets settings of the object type cache shared by connections
+    /// established by this connector.
+    ///
+    /// See [ObjectTypeCache][].
+    ///
+    /// [ObjectTypeCache]: struct.ObjectTypeCache.html
+    pub fn object_type_cache(&mut self, cache: ObjectTypeCache) -> &mut Connector {
+        self.object_type_cache = Some(cache);
+        self
+    }
+
     /// Connect an Oracle server using specified parameters
     pub fn connect(&self) -> Result<Connection> {
         let ctxt = Context::get()?;
@@ -442,28 +456,43 @@ impl Connector {
         let s = to_odpi_str(&self.driver_name);
         common_params.driverName = s.ptr;
         common_params.driverNameLength = s.len;
-        Connection::connect_internal(
+        let mut conn = Connection::connect_internal(
             &self.username,
             &self.password,
             &self.connect_string,
             Some(common_params),
             Some(conn_params),
-        )
+        )?;
+        if let Some(ref cache) = self.object_type_cache {
+            conn.objtype_cache_settings = cache.clone();
+        }
+        Ok(conn)
     }
 }
 
 /// Connection to an Oracle database
+///
+/// A connection is `Send` and `Sync`. It may be moved to another thread
+/// or shared by threads via `Arc<Connection>`. ODPI-C serializes calls
+/// on one connection internally, so concurrent calls from several threads
+/// don't break it but are executed one by one.
 pub struct Connection {
     pub(crate) ctxt: &'static Context,
     pub(crate) handle: DpiConn,
     tag: String,
     tag_found: bool,
     pub(crate) autocommit: bool,
-    pub(crate) objtype_cache: Mutex<HashMap<String, Arc<ObjectTypeInternal>>>,
+    pub(crate) fetch_array_size: u32,
+    pub(crate) prefetch_rows: Option<u32>,
+    boolean_bind_supported: AtomicBool,
+    objtype_cache: Mutex<CachedObjectTypes>,
+    objtype_cache_settings: ObjectTypeCache,
 }
 
 impl AssertSync for Context {}
 impl AssertSend for Context {}
+impl AssertSync for Connection {}
+impl AssertSend for Connection {}
 
 impl Connection {
     /// Connects to an Oracle server using username, password and connect string.
@@ -540,7 +569,11 @@ impl Connection {
             tag: to_rust_str(conn_params.outTag, conn_params.outTagLength),
             tag_found: conn_params.outTagFound != 0,
             autocommit: false,
-            objtype_cache: Mutex::new(HashMap::new()),
+            fetch_array_size: DPI_DEFAULT_FETCH_ARRAY_SIZE,
+            prefetch_rows: None,
+            boolean_bind_supported: AtomicBool::new(false),
+            objtype_cache: Mutex::new(CachedObjectTypes::new()),
+            objtype_cache_settings: ObjectTypeCache::new(),
         })
     }
 
@@ -684,7 +717,7 @@ impl Connection {
     ///
     /// [Query Methods]: https://github.com/kubo/rust-oracle/blob/master/docs/query-methods.md
     pub fn query_row(&self, sql: &str, params: &[&dyn ToSql]) -> Result<Row> {
-        let mut stmt = self.prepare(sql, &[StmtParam::FetchArraySize(1)])?;
+        let mut stmt = self.prepare(sql, self.query_row_params())?;
         if let Err(err) = stmt.query_row(params) {
             return Err(err);
         };
@@ -697,7 +730,7 @@ impl Connection {
     ///
     /// [Query Methods]: https://github.com/kubo/rust-oracle/blob/master/docs/query-methods.md
     pub fn query_row_named(&self, sql: &str, params: &[(&str, &dyn ToSql)]) -> Result<Row> {
-        let mut stmt = self.prepare(sql, &[StmtParam::FetchArraySize(1)])?;
+        let mut stmt = self.prepare(sql, self.query_row_params())?;
         if let Err(err) = stmt.query_row_named(params) {
             return Err(err);
         };
@@ -713,7 +746,7 @@ impl Connection {
     where
         T: RowValue,
     {
-        let mut stmt = self.prepare(sql, &[StmtParam::FetchArraySize(1)])?;
+        let mut stmt = self.prepare(sql, self.query_row_params())?;
         stmt.query_row_as::<T>(params)
     }
 
@@ -726,10 +759,24 @@ impl Connection {
     where
         T: RowValue,
     {
-        let mut stmt = self.prepare(sql, &[StmtParam::FetchArraySize(1)])?;
+        let mut stmt = self.prepare(sql, self.query_row_params())?;
         stmt.query_row_as_named::<T>(params)
     }
 
+    // Parameters to prepare statements in query_row methods.
+    // The row is prefetched on execution to save a round trip unless
+    // the number of prefetch rows is set to the connection.
+    fn query_row_params(&self) -> &'static [StmtParam] {
+        const WITH_PREFETCH: &[StmtParam] =
+            &[StmtParam::FetchArraySize(1), StmtParam::PrefetchRows(2)];
+        const WITHOUT_PREFETCH: &[StmtParam] = &[StmtParam::FetchArraySize(1)];
+        if self.prefetch_rows.is_some() {
+            WITHOUT_PREFETCH
+        } else {
+            WITH_PREFETCH
+        }
+    }
+
     /// Prepares a statement, binds values by position and executes it in one call.
     /// It will retunrs `Err` when the statemnet is a select statement.
     ///
@@ -801,6 +848,39 @@ impl Connection {
         self.autocommit = autocommit;
     }
 
+    /// Gets the default fetch array size of statements prepared by the connection.
+    /// It is 100 by default.
+    ///
+    /// See [StmtParam::FetchArraySize](enum.StmtParam.html#variant.FetchArraySize).
+    pub fn fetch_array_size(&self) -> u32 {
+        self.fetch_array_size
+    }
+
+    /// Sets the default fetch array size of statements prepared by the connection.
+    /// It is overridden by [StmtParam::FetchArraySize](enum.StmtParam.html#variant.FetchArraySize).
+    pub fn set_fetch_array_size(&mut self, size: u32) {
+        self.fetch_array_size = size;
+    }
+
+    /// Gets the default number of prefetch rows of statements prepared by the connection.
+    /// It is `None` by default, which means the default value of the Oracle
+    /// client library.
+    ///
+    /// See [StmtParam::PrefetchRows](enum.StmtParam.html#variant.PrefetchRows).
+    pub fn prefetch_rows(&self) -> Option<u32> {
+        self.prefetch_rows
+    }
+
+    /// Sets the default number of prefetch rows of statements prepared by the connection.
+    /// `None` resets it to the default value of the Oracle client library.
+    /// It is overridden by [StmtParam::PrefetchRows](enum.StmtParam.html#variant.PrefetchRows).
+    ///
+    /// It is also used by `query_row` methods, which otherwise
+    /// prefetch two rows.
+    pub fn set_prefetch_rows(&mut self, rows: Option<u32>) {
+        self.prefetch_rows = rows;
+    }
+
     /// Cancels execution of running statements in the connection
     pub fn break_execution(&self) -> Result<()> {
         chkerr!(self.ctxt, dpiConn_breakExecution(self.handle.raw()));
@@ -816,15 +896,23 @@ impl Connection {
     /// # Ok(())} fn main() { try_main().unwrap(); }
     /// ```
     ///
+    /// PL/SQL types declared in packages are specified as `PACKAGE.TYPE`
+    /// or `SCHEMA.PACKAGE.TYPE`. They are supported by Oracle client and
+    /// server 12.1 or later.
+    ///
     /// Note that the object type is cached in the connection.
     /// However when "CREATE TYPE", "ALTER TYPE" or "DROP TYPE"
-    /// is executed, the cache clears.
+    /// is executed, the cache clears and cached object types in
+    /// other connections sharing [ObjectTypeCache][] are invalidated.
+    /// See [ObjectTypeCache][] also to control the cache.
+    ///
+    /// [ObjectTypeCache]: struct.ObjectTypeCache.html
     pub fn object_type(&self, name: &str) -> Result<ObjectType> {
         {
-            let guard = self.objtype_cache.lock()?;
-            if let Some(rc_objtype) = guard.get(name) {
+            let mut guard = self.objtype_cache.lock()?;
+            if let Some(rc_objtype) = guard.get(name, &self.objtype_cache_settings) {
                 return Ok(ObjectType {
-                    internal: rc_objtype.clone(),
+                    internal: rc_objtype,
                 });
             }
         }
@@ -834,11 +922,13 @@ impl Connection {
             self.ctxt,
             dpiConn_getObjectType(self.handle.raw(), s.ptr, s.len, &mut handle)
         );
-        let res = ObjectType::from_dpi_object_type(self.ctxt, DpiObjectType::new(handle));
+        let res = ObjectType::from_dpi_object_type_by_name(self, DpiObjectType::new(handle), name);
         if let Ok(ref objtype) = res {
-            self.objtype_cache
-                .lock()?
-                .insert(name.to_string(), objtype.internal.clone());
+            self.objtype_cache.lock()?.insert(
+                name,
+                objtype.internal.clone(),
+                &self.objtype_cache_settings,
+            );
         };
         res
     }
@@ -851,6 +941,14 @@ impl Connection {
         Ok(())
     }
 
+    /// Gets settings of the object type cache.
+    ///
+    /// It is shared with other connections when it is set by
+    /// [Connector.object_type_cache](struct.Connector.html#method.object_type_cache).
+    pub fn object_type_cache(&self) -> &ObjectTypeCache {
+        &self.objtype_cache_settings
+    }
+
     #[doc(hide)]
     pub fn object_type_cache_len(&self) -> usize {
         self.objtype_cache.lock().unwrap().len()
@@ -885,6 +983,29 @@ impl Connection {
         Ok((Version::new_from_dpi_ver(dpi_ver), s.to_string()))
     }
 
+    // PL/SQL boolean binds are supported by Oracle 12.1 or later
+    // on both the client and server sides. The versions are checked
+    // until the first check passes.
+    pub(crate) fn check_boolean_support(&self) -> Result<()> {
+        if self.boolean_bind_supported.load(Ordering::Relaxed) {
+            return Ok(());
+        }
+        let min_ver = Version::new(12, 1, 0, 0, 0);
+        let client_ver = Version::client()?;
+        let (server_ver, _) = self.server_version()?;
+        if client_ver < min_ver || server_ver < min_ver {
+            Err(Error::InvalidOperation(format!(
+                "PL/SQL BOOLEAN binds require Oracle client and server 12.1 or later \
+                 (client: {}, server: {}). Declare the parameter as NUMBER(1) \
+                 and bind (&bool, &OracleType::Number(1, 0)) instead.",
+                client_ver, server_ver
+            )))
+        } else {
+            self.boolean_bind_supported.store(true, Ordering::Relaxed);
+            Ok(())
+        }
+    }
+
     /// Changes the password for the specified user
     pub fn change_password(
         &self,
@@ -917,8 +1038,7 @@ impl Connection {
     ///
     /// See also [Connection.status](struct.Connection.html#method.status).
     pub fn ping(&self) -> Result<()> {
-        chkerr!(self.ctxt, dpiConn_ping(self.handle.raw()));
-        Ok(())
+        conn_ping(self.ctxt, &self.handle)
     }
 
     /// Gets the status of the connection.
@@ -948,27 +1068,19 @@ impl Connection {
     ///
     /// See also [Connection.ping](struct.Connection.html#method.ping).
     pub fn status(&self) -> Result<ConnStatus> {
-        unsafe {
-            let mut status = 0;
-            if dpi_ext_dpiConn_getServerStatus(self.handle.raw(), &mut status) == 0 {
-                match status {
-                    DPI_OCI_SERVER_NOT_CONNECTED => Ok(ConnStatus::NotConnected),
-                    DPI_OCI_SERVER_NORMAL => Ok(ConnStatus::Normal),
-                    _ => Err(Error::InternalError(format!(
-                        "Unexpected server status: {}",
-                        status
-                    ))),
-                }
-            } else {
-                let mut err: dpiErrorInfo = Default::default();
-                dpiContext_getError(self.ctxt.context, &mut err);
-                let message = to_rust_slice(err.message, err.messageLength);
-                if message == b"DPI-1010: not connected" {
-                    Ok(ConnStatus::Closed)
-                } else {
-                    Err(error_from_dpi_error(&err))
-                }
-            }
+        conn_status(self.ctxt, &self.handle)
+    }
+
+    // Returns a closure which pings the connection and gets its status.
+    // It doesn't borrow `self` so that connection pools for async runtimes
+    // can run it on a thread where blocking calls are allowed.
+    #[cfg(feature = "deadpool")]
+    pub(crate) fn health_check(&self) -> impl FnOnce() -> Result<ConnStatus> + Send + 'static {
+        let ctxt = self.ctxt;
+        let handle = self.handle.clone();
+        move || {
+            conn_ping(ctxt, &handle)?;
+            conn_status(ctxt, &handle)
         }
     }
 
@@ -1374,3 +1486,33 @@ impl fmt::Debug for Connection {
         write!(f, ", autocommit: {:?} }}", self.autocommit)
     }
 }
+
+fn conn_ping(ctxt: &Context, handle: &DpiConn) -> Result<()> {
+    chkerr!(ctxt, dpiConn_ping(handle.raw()));
+    Ok(())
+}
+
+fn conn_status(ctxt: &Context, handle: &DpiConn) -> Result<ConnStatus> {
+    unsafe {
+        let mut status = 0;
+        if dpi_ext_dpiConn_getServerStatus(handle.raw(), &mut status) == 0 {
+            match status {
+                DPI_OCI_SERVER_NOT_CONNECTED => Ok(ConnStatus::NotConnected),
+                DPI_OCI_SERVER_NORMAL => Ok(ConnStatus::Normal),
+                _ => Err(Error::InternalError(format!(
+                    "Unexpected server status: {}",
+                    status
+                ))),
+            }
+        } else {
+            let mut err: dpiErrorInfo = Default::default();
+            dpiContext_getError(ctxt.context, &mut err);
+            let message = to_rust_slice(err.message, err.messageLength);
+            if message == b"DPI-1010: not connected" {
+                Ok(ConnStatus::Closed)
+            } else {
+                Err(error_from_dpi_error(&err))
+            }
+        }
+    }
+}
@@ -0,0 +1,144 @@
+// Rust-oracle - Rust binding for Oracle database
+//
+// URL: https://github.com/kubo/rust-oracle
+//
+//-----------------------------------------------------------------------------
+// Copyright (c) 2017-2018 Kubo Takehiro <kubo@jiubao.org>. All rights reserved.
+// This program is free software: you can modify it and/or redistribute it
+// under the terms of:
+//
+// (i)  the Universal Permissive License v 1.0 or at your option, any
+//      later version (http://oss.oracle.com/licenses/upl); and/or
+//
+// (ii) the Apache License v 2.0. (http://www.apache.org/licenses/LICENSE-2.0)
+//-----------------------------------------------------------------------------
+
+//! [deadpool](https://docs.rs/deadpool/0.12/deadpool/) connection manager
+//!
+//! This module is available when `deadpool` feature is enabled.
+//!
+//! Note that rust-oracle's API is blocking. When a runtime is set by
+//! [Manager.runtime][], [Manager][] establishes and checks connections
+//! via the runtime's `spawn_blocking`. The runtime is available when
+//! deadpool's `rt_tokio_1` or `rt_async-std_1` feature is enabled.
+//! Otherwise they are done on the task calling the pool. Use
+//! `spawn_blocking` or something similar provided by your async runtime
+//! to run queries on pooled connections.
+//!
+//! ```no_run
+//! # use oracle::*; async fn try_main() -> std::result::Result<(), Box<dyn std::error::Error>> {
+//! use oracle::deadpool::{Manager, Pool, Runtime};
+//!
+//! let mut manager = Manager::new(Connector::new("scott", "tiger", "//localhost/XE"));
+//! manager.runtime(Runtime::Tokio1);
+//! let pool = Pool::builder(manager).max_size(4).build()?;
+//!
+//! let conn = pool.get().await?;
+//! let row = conn.query_row_as::<String>("select ename from emp where empno = :1", &[&7369])?;
+//! # Ok(())} fn main() {}
+//! ```
+//!
+//! [Manager]: struct.Manager.html
+//! [Manager.runtime]: struct.Manager.html#method.runtime
+
+use deadpool::managed;
+use deadpool::managed::Metrics;
+use deadpool::managed::RecycleError;
+use deadpool::managed::RecycleResult;
+pub use deadpool::Runtime;
+
+use crate::ConnStatus;
+use crate::Connection;
+use crate::Connector;
+use crate::Error;
+use crate::Result;
+
+/// Type alias for a deadpool pool of [Connection][]s
+///
+/// [Connection]: ../struct.Connection.html
+pub type Pool = managed::Pool<Manager>;
+
+/// Type alias for a [Connection][] borrowed from [Pool][]
+///
+/// [Connection]: ../struct.Connection.html
+/// [Pool]: type.Pool.html
+pub type Object = managed::Object<Manager>;
+
+/// A [deadpool::managed::Manager][] implementation for [Connection][]
+///
+/// Connections are established by the [Connector][] passed to
+/// [Manager::new][]. When a connection is returned to the pool, it
+/// is checked by [Connection.ping][] and [Connection.status][] and
+/// discarded if it is broken.
+///
+/// [deadpool::managed::Manager]: https://docs.rs/deadpool/0.12/deadpool/managed/trait.Manager.html
+/// [Connection]: ../struct.Connection.html
+/// [Connector]: ../struct.Connector.html
+/// [Manager::new]: #method.new
+/// [Connection.ping]: ../struct.Connection.html#method.ping
+/// [Connection.status]: ../struct.Connection.html#method.status
+#[derive(Debug, Clone)]
+pub struct Manager {
+    connector: Connector,
+    runtime: Option<Runtime>,
+}
+
+impl Manager {
+    /// Creates a new connection manager.
+    pub fn new(connector: Connector) -> Manager {
+        Manager {
+            connector,
+            runtime: None,
+        }
+    }
+
+    /// Sets the runtime used to run blocking calls to establish and
+    /// check connections.
+    ///
+    /// When it isn't set, they block the task calling the pool.
+    pub fn runtime(&mut self, runtime: Runtime) -> &mut Manager {
+        self.runtime = Some(runtime);
+        self
+    }
+
+    /// Returns the connector used to establish connections.
+    pub fn connector(&self) -> &Connector {
+        &self.connector
+    }
+}
+
+impl managed::Manager for Manager {
+    type Type = Connection;
+    type Error = Error;
+
+    async fn create(&self) -> Result<Connection> {
+        match self.runtime {
+            Some(runtime) => {
+                let connector = self.connector.clone();
+                runtime
+                    .spawn_blocking(move || connector.connect())
+                    .await
+                    .map_err(|err| Error::InternalError(err.to_string()))?
+            }
+            None => self.connector.connect(),
+        }
+    }
+
+    async fn recycle(&self, conn: &mut Connection, _: &Metrics) -> RecycleResult<Error> {
+        let health_check = conn.health_check();
+        let status = match self.runtime {
+            Some(runtime) => runtime
+                .spawn_blocking(health_check)
+                .await
+                .map_err(|err| RecycleError::message(err.to_string()))??,
+            None => health_check()?,
+        };
+        match status {
+            ConnStatus::Normal => Ok(()),
+            status => Err(RecycleError::message(format!(
+                "connection status is {:?}",
+                status
+            ))),
+        }
+    }
+}
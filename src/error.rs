@@ -122,6 +122,56 @@ impl error::Error for ParseOracleTypeError {
     }
 }
 
+/// Classification of Oracle database errors and ODPI-C errors
+///
+/// This is returned by [DbError.kind()][] and [Error.kind()][].
+/// Each kind corresponds to the following error codes.
+///
+/// | ErrorKind | Error codes |
+/// | --- | --- |
+/// | `UniqueViolation` | ORA-00001 |
+/// | `Deadlock` | ORA-00060 |
+/// | `ResourceBusy` | ORA-00054, ORA-30006 |
+/// | `SessionDead` | ORA-00022, ORA-00028, ORA-00031, ORA-00045, ORA-00378, ORA-00602, ORA-00603, ORA-00609, ORA-01012, ORA-01041, ORA-01043, ORA-01089, ORA-01092, ORA-02396, ORA-03113, ORA-03114, ORA-03122, ORA-03135, ORA-12153, ORA-12537, ORA-12547, ORA-12570, ORA-12583, ORA-27146, ORA-28511, ORA-56600, DPI-1010, DPI-1080 |
+/// | `Timeout` | ORA-02049, ORA-03136, ORA-03156, ORA-04021, ORA-12170, ORA-12535, ORA-12609, ORA-24457, DPI-1067 |
+/// | `Other` | others |
+///
+/// The `SessionDead` codes are those which ODPI-C treats as dead sessions.
+/// Connections which got them must not be used any more.
+/// DPI-1067 is raised when the time set by [Connection.set_call_timeout][]
+/// expires.
+///
+/// [DbError.kind()]: struct.DbError.html#method.kind
+/// [Error.kind()]: enum.Error.html#method.kind
+/// [Connection.set_call_timeout]: struct.Connection.html#method.set_call_timeout
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ErrorKind {
+    /// Unique constraint violation
+    UniqueViolation,
+
+    /// Deadlock detected while waiting for a resource
+    Deadlock,
+
+    /// Resource busy such as a row locked by `SELECT ... FOR UPDATE NOWAIT`
+    ResourceBusy,
+
+    /// Connection to the database is lost
+    SessionDead,
+
+    /// Operation timed out
+    Timeout,
+
+    /// Other errors
+    Other,
+}
+
+const SESSION_DEAD_ORA_CODES: &[i32] = &[
+    22, 28, 31, 45, 378, 602, 603, 609, 1012, 1041, 1043, 1089, 1092, 2396, 3113, 3114, 3122, 3135,
+    12153, 12537, 12547, 12570, 12583, 27146, 28511, 56600,
+];
+
+const TIMEOUT_ORA_CODES: &[i32] = &[2049, 3136, 3156, 4021, 12170, 12535, 12609, 24457];
+
 /// Oracle database error or ODPI-C error
 #[derive(Debug, Eq, PartialEq, Clone)]
 pub struct DbError {
@@ -130,6 +180,7 @@ pub struct DbError {
     message: String,
     fn_name: String,
     action: String,
+    is_recoverable: bool,
 }
 
 impl DbError {
@@ -146,6 +197,7 @@ impl DbError {
             message: message,
             fn_name: fn_name,
             action: action,
+            is_recoverable: false,
         }
     }
 
@@ -173,6 +225,135 @@ impl DbError {
     pub fn action(&self) -> &str {
         &self.action
     }
+
+    /// Returns `true` when the error is recoverable by retrying the
+    /// transaction. This is always `false` unless both Oracle client and
+    /// server are 12.1 or later.
+    pub fn is_recoverable(&self) -> bool {
+        self.is_recoverable
+    }
+
+    /// Returns the classification of the error. See [ErrorKind][].
+    ///
+    /// [ErrorKind]: enum.ErrorKind.html
+    pub fn kind(&self) -> ErrorKind {
+        if let Some(dpi_code) = self.dpi_code() {
+            return match dpi_code {
+                1010 | 1080 => ErrorKind::SessionDead,
+                1067 => ErrorKind::Timeout,
+                _ => ErrorKind::Other,
+            };
+        }
+        match self.code {
+            1 => ErrorKind::UniqueViolation,
+            60 => ErrorKind::Deadlock,
+            54 | 30006 => ErrorKind::ResourceBusy,
+            code if SESSION_DEAD_ORA_CODES.contains(&code) => ErrorKind::SessionDead,
+            code if TIMEOUT_ORA_CODES.contains(&code) => ErrorKind::Timeout,
+            _ => ErrorKind::Other,
+        }
+    }
+
+    /// Returns `true` when the error is ORA-00001: unique constraint violated.
+    pub fn is_unique_violation(&self) -> bool {
+        self.kind() == ErrorKind::UniqueViolation
+    }
+
+    /// Returns `true` when the error is ORA-00060: deadlock detected.
+    pub fn is_deadlock(&self) -> bool {
+        self.kind() == ErrorKind::Deadlock
+    }
+
+    /// Returns `true` when the error is ORA-00054: resource busy.
+    pub fn is_resource_busy(&self) -> bool {
+        self.kind() == ErrorKind::ResourceBusy
+    }
+
+    /// Returns `true` when the connection is lost.
+    pub fn is_session_dead(&self) -> bool {
+        self.kind() == ErrorKind::SessionDead
+    }
+
+    /// Returns `true` when the operation timed out.
+    pub fn is_timeout(&self) -> bool {
+        self.kind() == ErrorKind::Timeout
+    }
+
+    // Returns NNNN in "DPI-NNNN: ..." messages.
+    fn dpi_code(&self) -> Option<i32> {
+        if !self.message.starts_with("DPI-") {
+            return None;
+        }
+        let digits = self.message[4..]
+            .split(|c: char| !c.is_ascii_digit())
+            .next()?;
+        digits.parse().ok()
+    }
+}
+
+impl Error {
+    /// Returns the Oracle database error or the ODPI-C error.
+    pub fn db_error(&self) -> Option<&DbError> {
+        match *self {
+            Error::OciError(ref err) | Error::DpiError(ref err) => Some(err),
+            _ => None,
+        }
+    }
+
+    /// Returns the classification of the Oracle database error or the
+    /// ODPI-C error. `ErrorKind::Other` is returned for other errors.
+    /// See [ErrorKind][].
+    ///
+    /// ```no_run
+    /// # use oracle::*; fn try_main() -> Result<()> {
+    /// let conn = Connection::connect("scott", "tiger", "")?;
+    /// match conn.execute("insert into emp(empno, ename) values (7369, 'SMITH')", &[]) {
+    ///     Ok(_) => conn.commit()?,
+    ///     Err(ref err) if err.kind() == ErrorKind::UniqueViolation => {
+    ///         println!("empno 7369 already exists");
+    ///     }
+    ///     Err(err) => return Err(err),
+    /// }
+    /// # Ok(())} fn main() { try_main().unwrap(); }
+    /// ```
+    ///
+    /// [ErrorKind]: enum.ErrorKind.html
+    pub fn kind(&self) -> ErrorKind {
+        self.db_error().map_or(ErrorKind::Other, DbError::kind)
+    }
+
+    /// Returns `true` when the error is ORA-00001: unique constraint violated.
+    pub fn is_unique_violation(&self) -> bool {
+        self.kind() == ErrorKind::UniqueViolation
+    }
+
+    /// Returns `true` when the error is ORA-00060: deadlock detected.
+    pub fn is_deadlock(&self) -> bool {
+        self.kind() == ErrorKind::Deadlock
+    }
+
+    /// Returns `true` when the error is ORA-00054: resource busy.
+    pub fn is_resource_busy(&self) -> bool {
+        self.kind() == ErrorKind::ResourceBusy
+    }
+
+    /// Returns `true` when the connection is lost.
+    pub fn is_session_dead(&self) -> bool {
+        self.kind() == ErrorKind::SessionDead
+    }
+
+    /// Returns `true` when the operation timed out.
+    pub fn is_timeout(&self) -> bool {
+        self.kind() == ErrorKind::Timeout
+    }
+
+    /// Returns `true` when the Oracle database error is recoverable by
+    /// retrying the transaction. See [DbError.is_recoverable()][].
+    ///
+    /// [DbError.is_recoverable()]: struct.DbError.html#method.is_recoverable
+    pub fn is_recoverable(&self) -> bool {
+        self.db_error().map_or(false, DbError::is_recoverable)
+    }
 }
 
 impl fmt::Display for Error {
@@ -297,7 +478,8 @@ impl<T> From<sync::PoisonError<T>> for Error {
 //
 
 pub fn error_from_dpi_error(err: &dpiErrorInfo) -> Error {
-    let err = DbError::new(
+    let is_recoverable = err.isRecoverable != 0;
+    let mut err = DbError::new(
         err.code,
         err.offset,
         to_rust_str(err.message, err.messageLength),
@@ -308,6 +490,7 @@ pub fn error_from_dpi_error(err: &dpiErrorInfo) -> Error {
             .to_string_lossy()
             .into_owned(),
     );
+    err.is_recoverable = is_recoverable;
     if err.message().starts_with("DPI") {
         Error::DpiError(err)
     } else {
@@ -343,3 +526,47 @@ macro_rules! chkerr {
         }
     }};
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn db_error(code: i32, message: &str) -> DbError {
+        DbError::new(
+            code,
+            0,
+            message.to_string(),
+            "dpiStmt_execute".to_string(),
+            "execute".to_string(),
+        )
+    }
+
+    #[test]
+    fn kind() {
+        let err = db_error(1, "ORA-00001: unique constraint (SCOTT.PK_EMP) violated");
+        assert_eq!(err.kind(), ErrorKind::UniqueViolation);
+        assert!(err.is_unique_violation());
+        assert!(!err.is_recoverable());
+        assert_eq!(db_error(60, "ORA-00060: ").kind(), ErrorKind::Deadlock);
+        assert_eq!(db_error(54, "ORA-00054: ").kind(), ErrorKind::ResourceBusy);
+        assert_eq!(db_error(3113, "ORA-03113: ").kind(), ErrorKind::SessionDead);
+        assert_eq!(db_error(3135, "ORA-03135: ").kind(), ErrorKind::SessionDead);
+        assert_eq!(db_error(12170, "ORA-12170: ").kind(), ErrorKind::Timeout);
+        assert_eq!(db_error(942, "ORA-00942: ").kind(), ErrorKind::Other);
+        let err = db_error(0, "DPI-1080: connection was closed by ORA-3113");
+        assert_eq!(err.kind(), ErrorKind::SessionDead);
+        let err = db_error(0, "DPI-1067: call timeout of 100 ms exceeded with ORA-3156");
+        assert_eq!(err.kind(), ErrorKind::Timeout);
+        // The code of DPI errors is not an Oracle error code.
+        assert_eq!(
+            db_error(1, "DPI-1002: invalid OCI handle").kind(),
+            ErrorKind::Other
+        );
+
+        let err = Error::DpiError(db_error(0, "DPI-1080: connection was closed by ORA-3113"));
+        assert!(err.is_session_dead());
+        assert!(!err.is_timeout());
+        assert_eq!(Error::NoDataFound.kind(), ErrorKind::Other);
+        assert!(!Error::NoDataFound.is_recoverable());
+    }
+}
@@ -62,6 +62,49 @@ data types, enable `chrono` feature:
 oracle = { version = "0.3.0", features = ["chrono"] }
 ```
 
+Enable `chrono-tz` feature in addition to `chrono` to fetch timestamps
+as `DateTime<chrono_tz::Tz>`. Time zone region names such as `Europe/Madrid`
+are available only when timestamps are fetched as strings by `TO_CHAR` with
+`TZR` because ODPI-C passes only time zone offsets.
+
+When you need to fetch or bind [time](https://docs.rs/time/0.3/time/)
+data types instead, enable `time` feature.
+
+When you need connection pools provided by [r2d2](https://docs.rs/r2d2/0.8/r2d2/)
+or [deadpool](https://docs.rs/deadpool/0.12/deadpool/), enable `r2d2` or
+`deadpool` feature. Connection managers are in `oracle::r2d2` and
+`oracle::deadpool` modules respectively.
+
+```text
+[dependencies]
+oracle = { version = "0.3.0", features = ["r2d2"] }
+```
+
+When you need to convert rows fetched by `Statement.fetch_columnar` to
+[Arrow](https://docs.rs/arrow/57/arrow/) record batches, enable `arrow` feature.
+
+When you need to fetch or bind NUMBER values as [rust_decimal](https://docs.rs/rust_decimal/1/rust_decimal/)
+or [bigdecimal](https://docs.rs/bigdecimal/0.4/bigdecimal/) types, enable `rust_decimal`
+or `bigdecimal` feature. `oracle::sql_type::OracleNumber` is available without them.
+
+JSON data in `IS JSON` columns is fetched and bound as `oracle::sql_type::JsonValue`.
+The native JSON data type in Oracle 21c isn't supported yet.
+When you need [serde_json](https://docs.rs/serde_json/1/serde_json/) values instead,
+enable `serde_json` feature.
+
+When you need to fetch or bind [uuid](https://docs.rs/uuid/1/uuid/) values
+such as `SYS_GUID()` in RAW(16) columns, enable `uuid` feature.
+
+When you need Rust structs corresponding to object types or query columns,
+`oracle::codegen` generates them with `FromSql`, `ToSql` or `RowValue`
+implementations. The `oracle-codegen` command prints them as follows.
+It reads the password from the `ORACLE_PASSWORD` environment variable or
+the standard input.
+
+```text
+ORACLE_PASSWORD=tiger cargo run --bin oracle-codegen -- scott "" --type MDSYS.SDO_POINT_TYPE --query Emp "select * from emp"
+```
+
 Then put this in your crate root:
 
 ```rust
@@ -266,7 +309,7 @@ required.
 
 * Connection pooling
 * Read and write LOB as stream
-* REF CURSOR, BOOLEAN
+* REF CURSOR
 * Scrollable cursors
 * Batch DML
 * Better Oracle object type support
@@ -298,8 +341,15 @@ use std::slice;
 #[allow(non_snake_case)]
 #[allow(improper_ctypes)]
 mod binding;
+pub mod codegen;
+mod columnar;
 mod connection;
+#[cfg(feature = "deadpool")]
+pub mod deadpool;
 mod error;
+mod object_type_cache;
+#[cfg(feature = "r2d2")]
+pub mod r2d2;
 mod row;
 pub mod sql_type;
 mod sql_value;
@@ -307,6 +357,8 @@ mod statement;
 mod util;
 mod version;
 
+pub use crate::columnar::ColumnData;
+pub use crate::columnar::ColumnarBatch;
 pub use crate::connection::ConnStatus;
 pub use crate::connection::Connection;
 pub use crate::connection::Connector;
@@ -315,7 +367,9 @@ pub use crate::connection::ShutdownMode;
 pub use crate::connection::StartupMode;
 pub use crate::error::DbError;
 pub use crate::error::Error;
+pub use crate::error::ErrorKind;
 pub use crate::error::ParseOracleTypeError;
+pub use crate::object_type_cache::ObjectTypeCache;
 pub use crate::row::ResultSet;
 pub use crate::row::Row;
 pub use crate::row::RowValue;
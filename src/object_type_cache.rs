@@ -0,0 +1,329 @@
+// Rust-oracle - Rust binding for Oracle database
+//
+// URL: https://github.com/kubo/rust-oracle
+//
+//-----------------------------------------------------------------------------
+// Copyright (c) 2017-2018 Kubo Takehiro <kubo@jiubao.org>. All rights reserved.
+// This program is free software: you can modify it and/or redistribute it
+// under the terms of:
+//
+// (i)  the Universal Permissive License v 1.0 or at your option, any
+//      later version (http://oss.oracle.com/licenses/upl); and/or
+//
+// (ii) the Apache License v 2.0. (http://www.apache.org/licenses/LICENSE-2.0)
+//-----------------------------------------------------------------------------
+
+use std::collections::HashMap;
+use std::fmt;
+use std::sync::Arc;
+use std::sync::Mutex;
+use std::time::Duration;
+use std::time::Instant;
+
+use crate::sql_type::ObjectTypeInternal;
+
+/// Settings and invalidation state of object type caches
+///
+/// Each connection caches object types got by [Connection.object_type][].
+/// An `ObjectTypeCache` controls the caches of all connections sharing it:
+/// how long entries live, how many entries a connection keeps and which
+/// entries are out of date.
+///
+/// Cached object types themselves are not shared because they belong to
+/// the connection which loaded them. When entries are invalidated, each
+/// connection sharing the `ObjectTypeCache` reloads them from the database
+/// on the next access.
+///
+/// Every connection has its own `ObjectTypeCache` by default. Set a shared
+/// one by [Connector.object_type_cache][] to control connections in a
+/// connection pool at once. Share it only among connections of the same
+/// user because unqualified type names are resolved in the user's schema.
+///
+/// ```no_run
+/// # use oracle::*; use std::time::Duration; fn try_main() -> Result<()> {
+/// let cache = ObjectTypeCache::new();
+/// cache.set_ttl(Some(Duration::from_secs(600)));
+/// cache.set_max_size(Some(100));
+///
+/// let mut connector = Connector::new("scott", "tiger", "");
+/// connector.object_type_cache(cache.clone());
+/// let conn1 = connector.connect()?;
+/// let conn2 = connector.connect()?;
+/// conn1.object_type("SCOTT.EMP_T")?;
+/// conn2.object_type("SCOTT.EMP_T")?;
+///
+/// // After types in SCOTT are changed by another application,
+/// // both connections reload them on the next access.
+/// cache.invalidate_schema("SCOTT");
+/// # Ok(())} fn main() { try_main().unwrap(); }
+/// ```
+///
+/// [Connection.object_type]: struct.Connection.html#method.object_type
+/// [Connector.object_type_cache]: struct.Connector.html#method.object_type_cache
+#[derive(Clone)]
+pub struct ObjectTypeCache {
+    shared: Arc<Mutex<SharedState>>,
+}
+
+#[derive(Default)]
+struct SharedState {
+    ttl: Option<Duration>,
+    max_size: Option<usize>,
+    invalidated_at: Option<Instant>,
+    schemas_invalidated_at: HashMap<String, Instant>,
+    // Keyed by schema and then by type name so that lookups don't allocate.
+    types_invalidated_at: HashMap<String, HashMap<String, Instant>>,
+}
+
+impl ObjectTypeCache {
+    /// Creates settings without time-to-live and size limit.
+    pub fn new() -> ObjectTypeCache {
+        ObjectTypeCache {
+            shared: Arc::new(Mutex::new(SharedState::default())),
+        }
+    }
+
+    /// Sets how long cached object types are used after they are loaded.
+    /// `None`, the default, means that they are used until invalidated.
+    pub fn set_ttl(&self, ttl: Option<Duration>) {
+        self.lock().ttl = ttl;
+    }
+
+    /// Gets the time-to-live of cached object types.
+    pub fn ttl(&self) -> Option<Duration> {
+        self.lock().ttl
+    }
+
+    /// Sets the maximum number of object types cached in each connection.
+    /// When it is exceeded, the least recently used entry is removed.
+    /// `None`, the default, means no limit.
+    pub fn set_max_size(&self, max_size: Option<usize>) {
+        self.lock().max_size = max_size;
+    }
+
+    /// Gets the maximum number of object types cached in each connection.
+    pub fn max_size(&self) -> Option<usize> {
+        self.lock().max_size
+    }
+
+    /// Invalidates all cached object types.
+    pub fn invalidate_all(&self) {
+        let mut state = self.lock();
+        state.invalidated_at = Some(Instant::now());
+        // Older invalidations are covered by this.
+        state.schemas_invalidated_at.clear();
+        state.types_invalidated_at.clear();
+    }
+
+    /// Invalidates cached object types owned by `schema`.
+    ///
+    /// The schema name is compared with [ObjectType.schema()][] case-sensitively.
+    ///
+    /// [ObjectType.schema()]: sql_type/struct.ObjectType.html#method.schema
+    pub fn invalidate_schema(&self, schema: &str) {
+        let now = Instant::now();
+        let mut state = self.lock();
+        state.prune(now);
+        state.schemas_invalidated_at.insert(schema.to_string(), now);
+    }
+
+    /// Invalidates a cached object type.
+    ///
+    /// The schema and type names are compared with [ObjectType.schema()][] and
+    /// [ObjectType.name()][] case-sensitively. Types declared in PL/SQL packages
+    /// are invalidated by the type names without package names.
+    ///
+    /// [ObjectType.schema()]: sql_type/struct.ObjectType.html#method.schema
+    /// [ObjectType.name()]: sql_type/struct.ObjectType.html#method.name
+    pub fn invalidate_type(&self, schema: &str, name: &str) {
+        let now = Instant::now();
+        let mut state = self.lock();
+        state.prune(now);
+        state
+            .types_invalidated_at
+            .entry(schema.to_string())
+            .or_default()
+            .insert(name.to_string(), now);
+    }
+
+    fn lock(&self) -> std::sync::MutexGuard<'_, SharedState> {
+        // The state is consistent even when another thread panicked.
+        match self.shared.lock() {
+            Ok(guard) => guard,
+            Err(err) => err.into_inner(),
+        }
+    }
+}
+
+impl SharedState {
+    fn is_stale(&self, entry: &CacheEntry, now: Instant) -> bool {
+        let loaded_at = entry.loaded_at;
+        if let Some(ttl) = self.ttl {
+            if now.duration_since(loaded_at) >= ttl {
+                return true;
+            }
+        }
+        let objtype = &entry.objtype;
+        let invalidated_after = |at: Option<&Instant>| at.map_or(false, |at| loaded_at <= *at);
+        invalidated_after(self.invalidated_at.as_ref())
+            || invalidated_after(self.schemas_invalidated_at.get(&objtype.schema))
+            || invalidated_after(
+                self.types_invalidated_at
+                    .get(&objtype.schema)
+                    .and_then(|types| types.get(&objtype.name)),
+            )
+    }
+
+    // Removes invalidations older than the time-to-live. Entries loaded
+    // before them are already stale by the time-to-live.
+    fn prune(&mut self, now: Instant) {
+        if let Some(ttl) = self.ttl {
+            let is_live = |at: &Instant| now.duration_since(*at) < ttl;
+            if !self.invalidated_at.as_ref().map_or(false, is_live) {
+                self.invalidated_at = None;
+            }
+            self.schemas_invalidated_at.retain(|_, at| is_live(at));
+            self.types_invalidated_at.retain(|_, types| {
+                types.retain(|_, at| is_live(at));
+                !types.is_empty()
+            });
+        }
+    }
+}
+
+impl Default for ObjectTypeCache {
+    fn default() -> ObjectTypeCache {
+        ObjectTypeCache::new()
+    }
+}
+
+impl PartialEq for ObjectTypeCache {
+    fn eq(&self, other: &Self) -> bool {
+        Arc::ptr_eq(&self.shared, &other.shared)
+    }
+}
+
+impl fmt::Debug for ObjectTypeCache {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let state = self.lock();
+        write!(
+            f,
+            "ObjectTypeCache {{ ttl: {:?}, max_size: {:?} }}",
+            state.ttl, state.max_size
+        )
+    }
+}
+
+//
+// CachedObjectTypes
+//
+
+struct CacheEntry {
+    objtype: Arc<ObjectTypeInternal>,
+    loaded_at: Instant,
+    last_used: Instant,
+}
+
+// Object types cached in a connection
+pub(crate) struct CachedObjectTypes {
+    entries: HashMap<String, CacheEntry>,
+}
+
+impl CachedObjectTypes {
+    pub(crate) fn new() -> CachedObjectTypes {
+        CachedObjectTypes {
+            entries: HashMap::new(),
+        }
+    }
+
+    pub(crate) fn get(
+        &mut self,
+        name: &str,
+        settings: &ObjectTypeCache,
+    ) -> Option<Arc<ObjectTypeInternal>> {
+        let now = Instant::now();
+        let stale = match self.entries.get_mut(name) {
+            Some(entry) => {
+                if settings.lock().is_stale(entry, now) {
+                    true
+                } else {
+                    entry.last_used = now;
+                    return Some(entry.objtype.clone());
+                }
+            }
+            None => false,
+        };
+        if stale {
+            self.entries.remove(name);
+        }
+        None
+    }
+
+    pub(crate) fn insert(
+        &mut self,
+        name: &str,
+        objtype: Arc<ObjectTypeInternal>,
+        settings: &ObjectTypeCache,
+    ) {
+        let now = Instant::now();
+        self.entries.insert(
+            name.to_string(),
+            CacheEntry {
+                objtype,
+                loaded_at: now,
+                last_used: now,
+            },
+        );
+        if let Some(max_size) = settings.max_size() {
+            while self.entries.len() > max_size {
+                let lru_name = self
+                    .entries
+                    .iter()
+                    .min_by_key(|(_, entry)| entry.last_used)
+                    .map(|(name, _)| name.clone());
+                match lru_name {
+                    Some(lru_name) => self.entries.remove(&lru_name),
+                    None => break,
+                };
+            }
+        }
+    }
+
+    pub(crate) fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    pub(crate) fn clear(&mut self) {
+        self.entries.clear();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn prune_invalidations() {
+        let cache = ObjectTypeCache::new();
+        cache.invalidate_schema("SCOTT");
+        cache.invalidate_type("SCOTT", "EMP_T");
+        // Invalidations are kept without time-to-live.
+        cache.invalidate_schema("HR");
+        assert_eq!(cache.lock().schemas_invalidated_at.len(), 2);
+        assert_eq!(cache.lock().types_invalidated_at.len(), 1);
+
+        cache.set_ttl(Some(Duration::from_millis(10)));
+        std::thread::sleep(Duration::from_millis(20));
+        cache.invalidate_type("HR", "DEPT_T");
+        let state = cache.lock();
+        assert!(state.schemas_invalidated_at.is_empty());
+        assert_eq!(
+            state.types_invalidated_at.keys().collect::<Vec<_>>(),
+            ["HR"]
+        );
+        assert_eq!(
+            state.types_invalidated_at["HR"].keys().collect::<Vec<_>>(),
+            ["DEPT_T"]
+        );
+    }
+}
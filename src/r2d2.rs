@@ -0,0 +1,95 @@
+// Rust-oracle - Rust binding for Oracle database
+//
+// URL: https://github.com/kubo/rust-oracle
+//
+//-----------------------------------------------------------------------------
+// Copyright (c) 2017-2018 Kubo Takehiro <kubo@jiubao.org>. All rights reserved.
+// This program is free software: you can modify it and/or redistribute it
+// under the terms of:
+//
+// (i)  the Universal Permissive License v 1.0 or at your option, any
+//      later version (http://oss.oracle.com/licenses/upl); and/or
+//
+// (ii) the Apache License v 2.0. (http://www.apache.org/licenses/LICENSE-2.0)
+//-----------------------------------------------------------------------------
+
+//! [r2d2](https://docs.rs/r2d2/0.8/r2d2/) connection manager
+//!
+//! This module is available when `r2d2` feature is enabled.
+//!
+//! ```no_run
+//! # use oracle::*; fn try_main() -> std::result::Result<(), Box<dyn std::error::Error>> {
+//! use oracle::r2d2::ConnectionManager;
+//!
+//! let manager = ConnectionManager::new(Connector::new("scott", "tiger", "//localhost/XE"));
+//! let pool = r2d2::Pool::builder().max_size(4).build(manager)?;
+//!
+//! let conn = pool.get()?;
+//! let row = conn.query_row_as::<String>("select ename from emp where empno = :1", &[&7369])?;
+//! # Ok(())} fn main() { try_main().unwrap(); }
+//! ```
+
+use r2d2::ManageConnection;
+
+use crate::ConnStatus;
+use crate::Connection;
+use crate::Connector;
+use crate::Error;
+use crate::Result;
+
+/// An [r2d2::ManageConnection][] implementation for [Connection][]
+///
+/// Connections are established by the [Connector][] passed to
+/// [ConnectionManager::new][].
+///
+/// [r2d2::ManageConnection]: https://docs.rs/r2d2/0.8/r2d2/trait.ManageConnection.html
+/// [Connection]: ../struct.Connection.html
+/// [Connector]: ../struct.Connector.html
+/// [ConnectionManager::new]: #method.new
+#[derive(Debug, Clone)]
+pub struct ConnectionManager {
+    connector: Connector,
+}
+
+impl ConnectionManager {
+    /// Creates a new connection manager.
+    pub fn new(connector: Connector) -> ConnectionManager {
+        ConnectionManager { connector }
+    }
+
+    /// Returns the connector used to establish connections.
+    pub fn connector(&self) -> &Connector {
+        &self.connector
+    }
+}
+
+impl ManageConnection for ConnectionManager {
+    type Connection = Connection;
+    type Error = Error;
+
+    fn connect(&self) -> Result<Connection> {
+        self.connector.connect()
+    }
+
+    /// Checks the connection by [Connection.ping](../struct.Connection.html#method.ping)
+    /// and [Connection.status](../struct.Connection.html#method.status).
+    fn is_valid(&self, conn: &mut Connection) -> Result<()> {
+        conn.ping()?;
+        match conn.status()? {
+            ConnStatus::Normal => Ok(()),
+            status => Err(Error::InvalidOperation(format!(
+                "connection status is {:?}",
+                status
+            ))),
+        }
+    }
+
+    /// Returns true when [Connection.status](../struct.Connection.html#method.status)
+    /// isn't `ConnStatus::Normal`. It doesn't make a network round-trip.
+    fn has_broken(&self, conn: &mut Connection) -> bool {
+        match conn.status() {
+            Ok(ConnStatus::Normal) => false,
+            _ => true,
+        }
+    }
+}
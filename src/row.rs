@@ -17,9 +17,10 @@ use std::boxed::Box;
 use std::fmt;
 use std::iter::FusedIterator;
 use std::marker::PhantomData;
-use std::rc::Rc;
+use std::sync::Arc;
 
 use crate::sql_type::FromSql;
+use crate::AssertSend;
 use crate::ColumnIndex;
 use crate::ColumnInfo;
 use crate::Connection;
@@ -35,10 +36,12 @@ pub struct RowSharedData {
 
 /// Row in a result set of a select statement
 pub struct Row {
-    pub(crate) shared: Rc<RowSharedData>,
+    pub(crate) shared: Arc<RowSharedData>,
     pub(crate) column_values: Vec<SqlValue>,
 }
 
+impl AssertSend for Row {}
+
 impl Row {
     pub(crate) fn new(
         conn: &Connection,
@@ -50,7 +53,7 @@ impl Row {
             conn_handle: conn.handle.clone(),
         };
         Ok(Row {
-            shared: Rc::new(shared),
+            shared: Arc::new(shared),
             column_values: column_values,
         })
     }
@@ -0,0 +1,58 @@
+// Rust-oracle - Rust binding for Oracle database
+//
+// URL: https://github.com/kubo/rust-oracle
+//
+//-----------------------------------------------------------------------------
+// Copyright (c) 2017-2018 Kubo Takehiro <kubo@jiubao.org>. All rights reserved.
+// This program is free software: you can modify it and/or redistribute it
+// under the terms of:
+//
+// (i)  the Universal Permissive License v 1.0 or at your option, any
+//      later version (http://oss.oracle.com/licenses/upl); and/or
+//
+// (ii) the Apache License v 2.0. (http://www.apache.org/licenses/LICENSE-2.0)
+//-----------------------------------------------------------------------------
+
+use std::str::FromStr;
+
+use crate::sql_type::FromSql;
+use crate::sql_type::OracleNumber;
+use crate::sql_type::OracleType;
+use crate::sql_type::ToSql;
+use crate::sql_type::ToSqlNull;
+use crate::Connection;
+use crate::Error;
+use crate::Result;
+use crate::SqlValue;
+use bigdecimal::BigDecimal;
+
+//
+// bigdecimal::BigDecimal
+//
+
+impl FromSql for BigDecimal {
+    fn from_sql(val: &SqlValue) -> Result<BigDecimal> {
+        let num = val.to_oracle_number()?.to_string();
+        BigDecimal::from_str(&num).map_err(|err| Error::ParseError(Box::new(err)))
+    }
+}
+
+impl ToSqlNull for BigDecimal {
+    fn oratype_for_null(_conn: &Connection) -> Result<OracleType> {
+        Ok(OracleType::Number(0, 0))
+    }
+}
+
+impl ToSql for BigDecimal {
+    fn oratype(&self, _conn: &Connection) -> Result<OracleType> {
+        Ok(OracleType::Number(0, 0))
+    }
+    fn to_sql(&self, val: &mut SqlValue) -> Result<()> {
+        // Values which need more than 40 significant digits or are out of
+        // the range of Oracle numbers are rejected instead of rounded.
+        let num = OracleNumber::from_str(&self.to_string()).map_err(|_| {
+            Error::OutOfRange(format!("{} cannot be converted to Oracle number", self))
+        })?;
+        val.set_oracle_number(&num)
+    }
+}
@@ -14,6 +14,7 @@
 //-----------------------------------------------------------------------------
 
 use chrono::prelude::*;
+use try_from::TryFrom;
 
 use crate::sql_type::FromSql;
 use crate::sql_type::IntervalDS;
@@ -27,6 +28,7 @@ use crate::Result;
 use crate::SqlValue;
 use chrono::naive::NaiveDate;
 use chrono::naive::NaiveDateTime;
+use chrono::naive::NaiveTime;
 use chrono::offset::LocalResult;
 use chrono::Duration;
 
@@ -233,35 +235,112 @@ impl ToSql for NaiveDate {
     }
 }
 
+//
+// chrono::naive::NaiveTime
+//
+
+impl FromSql for NaiveTime {
+    fn from_sql(val: &SqlValue) -> Result<NaiveTime> {
+        if let OracleType::IntervalDS(_, _) = *val.oracle_type()? {
+            let it = val.to_interval_ds()?;
+            let nanos = it.time_of_day_nanoseconds()?;
+            NaiveTime::from_num_seconds_from_midnight_opt(
+                (nanos / 1_000_000_000) as u32,
+                (nanos % 1_000_000_000) as u32,
+            )
+            .ok_or_else(|| Error::OutOfRange(format!("invalid time: {}", it)))
+        } else {
+            let ts = val.to_timestamp()?;
+            NaiveTime::from_hms_nano_opt(ts.hour(), ts.minute(), ts.second(), ts.nanosecond())
+                .ok_or_else(|| Error::OutOfRange(format!("invalid time: {}", ts)))
+        }
+    }
+}
+
+impl ToSqlNull for NaiveTime {
+    fn oratype_for_null(_conn: &Connection) -> Result<OracleType> {
+        Ok(OracleType::IntervalDS(9, 9))
+    }
+}
+
+impl ToSql for NaiveTime {
+    fn oratype(&self, _conn: &Connection) -> Result<OracleType> {
+        Ok(OracleType::IntervalDS(9, 9))
+    }
+
+    fn to_sql(&self, val: &mut SqlValue) -> Result<()> {
+        // The nanosecond exceeds 999,999,999 to represent a leap second,
+        // which interval day to second cannot represent.
+        if self.nanosecond() >= 1_000_000_000 {
+            return Err(Error::OutOfRange(format!(
+                "leap second {} cannot be bound",
+                self
+            )));
+        }
+        let it = IntervalDS::new(
+            0,
+            self.hour() as i32,
+            self.minute() as i32,
+            self.second() as i32,
+            self.nanosecond() as i32,
+        );
+        val.set_interval_ds(&it)
+    }
+}
+
 //
 // chrono::Duration
 //
 
 impl FromSql for Duration {
     fn from_sql(val: &SqlValue) -> Result<Duration> {
-        let err =
-            |it: IntervalDS| Error::OutOfRange(format!("Duration overflow: {}", it.to_string()));
-        let it = val.to_interval_ds()?;
+        Duration::try_from(val.to_interval_ds()?)
+    }
+}
+
+impl TryFrom<IntervalDS> for Duration {
+    type Err = Error;
+
+    /// Converts an interval day to second to a duration.
+    ///
+    /// This returns `Err(Error::OutOfRange(...))` when the interval
+    /// overflows `Duration`.
+    fn try_from(it: IntervalDS) -> Result<Duration> {
+        let err = || Error::OutOfRange(format!("Duration overflow: {}", it));
         let d = Duration::milliseconds(0);
         let d = d
             .checked_add(&Duration::days(it.days() as i64))
-            .ok_or(err(it))?;
+            .ok_or_else(err)?;
         let d = d
             .checked_add(&Duration::hours(it.hours() as i64))
-            .ok_or(err(it))?;
+            .ok_or_else(err)?;
         let d = d
             .checked_add(&Duration::minutes(it.minutes() as i64))
-            .ok_or(err(it))?;
+            .ok_or_else(err)?;
         let d = d
             .checked_add(&Duration::seconds(it.seconds() as i64))
-            .ok_or(err(it))?;
+            .ok_or_else(err)?;
         let d = d
             .checked_add(&Duration::nanoseconds(it.nanoseconds() as i64))
-            .ok_or(err(it))?;
+            .ok_or_else(err)?;
         Ok(d)
     }
 }
 
+impl TryFrom<Duration> for IntervalDS {
+    type Err = Error;
+
+    /// Converts a duration to an interval day to second.
+    ///
+    /// This returns `Err(Error::OutOfRange(...))` when the number of days
+    /// exceeds 999999999.
+    fn try_from(dur: Duration) -> Result<IntervalDS> {
+        let secs = dur.num_seconds();
+        let nsecs = (dur - Duration::seconds(secs)).num_nanoseconds().unwrap();
+        IntervalDS::with_total_nanoseconds(secs as i128 * 1_000_000_000 + nsecs as i128, 9, 9)
+    }
+}
+
 impl ToSqlNull for Duration {
     fn oratype_for_null(_conn: &Connection) -> Result<OracleType> {
         Ok(OracleType::IntervalDS(9, 9))
@@ -274,27 +353,6 @@ impl ToSql for Duration {
     }
 
     fn to_sql(&self, val: &mut SqlValue) -> Result<()> {
-        let secs = self.num_seconds();
-        let nsecs = (*self - Duration::seconds(secs)).num_nanoseconds().unwrap();
-        let days = secs / (24 * 60 * 60);
-        let secs = secs % (24 * 60 * 60);
-        let hours = secs / (60 * 60);
-        let secs = secs % (60 * 60);
-        let minutes = secs / 60;
-        let secs = secs % 60;
-        if days.abs() >= 1000000000 {
-            return Err(Error::OutOfRange(format!(
-                "too large days: {}",
-                self.to_string()
-            )));
-        }
-        let it = IntervalDS::new(
-            days as i32,
-            hours as i32,
-            minutes as i32,
-            secs as i32,
-            nsecs as i32,
-        );
-        val.set_interval_ds(&it)
+        val.set_interval_ds(&IntervalDS::try_from(*self)?)
     }
 }
@@ -0,0 +1,125 @@
+// Rust-oracle - Rust binding for Oracle database
+//
+// URL: https://github.com/kubo/rust-oracle
+//
+//-----------------------------------------------------------------------------
+// Copyright (c) 2017-2018 Kubo Takehiro <kubo@jiubao.org>. All rights reserved.
+// This program is free software: you can modify it and/or redistribute it
+// under the terms of:
+//
+// (i)  the Universal Permissive License v 1.0 or at your option, any
+//      later version (http://oss.oracle.com/licenses/upl); and/or
+//
+// (ii) the Apache License v 2.0. (http://www.apache.org/licenses/LICENSE-2.0)
+//-----------------------------------------------------------------------------
+
+use chrono::naive::NaiveDate;
+use chrono::offset::LocalResult;
+use chrono::prelude::*;
+use chrono_tz::Tz;
+use chrono_tz::TZ_VARIANTS;
+use try_from::TryFrom;
+
+use crate::sql_type::FromSql;
+use crate::sql_type::Timestamp;
+use crate::Error;
+use crate::Result;
+use crate::SqlValue;
+
+//
+// chrono::DateTime<chrono_tz::Tz>
+//
+
+fn tz_from_sql(ts: &Timestamp) -> Result<Tz> {
+    if let Some(name) = ts.tz_region() {
+        if let Ok(tz) = name.parse() {
+            return Ok(tz);
+        }
+        // Oracle may return region names in upper case.
+        return TZ_VARIANTS
+            .iter()
+            .find(|tz| tz.name().eq_ignore_ascii_case(name))
+            .cloned()
+            .ok_or_else(|| Error::OutOfRange(format!("unknown time zone region: {}", name)));
+    }
+    let offset = ts.tz_offset();
+    if offset == 0 {
+        return Ok(Tz::UTC);
+    }
+    // Etc/GMT+n is n hours behind UTC. There are no such zones for offsets
+    // which aren't whole hours. A region with the same offset at the time
+    // isn't used because its DST rules may differ from the original one.
+    if offset % 3600 == 0 {
+        if let Ok(tz) = format!("Etc/GMT{:+}", -offset / 3600).parse() {
+            return Ok(tz);
+        }
+    }
+    let sign = if offset < 0 { '-' } else { '+' };
+    let offset = offset.abs();
+    Err(Error::OutOfRange(format!(
+        "no time zone in the tz database for the fixed offset {}{:02}:{:02}",
+        sign,
+        offset / 3600,
+        offset % 3600 / 60
+    )))
+}
+
+/// When the timestamp has no time zone region name, `UTC` or `Etc/GMT±n`
+/// is used according to the time zone offset. `Err(Error::OutOfRange(...))`
+/// is returned when the offset isn't whole hours. Use `DateTime<FixedOffset>`
+/// for such values.
+///
+/// Note that `TIMESTAMP WITH TIME ZONE` values fetched as they are have no
+/// region names. See [Timestamp.and_tz_region](struct.Timestamp.html#method.and_tz_region).
+impl FromSql for DateTime<Tz> {
+    fn from_sql(val: &SqlValue) -> Result<DateTime<Tz>> {
+        let ts = val.to_timestamp()?;
+        let naive = NaiveDate::from_ymd_opt(ts.year(), ts.month(), ts.day())
+            .and_then(|date| {
+                date.and_hms_nano_opt(ts.hour(), ts.minute(), ts.second(), ts.nanosecond())
+            })
+            .ok_or_else(|| Error::OutOfRange(format!("invalid date and/or time: {}", ts)))?;
+        let tz = tz_from_sql(&ts)?;
+        match tz.from_local_datetime(&naive) {
+            LocalResult::Single(dt) => Ok(dt),
+            // Use the time zone offset to choose one in the overlap at the end of DST.
+            LocalResult::Ambiguous(earliest, latest) => {
+                if latest.offset().fix().local_minus_utc() == ts.tz_offset() {
+                    Ok(latest)
+                } else {
+                    Ok(earliest)
+                }
+            }
+            LocalResult::None => Err(Error::OutOfRange(format!(
+                "{} doesn't exist in {}",
+                naive,
+                tz.name()
+            ))),
+        }
+    }
+}
+
+/// Converts `DateTime<Tz>` to `Timestamp` with the time zone region name.
+///
+/// `Err(Error::OutOfRange(...))` is returned when the time zone name isn't
+/// accepted by [Timestamp.and_tz_region][]. See it also about binding the
+/// region name to the database.
+///
+/// [Timestamp.and_tz_region]: struct.Timestamp.html#method.and_tz_region
+impl<'a> TryFrom<&'a DateTime<Tz>> for Timestamp {
+    type Err = Error;
+
+    fn try_from(dt: &'a DateTime<Tz>) -> Result<Timestamp> {
+        Timestamp::new(
+            dt.year(),
+            dt.month(),
+            dt.day(),
+            dt.hour(),
+            dt.minute(),
+            dt.second(),
+            dt.nanosecond(),
+        )
+        .and_tz_offset(dt.offset().fix().local_minus_utc())
+        .and_tz_region(dt.timezone().name())
+    }
+}
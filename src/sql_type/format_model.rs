@@ -0,0 +1,1197 @@
+// Rust-oracle - Rust binding for Oracle database
+//
+// URL: https://github.com/kubo/rust-oracle
+//
+//-----------------------------------------------------------------------------
+// Copyright (c) 2017-2018 Kubo Takehiro <kubo@jiubao.org>. All rights reserved.
+// This program is free software: you can modify it and/or redistribute it
+// under the terms of:
+//
+// (i)  the Universal Permissive License v 1.0 or at your option, any
+//      later version (http://oss.oracle.com/licenses/upl); and/or
+//
+// (ii) the Apache License v 2.0. (http://www.apache.org/licenses/LICENSE-2.0)
+//-----------------------------------------------------------------------------
+
+//
+// Datetime format models used by Timestamp::format and Timestamp::parse_with_format
+//
+// The format elements and their behavior follow TO_CHAR and TO_TIMESTAMP_TZ
+// in Oracle with NLS_DATE_LANGUAGE=AMERICAN and NLS_TERRITORY=AMERICA.
+//
+
+use std::time::SystemTime;
+use std::time::UNIX_EPOCH;
+
+use crate::sql_type::timestamp::check_date;
+use crate::sql_type::timestamp::date_to_julian_day;
+use crate::sql_type::timestamp::is_tz_region_char;
+use crate::sql_type::timestamp::julian_day_to_date;
+use crate::sql_type::timestamp::MAX_JULIAN_DAY;
+use crate::sql_type::timestamp::MIN_JULIAN_DAY;
+use crate::sql_type::Timestamp;
+use crate::Error;
+use crate::Result;
+
+// Julian day number of 1970-01-01
+const UNIX_EPOCH_DAY: i64 = 2440588;
+
+const MONTH_NAMES: [&str; 12] = [
+    "JANUARY",
+    "FEBRUARY",
+    "MARCH",
+    "APRIL",
+    "MAY",
+    "JUNE",
+    "JULY",
+    "AUGUST",
+    "SEPTEMBER",
+    "OCTOBER",
+    "NOVEMBER",
+    "DECEMBER",
+];
+
+const MONTH_ABBRS: [&str; 12] = [
+    "JAN", "FEB", "MAR", "APR", "MAY", "JUN", "JUL", "AUG", "SEP", "OCT", "NOV", "DEC",
+];
+
+const ROMAN_MONTHS: [&str; 12] = [
+    "I", "II", "III", "IV", "V", "VI", "VII", "VIII", "IX", "X", "XI", "XII",
+];
+
+// The first day is Sunday as NLS_TERRITORY=AMERICA.
+const DAY_NAMES: [&str; 7] = [
+    "SUNDAY",
+    "MONDAY",
+    "TUESDAY",
+    "WEDNESDAY",
+    "THURSDAY",
+    "FRIDAY",
+    "SATURDAY",
+];
+
+const DAY_ABBRS: [&str; 7] = ["SUN", "MON", "TUE", "WED", "THU", "FRI", "SAT"];
+
+// Names are padded to the length of the longest one unless FM is specified.
+const MONTH_NAME_WIDTH: usize = 9;
+const DAY_NAME_WIDTH: usize = 9;
+const ROMAN_MONTH_WIDTH: usize = 4;
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+enum Case {
+    Upper,
+    Initcap,
+    Lower,
+}
+
+#[allow(clippy::upper_case_acronyms)]
+#[derive(Clone, Copy, Debug, PartialEq)]
+enum Element {
+    AD,
+    ADPeriods,
+    AM,
+    AMPeriods,
+    CC,
+    SCC,
+    D,
+    DAY,
+    DD,
+    DDD,
+    DY,
+    FF(u8), // FF(0) is FF without digits
+    FM,
+    FX,
+    HH,
+    HH12,
+    HH24,
+    I,
+    IY,
+    IYY,
+    IYYY,
+    IW,
+    J,
+    MI,
+    MM,
+    MON,
+    MONTH,
+    Q,
+    RM,
+    RR,
+    RRRR,
+    SS,
+    SSSSS,
+    SYYYY,
+    TZH,
+    TZM,
+    TZR,
+    W,
+    WW,
+    X,
+    Y,
+    YCommaYYY,
+    YY,
+    YYY,
+    YYYY,
+}
+
+// Format elements, which are case-insensitive. The longest one is used
+// when more than one match.
+const ELEMENTS: &[(&str, Element)] = &[
+    ("A.D.", Element::ADPeriods),
+    ("A.M.", Element::AMPeriods),
+    ("AD", Element::AD),
+    ("AM", Element::AM),
+    ("B.C.", Element::ADPeriods),
+    ("BC", Element::AD),
+    ("CC", Element::CC),
+    ("D", Element::D),
+    ("DAY", Element::DAY),
+    ("DD", Element::DD),
+    ("DDD", Element::DDD),
+    ("DY", Element::DY),
+    ("FF", Element::FF(0)),
+    ("FF1", Element::FF(1)),
+    ("FF2", Element::FF(2)),
+    ("FF3", Element::FF(3)),
+    ("FF4", Element::FF(4)),
+    ("FF5", Element::FF(5)),
+    ("FF6", Element::FF(6)),
+    ("FF7", Element::FF(7)),
+    ("FF8", Element::FF(8)),
+    ("FF9", Element::FF(9)),
+    ("FM", Element::FM),
+    ("FX", Element::FX),
+    ("HH", Element::HH),
+    ("HH12", Element::HH12),
+    ("HH24", Element::HH24),
+    ("I", Element::I),
+    ("IW", Element::IW),
+    ("IY", Element::IY),
+    ("IYY", Element::IYY),
+    ("IYYY", Element::IYYY),
+    ("J", Element::J),
+    ("MI", Element::MI),
+    ("MM", Element::MM),
+    ("MON", Element::MON),
+    ("MONTH", Element::MONTH),
+    ("P.M.", Element::AMPeriods),
+    ("PM", Element::AM),
+    ("Q", Element::Q),
+    ("RM", Element::RM),
+    ("RR", Element::RR),
+    ("RRRR", Element::RRRR),
+    ("SCC", Element::SCC),
+    ("SS", Element::SS),
+    ("SSSSS", Element::SSSSS),
+    ("SYYYY", Element::SYYYY),
+    ("TZH", Element::TZH),
+    ("TZM", Element::TZM),
+    ("TZR", Element::TZR),
+    ("W", Element::W),
+    ("WW", Element::WW),
+    ("X", Element::X),
+    ("Y", Element::Y),
+    ("Y,YYY", Element::YCommaYYY),
+    ("YY", Element::YY),
+    ("YYY", Element::YYY),
+    ("YYYY", Element::YYYY),
+];
+
+enum Token {
+    Element(Element, Case),
+    Punct(char),
+    Text(String),
+}
+
+fn parse_error(msg: String) -> Error {
+    Error::ParseError(msg.into())
+}
+
+fn out_of_range(msg: &str) -> Error {
+    Error::OutOfRange(msg.to_string())
+}
+
+// The case of names is determined by the first and second letters of
+// the format element as Oracle does.
+fn case_of(chars: &[char]) -> Case {
+    let mut letters = chars.iter().filter(|c| c.is_alphabetic());
+    match (letters.next(), letters.next()) {
+        (Some(c), _) if c.is_lowercase() => Case::Lower,
+        (_, Some(c)) if c.is_lowercase() => Case::Initcap,
+        _ => Case::Upper,
+    }
+}
+
+fn apply_case(s: &str, case: Case) -> String {
+    match case {
+        Case::Upper => s.to_string(),
+        Case::Initcap => {
+            let mut prev_is_alpha = false;
+            s.chars()
+                .map(|c| {
+                    let c = if prev_is_alpha {
+                        c.to_ascii_lowercase()
+                    } else {
+                        c
+                    };
+                    prev_is_alpha = c.is_alphabetic();
+                    c
+                })
+                .collect()
+        }
+        Case::Lower => s.to_ascii_lowercase(),
+    }
+}
+
+fn tokenize(fmt: &str) -> Result<Vec<Token>> {
+    let err = || parse_error(format!("date format not recognized: {}", fmt));
+    let chars: Vec<char> = fmt.chars().collect();
+    let mut tokens = Vec::new();
+    let mut pos = 0;
+    while pos < chars.len() {
+        let c = chars[pos];
+        if c == '"' {
+            let len = chars[pos + 1..]
+                .iter()
+                .position(|c| *c == '"')
+                .ok_or_else(err)?;
+            tokens.push(Token::Text(chars[pos + 1..pos + 1 + len].iter().collect()));
+            pos += len + 2;
+        } else if !c.is_alphanumeric() {
+            tokens.push(Token::Punct(c));
+            pos += 1;
+        } else {
+            let mut matched = None;
+            for &(name, elem) in ELEMENTS {
+                let len = name.len();
+                if pos + len <= chars.len()
+                    && chars[pos..pos + len]
+                        .iter()
+                        .zip(name.chars())
+                        .all(|(a, b)| a.to_ascii_uppercase() == b)
+                    && matched.map_or(true, |(matched_len, _)| matched_len < len)
+                {
+                    matched = Some((len, elem));
+                }
+            }
+            let (len, elem) = matched.ok_or_else(err)?;
+            tokens.push(Token::Element(elem, case_of(&chars[pos..pos + len])));
+            pos += len;
+        }
+    }
+    Ok(tokens)
+}
+
+// 0 is Sunday.
+fn day_of_week(jdn: i64) -> usize {
+    ((jdn + 1) % 7) as usize
+}
+
+// ISO 8601 week-numbering year and week number
+fn iso_year_and_week(jdn: i64) -> (i32, u32) {
+    let thursday = jdn - jdn % 7 + 3; // jdn % 7 is 0 on Monday.
+    let (year, _, _) = julian_day_to_date(thursday);
+    let week = (thursday - date_to_julian_day(year, 1, 1)) / 7 + 1;
+    (year, week as u32)
+}
+
+fn number(n: i64, width: usize, fm: bool) -> String {
+    if fm {
+        n.to_string()
+    } else {
+        format!("{:01$}", n, width)
+    }
+}
+
+fn name(s: &str, width: usize, case: Case, fm: bool) -> String {
+    let s = apply_case(s, case);
+    if fm {
+        s
+    } else {
+        format!("{:1$}", s, width)
+    }
+}
+
+pub(crate) fn format(ts: &Timestamp, fmt: &str) -> Result<String> {
+    // Timestamp::new doesn't check the fields used as indexes below.
+    Timestamp::try_new(
+        ts.year(),
+        ts.month(),
+        ts.day(),
+        ts.hour(),
+        ts.minute(),
+        ts.second(),
+        ts.nanosecond(),
+    )?;
+    let year = ts.year();
+    let abs_year = year.abs() as i64;
+    let jdn = date_to_julian_day(year, ts.month(), ts.day());
+    let day_of_year = jdn - date_to_julian_day(year, 1, 1) + 1;
+    let sign = |fm| {
+        if year < 0 {
+            "-"
+        } else if fm {
+            ""
+        } else {
+            " "
+        }
+    };
+    let mut fm = false;
+    let mut s = String::new();
+    for token in tokenize(fmt)? {
+        let (elem, case) = match token {
+            Token::Element(elem, case) => (elem, case),
+            Token::Punct(c) => {
+                s.push(c);
+                continue;
+            }
+            Token::Text(text) => {
+                s.push_str(&text);
+                continue;
+            }
+        };
+        let text = match elem {
+            Element::AD => apply_case(if year < 0 { "BC" } else { "AD" }, case),
+            Element::ADPeriods => apply_case(if year < 0 { "B.C." } else { "A.D." }, case),
+            Element::AM => apply_case(if ts.hour() < 12 { "AM" } else { "PM" }, case),
+            Element::AMPeriods => apply_case(if ts.hour() < 12 { "A.M." } else { "P.M." }, case),
+            Element::CC => number((abs_year + 99) / 100, 2, fm),
+            Element::SCC => sign(fm).to_string() + &number((abs_year + 99) / 100, 2, fm),
+            Element::D => number(day_of_week(jdn) as i64 + 1, 1, fm),
+            Element::DAY => name(DAY_NAMES[day_of_week(jdn)], DAY_NAME_WIDTH, case, fm),
+            Element::DD => number(ts.day() as i64, 2, fm),
+            Element::DDD => number(day_of_year, 3, fm),
+            Element::DY => apply_case(DAY_ABBRS[day_of_week(jdn)], case),
+            Element::FF(digits) => {
+                // The precision set by and_prec isn't checked.
+                let digits = if digits == 0 { ts.precision() } else { digits }.min(9) as u32;
+                if digits == 0 {
+                    String::new()
+                } else {
+                    let n = ts.nanosecond() / 10u32.pow(9 - digits);
+                    format!("{:01$}", n, digits as usize)
+                }
+            }
+            Element::FM => {
+                fm = !fm;
+                continue;
+            }
+            Element::FX => continue,
+            Element::HH | Element::HH12 => {
+                let hour = ts.hour() % 12;
+                number(if hour == 0 { 12 } else { hour } as i64, 2, fm)
+            }
+            Element::HH24 => number(ts.hour() as i64, 2, fm),
+            Element::I => number(iso_year_and_week(jdn).0.abs() as i64 % 10, 1, fm),
+            Element::IY => number(iso_year_and_week(jdn).0.abs() as i64 % 100, 2, fm),
+            Element::IYY => number(iso_year_and_week(jdn).0.abs() as i64 % 1000, 3, fm),
+            Element::IYYY => number(iso_year_and_week(jdn).0.abs() as i64, 4, fm),
+            Element::IW => number(iso_year_and_week(jdn).1 as i64, 2, fm),
+            Element::J => number(jdn, 7, fm),
+            Element::MI => number(ts.minute() as i64, 2, fm),
+            Element::MM => number(ts.month() as i64, 2, fm),
+            Element::MON => apply_case(MONTH_ABBRS[ts.month() as usize - 1], case),
+            Element::MONTH => name(
+                MONTH_NAMES[ts.month() as usize - 1],
+                MONTH_NAME_WIDTH,
+                case,
+                fm,
+            ),
+            Element::Q => number((ts.month() as i64 - 1) / 3 + 1, 1, fm),
+            Element::RM => name(
+                ROMAN_MONTHS[ts.month() as usize - 1],
+                ROMAN_MONTH_WIDTH,
+                case,
+                fm,
+            ),
+            Element::RR | Element::YY => number(abs_year % 100, 2, fm),
+            Element::RRRR | Element::YYYY => number(abs_year, 4, fm),
+            Element::SS => number(ts.second() as i64, 2, fm),
+            Element::SSSSS => number(
+                (ts.hour() * 3600 + ts.minute() * 60 + ts.second()) as i64,
+                5,
+                fm,
+            ),
+            Element::SYYYY => sign(fm).to_string() + &number(abs_year, 4, fm),
+            Element::TZH => {
+                let sign = if ts.tz_hour_offset() < 0 || ts.tz_minute_offset() < 0 {
+                    '-'
+                } else {
+                    '+'
+                };
+                format!("{}{:02}", sign, ts.tz_hour_offset().abs())
+            }
+            Element::TZM => format!("{:02}", ts.tz_minute_offset().abs()),
+            Element::TZR => match ts.tz_region() {
+                Some(region) => region.to_string(),
+                None => format!(
+                    "{}{:02}:{:02}",
+                    if ts.tz_offset() < 0 { '-' } else { '+' },
+                    ts.tz_hour_offset().abs(),
+                    ts.tz_minute_offset().abs()
+                ),
+            },
+            Element::W => number((ts.day() as i64 - 1) / 7 + 1, 1, fm),
+            Element::WW => number((day_of_year - 1) / 7 + 1, 2, fm),
+            Element::X => ".".to_string(),
+            Element::Y => number(abs_year % 10, 1, fm),
+            Element::YCommaYYY => format!("{},{:03}", abs_year / 1000, abs_year % 1000),
+            Element::YYY => number(abs_year % 1000, 3, fm),
+        };
+        s.push_str(&text);
+    }
+    Ok(s)
+}
+
+#[derive(Default)]
+struct Fields {
+    year: Option<i32>,
+    bc: Option<bool>,
+    month: Option<u32>,
+    day: Option<u32>,
+    day_of_year: Option<u32>,
+    day_of_week: Option<usize>,
+    julian_day: Option<i64>,
+    hour: Option<u32>,
+    hour12: Option<u32>,
+    pm: Option<bool>,
+    minute: Option<u32>,
+    second: Option<u32>,
+    seconds_of_day: Option<u32>,
+    nanosecond: Option<u32>,
+    precision: u8,
+    tz_negative: bool,
+    tz_hour: Option<i32>,
+    tz_minute: Option<i32>,
+    tz_region: Option<String>,
+}
+
+fn set<T>(field: &mut Option<T>, value: T) -> Result<()> {
+    if field.is_some() {
+        return Err(parse_error("format code appears twice".to_string()));
+    }
+    *field = Some(value);
+    Ok(())
+}
+
+struct Parser {
+    chars: Vec<char>,
+    pos: usize,
+    fm: bool,
+    fx: bool,
+}
+
+impl Parser {
+    fn peek(&self) -> Option<char> {
+        self.chars.get(self.pos).cloned()
+    }
+
+    fn mismatch(&self) -> Error {
+        parse_error("literal does not match format string".to_string())
+    }
+
+    fn skip_spaces(&mut self) {
+        if !self.fx {
+            while let Some(' ') = self.peek() {
+                self.pos += 1;
+            }
+        }
+    }
+
+    // Reads up to `max_digits` digits. Exactly `max_digits` digits are
+    // required in FX mode without FM.
+    fn read_number(&mut self, max_digits: usize) -> Result<(u32, usize)> {
+        self.skip_spaces();
+        let mut n = 0;
+        let mut ndigits = 0;
+        while ndigits < max_digits {
+            match self.peek().and_then(|c| c.to_digit(10)) {
+                Some(digit) => n = n * 10 + digit,
+                None => break,
+            }
+            self.pos += 1;
+            ndigits += 1;
+        }
+        if ndigits == 0 || (self.fx && !self.fm && ndigits != max_digits) {
+            return Err(self.mismatch());
+        }
+        Ok((n, ndigits))
+    }
+
+    fn read_sign(&mut self) -> bool {
+        self.skip_spaces();
+        match self.peek() {
+            Some('-') => {
+                self.pos += 1;
+                true
+            }
+            Some('+') => {
+                self.pos += 1;
+                false
+            }
+            _ => false,
+        }
+    }
+
+    // Returns the index of the longest word matching case-insensitively.
+    fn read_word(&mut self, words: &[&str]) -> Result<usize> {
+        self.skip_spaces();
+        let rest = &self.chars[self.pos..];
+        let mut matched: Option<(usize, usize)> = None;
+        for (idx, word) in words.iter().enumerate() {
+            let len = word.chars().count();
+            if len <= rest.len()
+                && rest
+                    .iter()
+                    .zip(word.chars())
+                    .all(|(a, b)| a.to_ascii_uppercase() == b)
+                && matched.map_or(true, |(_, matched_len)| matched_len < len)
+            {
+                matched = Some((idx, len));
+            }
+        }
+        let (idx, len) = matched.ok_or_else(|| self.mismatch())?;
+        self.pos += len;
+        Ok(idx)
+    }
+
+    fn read_punct(&mut self, punct: char, keep_sign: bool) -> Result<()> {
+        if self.fx {
+            if self.peek() != Some(punct) {
+                return Err(self.mismatch());
+            }
+            self.pos += 1;
+        } else {
+            // Any punctuation characters or none match punctuation
+            // in the format.
+            while let Some(c) = self.peek() {
+                if c.is_alphanumeric() || (keep_sign && (c == '+' || c == '-')) {
+                    break;
+                }
+                self.pos += 1;
+            }
+        }
+        Ok(())
+    }
+
+    fn read_text(&mut self, text: &str) -> Result<()> {
+        for c in text.chars() {
+            match self.peek() {
+                Some(d) if d.to_lowercase().eq(c.to_lowercase()) => self.pos += 1,
+                _ => return Err(self.mismatch()),
+            }
+        }
+        Ok(())
+    }
+}
+
+// Years with less than four digits are completed by the current year for
+// Y, YY and YYY. For RR and RRRR, years with two or less digits are in the
+// century making the year nearest to the current year.
+fn complete_year(year: u32, ndigits: usize, current_year: i32, rr: bool) -> i32 {
+    let year = year as i32;
+    if rr && ndigits <= 2 {
+        let century = current_year - current_year % 100;
+        match (current_year % 100 < 50, year < 50) {
+            (true, false) => century - 100 + year,
+            (false, true) => century + 100 + year,
+            _ => century + year,
+        }
+    } else if ndigits < 4 {
+        let unit = 10i32.pow(ndigits as u32);
+        current_year - current_year % unit + year
+    } else {
+        year
+    }
+}
+
+// The date in UTC. Oracle uses the local date of the database server.
+fn current_year_and_month() -> (i32, u32) {
+    let days = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|dur| dur.as_secs() / 86400)
+        .unwrap_or(0);
+    let (year, month, _) = julian_day_to_date(UNIX_EPOCH_DAY + days as i64);
+    (year, month)
+}
+
+pub(crate) fn parse(s: &str, fmt: &str) -> Result<Timestamp> {
+    parse_with_current_date(s, fmt, current_year_and_month())
+}
+
+// `current` is used when the year and/or the month are omitted as Oracle does.
+fn parse_with_current_date(s: &str, fmt: &str, current: (i32, u32)) -> Result<Timestamp> {
+    let tokens = tokenize(fmt)?;
+    let mut p = Parser {
+        chars: s.chars().collect(),
+        pos: 0,
+        fm: false,
+        fx: false,
+    };
+    let mut f = Fields::default();
+    let mut with_tz = false;
+    for (idx, token) in tokens.iter().enumerate() {
+        let (elem, _) = match *token {
+            Token::Element(elem, case) => (elem, case),
+            Token::Punct(c) => {
+                let keep_sign = match tokens.get(idx + 1) {
+                    Some(Token::Element(Element::SYYYY, _))
+                    | Some(Token::Element(Element::TZH, _))
+                    | Some(Token::Element(Element::TZR, _)) => true,
+                    _ => false,
+                };
+                p.read_punct(c, keep_sign)?;
+                continue;
+            }
+            Token::Text(ref text) => {
+                p.read_text(text)?;
+                continue;
+            }
+        };
+        match elem {
+            Element::AD | Element::ADPeriods => {
+                let idx = p.read_word(&["AD", "BC", "A.D.", "B.C."])?;
+                set(&mut f.bc, idx % 2 == 1)?;
+            }
+            Element::AM | Element::AMPeriods => {
+                let idx = p.read_word(&["AM", "PM", "A.M.", "P.M."])?;
+                set(&mut f.pm, idx % 2 == 1)?;
+            }
+            Element::CC
+            | Element::SCC
+            | Element::I
+            | Element::IY
+            | Element::IYY
+            | Element::IYYY
+            | Element::IW
+            | Element::Q
+            | Element::W
+            | Element::WW => {
+                return Err(parse_error(
+                    "format code cannot appear in date input format".to_string(),
+                ));
+            }
+            Element::D => {
+                let (n, _) = p.read_number(1)?;
+                if n < 1 || 7 < n {
+                    return Err(out_of_range("day of week must be between 1 and 7"));
+                }
+                set(&mut f.day_of_week, n as usize - 1)?;
+            }
+            Element::DAY | Element::DY => {
+                let mut words = DAY_NAMES.to_vec();
+                words.extend_from_slice(&DAY_ABBRS);
+                let idx = p.read_word(&words)?;
+                set(&mut f.day_of_week, idx % 7)?;
+            }
+            Element::DD => {
+                let (n, _) = p.read_number(2)?;
+                set(&mut f.day, n)?;
+            }
+            Element::DDD => {
+                let (n, _) = p.read_number(3)?;
+                set(&mut f.day_of_year, n)?;
+            }
+            Element::FF(digits) => {
+                let max_digits = if digits == 0 {
+                    9
+                } else {
+                    digits.min(9) as usize
+                };
+                let (n, ndigits) = p.read_number(max_digits)?;
+                set(&mut f.nanosecond, n * 10u32.pow(9 - ndigits as u32))?;
+                f.precision = if digits == 0 { ndigits as u8 } else { digits };
+            }
+            Element::FM => p.fm = !p.fm,
+            Element::FX => p.fx = !p.fx,
+            Element::HH | Element::HH12 => {
+                let (n, _) = p.read_number(2)?;
+                if n < 1 || 12 < n {
+                    return Err(out_of_range("hour must be between 1 and 12"));
+                }
+                set(&mut f.hour12, n)?;
+            }
+            Element::HH24 => {
+                let (n, _) = p.read_number(2)?;
+                if n > 23 {
+                    return Err(out_of_range("hour must be between 0 and 23"));
+                }
+                set(&mut f.hour, n)?;
+            }
+            Element::J => {
+                let (n, _) = p.read_number(7)?;
+                let jdn = n as i64;
+                if jdn < MIN_JULIAN_DAY || MAX_JULIAN_DAY < jdn {
+                    return Err(out_of_range("julian date must be between 1 and 5373484"));
+                }
+                set(&mut f.julian_day, jdn)?;
+            }
+            Element::MI => {
+                let (n, _) = p.read_number(2)?;
+                if n > 59 {
+                    return Err(out_of_range("minutes must be between 0 and 59"));
+                }
+                set(&mut f.minute, n)?;
+            }
+            Element::MM => {
+                let (n, _) = p.read_number(2)?;
+                set(&mut f.month, n)?;
+            }
+            Element::MON | Element::MONTH => {
+                let mut words = MONTH_NAMES.to_vec();
+                words.extend_from_slice(&MONTH_ABBRS);
+                let idx = p.read_word(&words)?;
+                set(&mut f.month, idx as u32 % 12 + 1)?;
+            }
+            Element::RM => {
+                let idx = p.read_word(&ROMAN_MONTHS)?;
+                set(&mut f.month, idx as u32 + 1)?;
+            }
+            Element::RR | Element::RRRR => {
+                let (n, ndigits) = p.read_number(4)?;
+                set(&mut f.year, complete_year(n, ndigits, current.0, true))?;
+            }
+            Element::SS => {
+                let (n, _) = p.read_number(2)?;
+                if n > 59 {
+                    return Err(out_of_range("seconds must be between 0 and 59"));
+                }
+                set(&mut f.second, n)?;
+            }
+            Element::SSSSS => {
+                let (n, _) = p.read_number(5)?;
+                if n > 86399 {
+                    return Err(out_of_range("seconds in day must be between 0 and 86399"));
+                }
+                set(&mut f.seconds_of_day, n)?;
+            }
+            Element::SYYYY => {
+                let negative = p.read_sign();
+                let (n, _) = p.read_number(4)?;
+                set(&mut f.year, n as i32)?;
+                if negative {
+                    set(&mut f.bc, true)?;
+                }
+            }
+            Element::TZH => {
+                f.tz_negative = p.read_sign();
+                let (n, _) = p.read_number(2)?;
+                set(&mut f.tz_hour, n as i32)?;
+                with_tz = true;
+            }
+            Element::TZM => {
+                let (n, _) = p.read_number(2)?;
+                if n > 59 {
+                    return Err(out_of_range("time zone minute must be between 0 and 59"));
+                }
+                set(&mut f.tz_minute, n as i32)?;
+                with_tz = true;
+            }
+            Element::TZR => {
+                p.skip_spaces();
+                match p.peek() {
+                    Some('+') | Some('-') => {
+                        f.tz_negative = p.read_sign();
+                        let (n, _) = p.read_number(2)?;
+                        set(&mut f.tz_hour, n as i32)?;
+                        if let Some(':') = p.peek() {
+                            p.pos += 1;
+                            let (n, _) = p.read_number(2)?;
+                            if n > 59 {
+                                return Err(out_of_range(
+                                    "time zone minute must be between 0 and 59",
+                                ));
+                            }
+                            set(&mut f.tz_minute, n as i32)?;
+                        }
+                    }
+                    Some(c) if c.is_alphabetic() => {
+                        let mut region = String::new();
+                        while let Some(c) = p.peek() {
+                            if !is_tz_region_char(c) {
+                                break;
+                            }
+                            region.push(c);
+                            p.pos += 1;
+                        }
+                        set(&mut f.tz_region, region)?;
+                    }
+                    _ => return Err(p.mismatch()),
+                }
+                with_tz = true;
+            }
+            Element::X => {
+                if p.peek() != Some('.') {
+                    return Err(p.mismatch());
+                }
+                p.pos += 1;
+            }
+            Element::Y | Element::YY | Element::YYY | Element::YYYY => {
+                let max_digits = match elem {
+                    Element::Y => 1,
+                    Element::YY => 2,
+                    Element::YYY => 3,
+                    _ => 4,
+                };
+                let (n, _) = p.read_number(max_digits)?;
+                set(&mut f.year, complete_year(n, max_digits, current.0, false))?;
+            }
+            Element::YCommaYYY => {
+                let (thousands, _) = p.read_number(1)?;
+                if p.peek() != Some(',') {
+                    return Err(p.mismatch());
+                }
+                p.pos += 1;
+                let (n, _) = p.read_number(3)?;
+                set(&mut f.year, (thousands * 1000 + n) as i32)?;
+            }
+        }
+    }
+    if !p.fx {
+        while let Some(c) = p.peek() {
+            if !c.is_whitespace() {
+                break;
+            }
+            p.pos += 1;
+        }
+    }
+    if p.pos < p.chars.len() {
+        return Err(parse_error(
+            "date format picture ends before converting entire input string".to_string(),
+        ));
+    }
+
+    // date
+    let (year, month, day) = match f.julian_day {
+        Some(jdn) => {
+            let (year, month, day) = julian_day_to_date(jdn);
+            if f.year.map_or(false, |y| y != year.abs())
+                || f.bc.map_or(false, |bc| bc != (year < 0))
+                || f.month.map_or(false, |m| m != month)
+                || f.day.map_or(false, |d| d != day)
+                || f.day_of_year.is_some()
+            {
+                return Err(out_of_range("Julian date conflicts with other fields"));
+            }
+            (year, month, day)
+        }
+        None => {
+            let year = match (f.year.unwrap_or(current.0), f.bc) {
+                (year, Some(true)) => -year,
+                (year, _) => year,
+            };
+            check_date(year, 1, 1)?;
+            let (month, day) = match f.day_of_year {
+                Some(day_of_year) => {
+                    let jdn = date_to_julian_day(year, 1, 1) + day_of_year as i64 - 1;
+                    let (y, m, d) = julian_day_to_date(jdn);
+                    if day_of_year == 0 || y != year {
+                        return Err(out_of_range(
+                            "day of year must be between 1 and 365 (366 for leap year)",
+                        ));
+                    }
+                    if f.month.map_or(false, |month| month != m)
+                        || f.day.map_or(false, |day| day != d)
+                    {
+                        return Err(out_of_range("day of year conflicts with Julian date"));
+                    }
+                    (m, d)
+                }
+                None => (f.month.unwrap_or(current.1), f.day.unwrap_or(1)),
+            };
+            check_date(year, month, day)?;
+            (year, month, day)
+        }
+    };
+    let jdn = date_to_julian_day(year, month, day);
+    if f.day_of_week.map_or(false, |dow| dow != day_of_week(jdn)) {
+        return Err(out_of_range("day of week conflicts with Julian date"));
+    }
+
+    // time
+    let mut hour = match (f.hour, f.hour12) {
+        (Some(_), Some(_)) => {
+            return Err(parse_error("format code appears twice".to_string()));
+        }
+        (Some(hour), None) => hour,
+        (None, Some(hour)) => hour % 12 + if f.pm == Some(true) { 12 } else { 0 },
+        (None, None) => 0,
+    };
+    let mut minute = f.minute.unwrap_or(0);
+    let mut second = f.second.unwrap_or(0);
+    if let Some(secs) = f.seconds_of_day {
+        let (h, m, s) = (secs / 3600, secs / 60 % 60, secs % 60);
+        if (f.hour.is_some() || f.hour12.is_some()) && hour != h
+            || f.minute.is_some() && minute != m
+            || f.second.is_some() && second != s
+        {
+            return Err(out_of_range(
+                "seconds of day conflicts with seconds of hour, minute or second",
+            ));
+        }
+        hour = h;
+        minute = m;
+        second = s;
+    }
+
+    let mut ts = Timestamp::new(
+        year,
+        month,
+        day,
+        hour,
+        minute,
+        second,
+        f.nanosecond.unwrap_or(0),
+    )
+    .and_prec(f.precision);
+    if with_tz {
+        let tz_hour = f.tz_hour.unwrap_or(0);
+        let tz_minute = f.tz_minute.unwrap_or(0);
+        if tz_hour * 60 + tz_minute
+            > if f.tz_negative {
+                12 * 60 + 59
+            } else {
+                14 * 60 + 59
+            }
+        {
+            return Err(out_of_range("time zone hour must be between -12 and 14"));
+        }
+        ts = if f.tz_negative {
+            ts.and_tz_hm_offset(-tz_hour, -tz_minute)
+        } else {
+            ts.and_tz_hm_offset(tz_hour, tz_minute)
+        };
+        if let Some(region) = f.tz_region {
+            ts = ts.and_tz_region(&region)?;
+        }
+    }
+    Ok(ts)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn format_elements() {
+        let ts = Timestamp::new(2017, 8, 9, 23, 2, 3, 123456789).and_tz_hm_offset(-8, -30);
+        let f = |fmt| format(&ts, fmt).unwrap();
+        assert_eq!(
+            f("YYYY-MM-DD HH24:MI:SS.FF"),
+            "2017-08-09 23:02:03.123456789"
+        );
+        assert_eq!(
+            f("yyyy/mm/dd hh:mi:ss.ff3 am"),
+            "2017/08/09 11:02:03.123 pm"
+        );
+        assert_eq!(
+            f("Y,YYY YYY YY Y RR RRRR SYYYY"),
+            "2,017 017 17 7 17 2017  2017"
+        );
+        assert_eq!(f("CC SCC FMSCC"), "21  21 21");
+        assert_eq!(
+            f("MON Mon mon MONTH Month"),
+            "AUG Aug aug AUGUST    August   "
+        );
+        assert_eq!(f("FMMonth FMMonth"), "August August   ");
+        assert_eq!(f("RM rm FMRM"), "VIII viii VIII");
+        assert_eq!(
+            f("DAY Day DY Dy D DDD"),
+            "WEDNESDAY Wednesday WED Wed 4 221"
+        );
+        assert_eq!(f("Q W WW IW IYYY IYY IY I"), "3 2 32 32 2017 017 17 7");
+        assert_eq!(f("HH HH12 HH24 SSSSS"), "11 11 23 82923");
+        assert_eq!(
+            f("A.M. P.M. a.m. AD BC A.D. b.c."),
+            "P.M. P.M. p.m. AD AD A.D. a.d."
+        );
+        assert_eq!(f("SSXFF1 FF5 FF9"), "03.1 12345 123456789");
+        assert_eq!(format(&ts.and_prec(12), "FF").unwrap(), "123456789");
+        assert_eq!(f("TZH:TZM TZR"), "-08:30 -08:30");
+        assert_eq!(f("\"Quarter\" Q, \"YYYY\""), "Quarter 3, YYYY");
+        assert_eq!(f("FMDD/MM/YYYY HH24:MI:SS"), "9/8/2017 23:2:3");
+        assert_eq!(f("FMDD/MM/FMYYYY"), "9/8/2017");
+
+        let ts = Timestamp::new(-44, 3, 15, 0, 5, 0, 0).and_prec(0);
+        let f = |fmt| format(&ts, fmt).unwrap();
+        assert_eq!(f("SYYYY-MM-DD HH:MI AM BC"), "-0044-03-15 12:05 AM BC");
+        assert_eq!(f("YYYY FMYYYY SCC FF"), "0044 44 -1 ");
+        let ts = ts.and_tz_region("Europe/Madrid").unwrap();
+        assert_eq!(format(&ts, "TZR").unwrap(), "Europe/Madrid");
+
+        let date = |y, m, d| Timestamp::new(y, m, d, 0, 0, 0, 0);
+        assert_eq!(format(&date(2019, 1, 6), "D DY").unwrap(), "1 SUN");
+        assert_eq!(format(&date(2021, 1, 1), "IYYY-IW").unwrap(), "2020-53");
+        assert_eq!(format(&date(2018, 12, 31), "IYYY-IW").unwrap(), "2019-01");
+        assert_eq!(format(&date(2000, 12, 31), "CC DDD").unwrap(), "20 366");
+        assert_eq!(format(&date(1582, 10, 15), "DY DDD").unwrap(), "FRI 278");
+        assert_eq!(format(&date(2000, 1, 1), "J").unwrap(), "2451545");
+        assert_eq!(format(&date(-4712, 1, 1), "J FMJ").unwrap(), "0000001 1");
+
+        assert!(format(&ts, "YYYY-MM-DD HH24:MI:SS.FF BAD").is_err());
+        assert!(format(&ts, "\"unterminated").is_err());
+    }
+
+    #[test]
+    fn parse_elements() {
+        let parse = |s, fmt| parse_with_current_date(s, fmt, (2019, 5));
+        let ts = Timestamp::new(2017, 8, 9, 23, 2, 3, 123000000).and_prec(3);
+        assert_eq!(
+            parse("2017-08-09 23:02:03.123", "YYYY-MM-DD HH24:MI:SS.FF").unwrap(),
+            ts
+        );
+        assert_eq!(
+            parse("2017/8/9 11:2:3.123 PM", "YYYY-MM-DD HH:MI:SS.FF3 AM").unwrap(),
+            ts
+        );
+        assert_eq!(
+            parse("20170809230203123", "YYYYMMDDHH24MISSFF").unwrap(),
+            ts
+        );
+        assert_eq!(
+            parse("09-aug-2017 82923.123", "DD-MON-YYYY SSSSS.FF").unwrap(),
+            ts
+        );
+        assert_eq!(
+            parse(
+                "Wednesday, August 9, 2017 11:02:03.123 p.m.",
+                "Day, Month DD, YYYY HH:MI:SS.FF A.M."
+            )
+            .unwrap(),
+            ts
+        );
+        assert_eq!(
+            parse("2017 221 23:02:03.123", "YYYY DDD HH24:MI:SS.FF").unwrap(),
+            ts
+        );
+        assert_eq!(
+            parse("VIII/9/2,017 23.02.03X123", "RM/DD/Y,YYY HH24.MI.SS\"X\"FF").unwrap(),
+            ts
+        );
+
+        // Julian day
+        let date = |y, m, d| Timestamp::new(y, m, d, 0, 0, 0, 0).and_prec(0);
+        assert_eq!(parse("2451545", "J").unwrap(), date(2000, 1, 1));
+        assert_eq!(parse("1", "J").unwrap(), date(-4712, 1, 1));
+        assert_eq!(
+            parse("2299160 12", "J HH24").unwrap(),
+            Timestamp::new(1582, 10, 4, 12, 0, 0, 0).and_prec(0)
+        );
+        assert_eq!(parse("2000 2451545", "YYYY J").unwrap(), date(2000, 1, 1));
+        assert!(parse("2001 2451545", "YYYY J").is_err());
+        assert!(parse("0", "J").is_err());
+        assert!(parse("5373485", "J").is_err());
+
+        // omitted fields
+        assert_eq!(parse("10", "DD").unwrap(), date(2019, 5, 10));
+        assert_eq!(parse("2", "MM").unwrap(), date(2019, 2, 1));
+        assert_eq!(parse("1999", "YYYY").unwrap(), date(1999, 5, 1));
+
+        // two-digit years
+        assert_eq!(parse("49", "RR").unwrap(), date(2049, 5, 1));
+        assert_eq!(parse("50", "RR").unwrap(), date(1950, 5, 1));
+        assert_eq!(parse("50", "RRRR").unwrap(), date(1950, 5, 1));
+        assert_eq!(parse("1850", "RR").unwrap(), date(1850, 5, 1));
+        assert_eq!(parse("50", "YY").unwrap(), date(2050, 5, 1));
+        assert_eq!(parse("7", "Y").unwrap(), date(2017, 5, 1));
+        let parse_in_2060 = |s, fmt| parse_with_current_date(s, fmt, (2060, 1));
+        assert_eq!(parse_in_2060("10", "RR").unwrap(), date(2110, 1, 1));
+        assert_eq!(parse_in_2060("70", "RR").unwrap(), date(2070, 1, 1));
+
+        // BC
+        assert_eq!(
+            parse("-0044-03-15", "SYYYY-MM-DD").unwrap(),
+            date(-44, 3, 15)
+        );
+        assert_eq!(
+            parse("44-03-15 BC", "YYYY-MM-DD AD").unwrap(),
+            date(-44, 3, 15)
+        );
+        assert_eq!(
+            parse("44-03-15 b.c.", "YYYY-MM-DD A.D.").unwrap(),
+            date(-44, 3, 15)
+        );
+
+        // time zone
+        let ts = Timestamp::new(2019, 7, 1, 10, 0, 0, 0).and_prec(0);
+        assert_eq!(
+            parse("2019-07-01 10:00 -08:30", "YYYY-MM-DD HH24:MI TZH:TZM").unwrap(),
+            ts.and_tz_hm_offset(-8, -30)
+        );
+        assert_eq!(
+            parse("2019-07-01 10:00 +0530", "YYYY-MM-DD HH24:MI TZHTZM").unwrap(),
+            ts.and_tz_hm_offset(5, 30)
+        );
+        assert_eq!(
+            parse("2019-07-01 10:00 -03:00", "YYYY-MM-DD HH24:MI TZR").unwrap(),
+            ts.and_tz_hm_offset(-3, 0)
+        );
+        let ts_tzr = parse("2019-07-01 10:00 Europe/Madrid", "YYYY-MM-DD HH24:MI TZR").unwrap();
+        assert_eq!(ts_tzr.tz_region(), Some("Europe/Madrid"));
+        assert!(ts_tzr.with_tz());
+        assert!(parse("2019-07-01 10:00 +05:60", "YYYY-MM-DD HH24:MI TZR").is_err());
+        assert!(parse("2019-07-01 10:00 +15:00", "YYYY-MM-DD HH24:MI TZR").is_err());
+        assert!(parse("2019-07-01 10:00 -13:00", "YYYY-MM-DD HH24:MI TZR").is_err());
+
+        // FX
+        assert!(parse("2019-7-1", "FXYYYY-MM-DD").is_err());
+        assert!(parse("2019/07/01", "FXYYYY-MM-DD").is_err());
+        assert_eq!(
+            parse("2019-07-01", "FXYYYY-MM-DD").unwrap(),
+            date(2019, 7, 1)
+        );
+        assert_eq!(
+            parse("2019-7-1", "FXYYYY-FMMM-DD").unwrap(),
+            date(2019, 7, 1)
+        );
+
+        // errors
+        assert!(parse("2019-02-29", "YYYY-MM-DD").is_err());
+        assert!(parse("2020-02-30", "YYYY-MM-DD").is_err());
+        assert!(parse("2019-13-01", "YYYY-MM-DD").is_err());
+        assert!(parse("0000-01-01", "YYYY-MM-DD").is_err());
+        assert!(parse("-4713-12-31", "SYYYY-MM-DD").is_err());
+        assert!(parse("1582-10-10", "YYYY-MM-DD").is_err());
+        assert!(parse("2019-01-01 24:00", "YYYY-MM-DD HH24:MI").is_err());
+        assert!(parse("2019-01-01 00:00", "YYYY-MM-DD HH:MI").is_err());
+        assert!(parse("2019-01-01 extra", "YYYY-MM-DD").is_err());
+        assert!(parse("2019-01-01 TUE", "YYYY-MM-DD DY").is_ok());
+        assert!(parse("2019-01-01 MON", "YYYY-MM-DD DY").is_err());
+        assert!(parse("2019 01", "YYYY YYYY").is_err());
+        assert!(parse("2019 01", "YYYY IW").is_err());
+        assert!(parse("2019 366", "YYYY DDD").is_err());
+        assert!(parse("2019-01-01 +15:00", "YYYY-MM-DD TZH:TZM").is_err());
+    }
+
+    #[test]
+    fn format_invalid_fields() {
+        for ts in &[
+            Timestamp::new(2017, 0, 9, 23, 2, 3, 0),
+            Timestamp::new(2017, 13, 9, 23, 2, 3, 0),
+            Timestamp::new(2017, 8, 32, 23, 2, 3, 0),
+            Timestamp::new(0, 8, 9, 23, 2, 3, 0),
+            Timestamp::new(2017, 8, 9, 24, 2, 3, 0),
+        ] {
+            for fmt in &["MON", "MONTH", "RM", "HH24"] {
+                assert!(format(ts, fmt).is_err(), "{} {}", ts, fmt);
+            }
+        }
+    }
+
+    #[test]
+    fn format_and_parse() {
+        let fmt = "SYYYY-MM-DD HH24:MI:SS.FF9 TZH:TZM";
+        for ts in &[
+            Timestamp::new(-4712, 1, 1, 0, 0, 0, 0),
+            Timestamp::new(-1, 12, 31, 23, 59, 59, 999999999),
+            Timestamp::new(1, 1, 1, 0, 0, 0, 1),
+            Timestamp::new(1582, 10, 4, 12, 0, 0, 0),
+            Timestamp::new(1582, 10, 15, 12, 0, 0, 0),
+            Timestamp::new(2019, 7, 1, 10, 0, 0, 0).and_tz_hm_offset(2, 0),
+            Timestamp::new(9999, 12, 31, 23, 59, 59, 999999999).and_tz_hm_offset(-12, -59),
+        ] {
+            let s = format(ts, fmt).unwrap();
+            assert_eq!(parse(&s, fmt).unwrap(), *ts, "{}", s);
+        }
+    }
+}
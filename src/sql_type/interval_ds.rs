@@ -15,22 +15,39 @@
 
 use std::cmp;
 use std::fmt;
+use std::ops;
+use std::result;
 use std::str;
+use std::time::Duration;
+use try_from::TryFrom;
 
 use crate::binding::dpiIntervalDS;
 use crate::sql_type::OracleType;
 use crate::util::Scanner;
+use crate::Error;
 use crate::ParseOracleTypeError;
+use crate::Result;
+
+// the maximum number of days in interval day(9) to second(9)
+const MAX_DAYS: i32 = 999_999_999;
+
+const NANOS_PER_SEC: i128 = 1_000_000_000;
 
 /// Oracle-specific [Interval Day to Second][INTVL_DS] data type.
 ///
 /// [INTVL_DS]: https://docs.oracle.com/database/122/NLSPG/datetime-data-types-and-time-zone-support.htm#GUID-FD8C41B7-8CDC-4D02-8E6B-5250416BC17D
 ///
-/// This struct doesn't have arithmetic methods and they won't be added to avoid
-/// reinventing the wheel. If you need methods such as adding an interval to a
-/// timestamp, enable `chrono` feature and use [chrono::Duration][] instead.
+/// Intervals can be added, subtracted, negated and multiplied by an integer.
+/// The results are normalized in the same way as Oracle, that is, 25 hours
+/// becomes 1 day and 1 hour. Intervals are equal when their components are
+/// equal and are ordered by their lengths.
 ///
-/// [chrono::Duration]: https://docs.rs/chrono/0.4/chrono/struct.Duration.html
+/// Conversions from and to [std::time::Duration][] are available via
+/// `TryFrom`, and also from and to `chrono::Duration` when the `chrono`
+/// feature is enabled. Strings in ISO 8601 duration format such as `P1DT2H` are
+/// also parsed by `parse()` and written by [to_iso8601](#method.to_iso8601).
+///
+/// [std::time::Duration]: https://doc.rust-lang.org/std/time/struct.Duration.html
 ///
 /// # Examples
 ///
@@ -65,6 +82,30 @@ use crate::ParseOracleTypeError;
 /// # Ok(())} fn main() { try_main().unwrap(); }
 /// ```
 ///
+/// Arithmetic and conversions.
+///
+/// ```
+/// # use oracle::*; use oracle::sql_type::*; fn try_main() -> Result<()> {
+/// use try_from::TryFrom;
+/// use std::time::Duration;
+///
+/// let intvl = IntervalDS::new(0, 20, 0, 0, 0) + IntervalDS::new(0, 5, 30, 0, 0);
+/// assert_eq!(intvl, IntervalDS::new(1, 1, 30, 0, 0));
+/// assert_eq!(intvl.hours(), 1);
+/// assert_eq!(intvl * 2, IntervalDS::new(2, 3, 0, 0, 0));
+/// assert!(-intvl < IntervalDS::new(0, 0, 0, 0, 0));
+///
+/// // Conversion from and to std::time::Duration
+/// assert_eq!(Duration::try_from(intvl)?, Duration::from_secs(91800));
+/// assert_eq!(IntervalDS::try_from(Duration::from_millis(1500))?,
+///            IntervalDS::new(0, 0, 0, 1, 500000000));
+///
+/// // ISO 8601 duration format
+/// assert_eq!(intvl.to_iso8601(), "P1DT1H30M");
+/// assert_eq!("PT25H30M".parse::<IntervalDS>()?, intvl);
+/// # Ok(())} fn main() { try_main().unwrap(); }
+/// ```
+///
 /// Fetch and bind interval values.
 ///
 /// ```no_run
@@ -191,6 +232,251 @@ impl IntervalDS {
     pub fn fsprec(&self) -> u8 {
         self.fsprec
     }
+
+    // Returns the interval in nanoseconds.
+    pub(crate) fn total_nanoseconds(&self) -> i128 {
+        (((self.days as i128 * 24 + self.hours as i128) * 60 + self.minutes as i128) * 60
+            + self.seconds as i128)
+            * 1_000_000_000
+            + self.nanoseconds as i128
+    }
+
+    // Creates an interval from nanoseconds. All components have same sign.
+    // None when the number of days exceeds the range of interval day(9) to second(9).
+    pub(crate) fn from_total_nanoseconds(nanos: i128) -> Option<IntervalDS> {
+        let secs = nanos / 1_000_000_000;
+        let days = secs / 86400;
+        if days.abs() > MAX_DAYS as i128 {
+            return None;
+        }
+        Some(IntervalDS::new(
+            days as i32,
+            (secs / 3600 % 24) as i32,
+            (secs / 60 % 60) as i32,
+            (secs % 60) as i32,
+            (nanos % 1_000_000_000) as i32,
+        ))
+    }
+
+    // Returns nanoseconds since midnight when the interval is used as a time of day.
+    #[cfg(any(feature = "chrono", feature = "time"))]
+    pub(crate) fn time_of_day_nanoseconds(&self) -> Result<u64> {
+        let nanos = self.total_nanoseconds();
+        if nanos < 0 || 86400 * NANOS_PER_SEC <= nanos {
+            return Err(Error::OutOfRange(format!(
+                "interval {} is not between 0 and 24 hours",
+                self
+            )));
+        }
+        Ok(nanos as u64)
+    }
+
+    // Creates an interval from nanoseconds with the specified precisions.
+    pub(crate) fn with_total_nanoseconds(
+        nanos: i128,
+        lfprec: u8,
+        fsprec: u8,
+    ) -> Result<IntervalDS> {
+        match IntervalDS::from_total_nanoseconds(nanos) {
+            Some(it) => Ok(it.and_prec(lfprec, fsprec)),
+            None => Err(Error::OutOfRange(format!(
+                "interval day to second must be between -{0} and +{0} days",
+                MAX_DAYS
+            ))),
+        }
+    }
+
+    /// Returns an interval whose hours, minutes, seconds and nanoseconds
+    /// components are carried over to upper components as Oracle does.
+    /// For example `IntervalDS::new(0, 25, 0, 0, 0)` is normalized to
+    /// `IntervalDS::new(1, 1, 0, 0, 0)`. The precisions are kept.
+    ///
+    /// This returns `Err(Error::OutOfRange(...))` when the number of days
+    /// exceeds 999999999.
+    pub fn normalize(&self) -> Result<IntervalDS> {
+        IntervalDS::with_total_nanoseconds(self.total_nanoseconds(), self.lfprec, self.fsprec)
+    }
+
+    /// Adds an interval. The result is normalized and its precisions
+    /// are larger ones of `self` and `other`.
+    ///
+    /// This returns `Err(Error::OutOfRange(...))` when the number of days
+    /// exceeds 999999999.
+    pub fn checked_add(&self, other: &IntervalDS) -> Result<IntervalDS> {
+        IntervalDS::with_total_nanoseconds(
+            self.total_nanoseconds() + other.total_nanoseconds(),
+            cmp::max(self.lfprec, other.lfprec),
+            cmp::max(self.fsprec, other.fsprec),
+        )
+    }
+
+    /// Subtracts an interval. The result is normalized and its precisions
+    /// are larger ones of `self` and `other`.
+    ///
+    /// This returns `Err(Error::OutOfRange(...))` when the number of days
+    /// exceeds 999999999.
+    pub fn checked_sub(&self, other: &IntervalDS) -> Result<IntervalDS> {
+        IntervalDS::with_total_nanoseconds(
+            self.total_nanoseconds() - other.total_nanoseconds(),
+            cmp::max(self.lfprec, other.lfprec),
+            cmp::max(self.fsprec, other.fsprec),
+        )
+    }
+
+    /// Multiplies the interval by an integer. The result is normalized.
+    ///
+    /// This returns `Err(Error::OutOfRange(...))` when the number of days
+    /// exceeds 999999999.
+    pub fn checked_mul(&self, rhs: i32) -> Result<IntervalDS> {
+        // The product is far less than i128::MAX.
+        IntervalDS::with_total_nanoseconds(
+            self.total_nanoseconds() * rhs as i128,
+            self.lfprec,
+            self.fsprec,
+        )
+    }
+
+    /// Returns the interval in [ISO 8601 duration format][ISO8601],
+    /// which is accepted by Oracle's `TO_DSINTERVAL` function.
+    /// Fractional seconds are written without trailing zeros.
+    ///
+    /// [ISO8601]: https://en.wikipedia.org/wiki/ISO_8601#Durations
+    ///
+    /// ```
+    /// # use oracle::*; use oracle::sql_type::*; fn try_main() -> Result<()> {
+    /// assert_eq!(IntervalDS::new(1, 2, 0, 0, 0).to_iso8601(), "P1DT2H");
+    /// assert_eq!(IntervalDS::new(0, 0, -3, -4, -500000000).to_iso8601(), "-PT3M4.5S");
+    /// assert_eq!(IntervalDS::new(0, 0, 0, 0, 0).to_iso8601(), "PT0S");
+    /// # Ok(())} fn main() { try_main().unwrap(); }
+    /// ```
+    pub fn to_iso8601(&self) -> String {
+        let nanos = self.total_nanoseconds();
+        let mut s = String::new();
+        if nanos < 0 {
+            s.push('-');
+        }
+        let nanos = nanos.abs();
+        let secs = nanos / NANOS_PER_SEC;
+        let frac = nanos % NANOS_PER_SEC;
+        let days = secs / 86400;
+        let hours = secs / 3600 % 24;
+        let minutes = secs / 60 % 60;
+        let seconds = secs % 60;
+        s.push('P');
+        if days != 0 {
+            s.push_str(&format!("{}D", days));
+        }
+        if secs % 86400 != 0 || frac != 0 || days == 0 {
+            s.push('T');
+            if hours != 0 {
+                s.push_str(&format!("{}H", hours));
+            }
+            if minutes != 0 {
+                s.push_str(&format!("{}M", minutes));
+            }
+            if seconds != 0 || frac != 0 || secs == 0 {
+                s.push_str(&seconds.to_string());
+                if frac != 0 {
+                    let frac = format!("{:09}", frac);
+                    s.push('.');
+                    s.push_str(frac.trim_end_matches('0'));
+                }
+                s.push('S');
+            }
+        }
+        s
+    }
+}
+
+// Parses the rest of ISO 8601 duration after 'P'.
+fn parse_iso8601(s: &mut Scanner) -> Option<i128> {
+    let mut nanos = 0i128;
+    // 0: days, 1: hours, 2: minutes, 3: seconds
+    let mut next_unit = 0;
+    let mut time_part = false;
+    let mut has_component = false;
+    loop {
+        if !time_part {
+            if let Some('T') = s.char() {
+                s.next();
+                time_part = true;
+                has_component = false;
+                next_unit = 1;
+            }
+        }
+        let num = match s.read_digits() {
+            Some(num) => num as i128,
+            None => break,
+        };
+        let mut frac = 0;
+        if let Some('.') = s.char() {
+            s.next();
+            let digits = s.read_digits()? as i128;
+            let ndigits = s.ndigits();
+            frac = if ndigits <= 9 {
+                digits * 10i128.pow(9 - ndigits)
+            } else {
+                digits / 10i128.pow(ndigits - 9)
+            };
+        }
+        let (unit, secs) = match (time_part, s.char()?) {
+            (false, 'W') => (0, 7 * 86400),
+            (false, 'D') => (0, 86400),
+            (true, 'H') => (1, 3600),
+            (true, 'M') => (2, 60),
+            (true, 'S') => (3, 1),
+            _ => return None,
+        };
+        if unit < next_unit || (frac != 0 && unit != 3) {
+            return None;
+        }
+        s.next();
+        next_unit = unit + 1;
+        has_component = true;
+        nanos = nanos.checked_add(num.checked_mul(secs * NANOS_PER_SEC)? + frac)?;
+    }
+    if has_component && s.char().is_none() {
+        Some(nanos)
+    } else {
+        None
+    }
+}
+
+impl TryFrom<Duration> for IntervalDS {
+    type Err = Error;
+
+    /// Converts a duration to an interval day to second.
+    ///
+    /// This returns `Err(Error::OutOfRange(...))` when the number of days
+    /// exceeds 999999999.
+    fn try_from(dur: Duration) -> Result<IntervalDS> {
+        IntervalDS::with_total_nanoseconds(
+            dur.as_secs() as i128 * NANOS_PER_SEC + dur.subsec_nanos() as i128,
+            9,
+            9,
+        )
+    }
+}
+
+impl TryFrom<IntervalDS> for Duration {
+    type Err = Error;
+
+    /// Converts an interval day to second to a duration.
+    ///
+    /// This returns `Err(Error::OutOfRange(...))` when the interval is negative.
+    fn try_from(it: IntervalDS) -> Result<Duration> {
+        let nanos = it.total_nanoseconds();
+        if nanos < 0 {
+            return Err(Error::OutOfRange(format!(
+                "negative interval {} cannot be converted to Duration",
+                it
+            )));
+        }
+        Ok(Duration::new(
+            (nanos / NANOS_PER_SEC) as u64,
+            (nanos % NANOS_PER_SEC) as u32,
+        ))
+    }
 }
 
 impl cmp::PartialEq for IntervalDS {
@@ -203,6 +489,63 @@ impl cmp::PartialEq for IntervalDS {
     }
 }
 
+impl cmp::Eq for IntervalDS {}
+
+/// Intervals are ordered by their lengths. Intervals with the same length
+/// but different components such as `IntervalDS::new(0, 25, 0, 0, 0)` and
+/// `IntervalDS::new(1, 1, 0, 0, 0)` are neither equal nor ordered. Compare
+/// [normalized](#method.normalize) intervals to treat them as Oracle does.
+impl cmp::PartialOrd for IntervalDS {
+    fn partial_cmp(&self, other: &Self) -> Option<cmp::Ordering> {
+        match self.total_nanoseconds().cmp(&other.total_nanoseconds()) {
+            cmp::Ordering::Equal if self != other => None,
+            ord => Some(ord),
+        }
+    }
+}
+
+/// Panics when the result is out of range. Use [checked_add](#method.checked_add) not to panic.
+impl ops::Add for IntervalDS {
+    type Output = IntervalDS;
+
+    fn add(self, other: IntervalDS) -> IntervalDS {
+        self.checked_add(&other).unwrap()
+    }
+}
+
+/// Panics when the result is out of range. Use [checked_sub](#method.checked_sub) not to panic.
+impl ops::Sub for IntervalDS {
+    type Output = IntervalDS;
+
+    fn sub(self, other: IntervalDS) -> IntervalDS {
+        self.checked_sub(&other).unwrap()
+    }
+}
+
+/// Panics when the result is out of range. Use [checked_mul](#method.checked_mul) not to panic.
+impl ops::Mul<i32> for IntervalDS {
+    type Output = IntervalDS;
+
+    fn mul(self, rhs: i32) -> IntervalDS {
+        self.checked_mul(rhs).unwrap()
+    }
+}
+
+impl ops::Neg for IntervalDS {
+    type Output = IntervalDS;
+
+    fn neg(self) -> IntervalDS {
+        IntervalDS {
+            days: -self.days,
+            hours: -self.hours,
+            minutes: -self.minutes,
+            seconds: -self.seconds,
+            nanoseconds: -self.nanoseconds,
+            ..self
+        }
+    }
+}
+
 impl fmt::Display for IntervalDS {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         if self.days < 0
@@ -253,7 +596,7 @@ impl fmt::Display for IntervalDS {
 impl str::FromStr for IntervalDS {
     type Err = ParseOracleTypeError;
 
-    fn from_str(s: &str) -> Result<Self, Self::Err> {
+    fn from_str(s: &str) -> result::Result<Self, Self::Err> {
         let err = || ParseOracleTypeError::new("IntervalDS");
         let mut s = Scanner::new(s);
         let minus = match s.char() {
@@ -267,6 +610,12 @@ impl str::FromStr for IntervalDS {
             }
             _ => false,
         };
+        if let Some('P') = s.char() {
+            s.next();
+            let nanos = parse_iso8601(&mut s).ok_or(err())?;
+            let nanos = if minus { -nanos } else { nanos };
+            return IntervalDS::from_total_nanoseconds(nanos).ok_or(err());
+        }
         let days = s.read_digits().ok_or(err())? as i32;
         let lfprec = s.ndigits();
         if let Some(' ') = s.char() {
@@ -449,4 +798,150 @@ mod tests {
         it.nanoseconds = -123456789;
         assert_eq!("-1 02:03:04.123456789".parse(), Ok(it));
     }
+
+    #[test]
+    fn arithmetic() {
+        let it = IntervalDS::new(0, 20, 0, 0, 0) + IntervalDS::new(0, 5, 30, 0, 500000000);
+        assert_eq!(it.days(), 1);
+        assert_eq!(it.hours(), 1);
+        assert_eq!(it.minutes(), 30);
+        assert_eq!(it.nanoseconds(), 500000000);
+        let it = IntervalDS::new(0, 1, 0, 0, 0) - IntervalDS::new(1, 0, 0, 0, 0);
+        assert_eq!(it, IntervalDS::new(0, -23, 0, 0, 0));
+        assert_eq!(it.hours(), -23);
+        assert_eq!(-it, IntervalDS::new(0, 23, 0, 0, 0));
+        assert_eq!(
+            IntervalDS::new(0, 0, 0, 45, 0) * 3,
+            IntervalDS::new(0, 0, 2, 15, 0)
+        );
+        assert_eq!(
+            IntervalDS::new(0, 0, 0, 45, 0) * -3,
+            IntervalDS::new(0, 0, -2, -15, 0)
+        );
+
+        let max = IntervalDS::new(MAX_DAYS, 23, 59, 59, 999999999);
+        assert!(max.checked_add(&IntervalDS::new(0, 0, 0, 0, 1)).is_err());
+        assert!((-max).checked_sub(&IntervalDS::new(0, 0, 0, 0, 1)).is_err());
+        assert!(max.checked_mul(2).is_err());
+
+        let it = IntervalDS::new(0, 1, 0, 0, 0).and_prec(2, 3)
+            + IntervalDS::new(0, 1, 0, 0, 0).and_prec(4, 0);
+        assert_eq!(it.lfprec(), 4);
+        assert_eq!(it.fsprec(), 3);
+    }
+
+    #[test]
+    fn normalize() {
+        let it = IntervalDS::new(0, 25, 61, 61, 1000000001).and_prec(2, 6);
+        let normalized = it.normalize().unwrap();
+        assert_ne!(it, normalized);
+        assert_eq!(it.partial_cmp(&normalized), None);
+        assert_eq!(normalized.days(), 1);
+        assert_eq!(normalized.hours(), 2);
+        assert_eq!(normalized.minutes(), 2);
+        assert_eq!(normalized.seconds(), 2);
+        assert_eq!(normalized.nanoseconds(), 1);
+        assert_eq!(normalized.lfprec(), 2);
+        assert_eq!(normalized.fsprec(), 6);
+        assert!(IntervalDS::new(MAX_DAYS, 24, 0, 0, 0).normalize().is_err());
+    }
+
+    #[test]
+    fn ordering() {
+        let mut intervals = vec![
+            IntervalDS::new(1, 0, 0, 0, 0),
+            IntervalDS::new(0, 0, 0, 0, -1),
+            IntervalDS::new(0, 23, 59, 59, 999999999),
+            IntervalDS::new(-1, 0, 0, 0, 0),
+            IntervalDS::new(0, 0, 0, 0, 0),
+        ];
+        intervals.sort_by(|a, b| a.partial_cmp(b).unwrap());
+        assert_eq!(
+            intervals,
+            vec![
+                IntervalDS::new(-1, 0, 0, 0, 0),
+                IntervalDS::new(0, 0, 0, 0, -1),
+                IntervalDS::new(0, 0, 0, 0, 0),
+                IntervalDS::new(0, 23, 59, 59, 999999999),
+                IntervalDS::new(1, 0, 0, 0, 0),
+            ]
+        );
+        assert_ne!(
+            IntervalDS::new(0, 24, 0, 0, 0),
+            IntervalDS::new(1, 0, 0, 0, 0)
+        );
+        assert_eq!(
+            IntervalDS::new(0, 24, 0, 0, 0).normalize().unwrap(),
+            IntervalDS::new(1, 0, 0, 0, 0)
+        );
+        assert!(IntervalDS::new(0, 24, 0, 0, 0) < IntervalDS::new(1, 0, 0, 0, 1));
+    }
+
+    #[test]
+    fn duration() {
+        let dur = Duration::new(86400 * 2 + 3661, 5);
+        let it = IntervalDS::try_from(dur).unwrap();
+        assert_eq!(it, IntervalDS::new(2, 1, 1, 1, 5));
+        assert_eq!(Duration::try_from(it).unwrap(), dur);
+        assert!(Duration::try_from(-it).is_err());
+        assert_eq!(
+            Duration::try_from(IntervalDS::new(0, 0, 0, 0, 0)).unwrap(),
+            Duration::new(0, 0)
+        );
+        let max_secs = (MAX_DAYS as u64 + 1) * 86400;
+        assert!(IntervalDS::try_from(Duration::new(max_secs - 1, 999999999)).is_ok());
+        assert!(IntervalDS::try_from(Duration::new(max_secs, 0)).is_err());
+    }
+
+    #[cfg(feature = "chrono")]
+    #[test]
+    fn chrono_duration() {
+        let dur = chrono::Duration::days(-2) - chrono::Duration::nanoseconds(3_661_000_000_005);
+        let it = IntervalDS::try_from(dur).unwrap();
+        assert_eq!(it, IntervalDS::new(-2, -1, -1, -1, -5));
+        assert_eq!(chrono::Duration::try_from(it).unwrap(), dur);
+        let max_secs = (MAX_DAYS as i64 + 1) * 86400;
+        assert!(IntervalDS::try_from(chrono::Duration::seconds(max_secs - 1)).is_ok());
+        assert!(IntervalDS::try_from(chrono::Duration::seconds(max_secs)).is_err());
+    }
+
+    #[test]
+    fn iso8601() {
+        let to_iso = |d, h, m, s, ns| IntervalDS::new(d, h, m, s, ns).to_iso8601();
+        assert_eq!(to_iso(0, 0, 0, 0, 0), "PT0S");
+        assert_eq!(to_iso(1, 0, 0, 0, 0), "P1D");
+        assert_eq!(to_iso(1, 2, 0, 0, 0), "P1DT2H");
+        assert_eq!(to_iso(0, 0, 3, 0, 0), "PT3M");
+        assert_eq!(to_iso(0, 0, 0, 4, 500000000), "PT4.5S");
+        assert_eq!(to_iso(0, 0, 0, 0, 1), "PT0.000000001S");
+        assert_eq!(to_iso(1, 2, 3, 4, 120000000), "P1DT2H3M4.12S");
+        assert_eq!(to_iso(-1, -2, -3, -4, 0), "-P1DT2H3M4S");
+        assert_eq!(to_iso(0, 25, 0, 0, 0), "P1DT1H");
+
+        let parse = |s: &str| s.parse::<IntervalDS>().ok();
+        let it = |d, h, m, s, ns| Some(IntervalDS::new(d, h, m, s, ns));
+        assert_eq!(parse("P1D"), it(1, 0, 0, 0, 0));
+        assert_eq!(parse("+P1DT2H"), it(1, 2, 0, 0, 0));
+        assert_eq!(parse("-P1DT2H3M4.5S"), it(-1, -2, -3, -4, -500000000));
+        assert_eq!(parse("PT36H"), it(1, 12, 0, 0, 0));
+        assert_eq!(parse("PT90M"), it(0, 1, 30, 0, 0));
+        assert_eq!(parse("PT0.1234567891S"), it(0, 0, 0, 0, 123456789));
+        assert_eq!(parse("P2W"), it(14, 0, 0, 0, 0));
+        assert_eq!(parse("PT0S"), it(0, 0, 0, 0, 0));
+        assert_eq!(parse("P1DT25H").unwrap().days(), 2);
+        assert_eq!(parse("P"), None);
+        assert_eq!(parse("PT"), None);
+        assert_eq!(parse("P1DT"), None);
+        assert_eq!(parse("P1H"), None);
+        assert_eq!(parse("PT1D"), None);
+        assert_eq!(parse("P1Y"), None);
+        assert_eq!(parse("PT2M1H"), None);
+        assert_eq!(parse("PT1H1H"), None);
+        assert_eq!(parse("PT1.5H"), None);
+        assert_eq!(parse("PT1S "), None);
+        assert_eq!(parse("P1000000000D"), None);
+
+        let it = IntervalDS::new(-12, -3, -45, -6, -789000000);
+        assert_eq!(parse(&it.to_iso8601()), Some(it));
+    }
 }
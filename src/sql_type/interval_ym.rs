@@ -15,17 +15,30 @@
 
 use std::cmp;
 use std::fmt;
+use std::ops;
+use std::result;
 use std::str;
 
 use crate::binding::dpiIntervalYM;
 use crate::sql_type::OracleType;
 use crate::util::Scanner;
+use crate::Error;
 use crate::ParseOracleTypeError;
+use crate::Result;
+
+// the maximum number of years in interval year(9) to month
+const MAX_YEARS: i64 = 999_999_999;
 
 /// Oracle-specific [Interval Year to Month][INTVL_YM] data type.
 ///
 /// [INTVL_YM]: https://docs.oracle.com/database/122/NLSPG/datetime-data-types-and-time-zone-support.htm#GUID-517CEB46-C6FA-4B94-9299-5BBB5A58CF7B
 ///
+/// Intervals can be added, subtracted, negated and multiplied by an integer.
+/// The results are normalized, that is, 14 months becomes 1 year and 2 months.
+/// Intervals are equal when their components are equal and are ordered by
+/// their lengths. Strings in ISO 8601 duration format such as `P1Y2M` are
+/// also parsed by `parse()` and written by [to_iso8601](#method.to_iso8601).
+///
 /// # Examples
 ///
 /// ```
@@ -141,10 +154,137 @@ impl IntervalYM {
         self.months
     }
 
+    // Returns the interval in months.
+    pub(crate) fn total_months(&self) -> i64 {
+        self.years as i64 * 12 + self.months as i64
+    }
+
     /// Returns precision.
     pub fn precision(&self) -> u8 {
         self.precision
     }
+
+    // Creates an interval from months with the specified precision.
+    // Both components have same sign.
+    fn with_total_months(months: i64, precision: u8) -> Result<IntervalYM> {
+        if months.abs() / 12 > MAX_YEARS {
+            return Err(out_of_range());
+        }
+        Ok(IntervalYM {
+            years: (months / 12) as i32,
+            months: (months % 12) as i32,
+            precision,
+        })
+    }
+
+    /// Returns an interval whose months component is carried over to
+    /// the years component as Oracle does. For example `IntervalYM::new(0, 14)`
+    /// is normalized to `IntervalYM::new(1, 2)`. The precision is kept.
+    ///
+    /// This returns `Err(Error::OutOfRange(...))` when the number of years
+    /// exceeds 999999999.
+    pub fn normalize(&self) -> Result<IntervalYM> {
+        IntervalYM::with_total_months(self.total_months(), self.precision)
+    }
+
+    /// Adds an interval. The result is normalized and its precision
+    /// is larger one of `self` and `other`.
+    ///
+    /// This returns `Err(Error::OutOfRange(...))` when the number of years
+    /// exceeds 999999999.
+    pub fn checked_add(&self, other: &IntervalYM) -> Result<IntervalYM> {
+        IntervalYM::with_total_months(
+            self.total_months() + other.total_months(),
+            cmp::max(self.precision, other.precision),
+        )
+    }
+
+    /// Subtracts an interval. The result is normalized and its precision
+    /// is larger one of `self` and `other`.
+    ///
+    /// This returns `Err(Error::OutOfRange(...))` when the number of years
+    /// exceeds 999999999.
+    pub fn checked_sub(&self, other: &IntervalYM) -> Result<IntervalYM> {
+        IntervalYM::with_total_months(
+            self.total_months() - other.total_months(),
+            cmp::max(self.precision, other.precision),
+        )
+    }
+
+    /// Multiplies the interval by an integer. The result is normalized.
+    ///
+    /// This returns `Err(Error::OutOfRange(...))` when the number of years
+    /// exceeds 999999999.
+    pub fn checked_mul(&self, rhs: i32) -> Result<IntervalYM> {
+        match self.total_months().checked_mul(rhs as i64) {
+            Some(months) => IntervalYM::with_total_months(months, self.precision),
+            None => Err(out_of_range()),
+        }
+    }
+
+    /// Returns the interval in [ISO 8601 duration format][ISO8601],
+    /// which is accepted by Oracle's `TO_YMINTERVAL` function.
+    ///
+    /// [ISO8601]: https://en.wikipedia.org/wiki/ISO_8601#Durations
+    ///
+    /// ```
+    /// # use oracle::*; use oracle::sql_type::*; fn try_main() -> Result<()> {
+    /// assert_eq!(IntervalYM::new(1, 2).to_iso8601(), "P1Y2M");
+    /// assert_eq!(IntervalYM::new(0, -14).to_iso8601(), "-P1Y2M");
+    /// assert_eq!(IntervalYM::new(0, 0).to_iso8601(), "P0M");
+    /// # Ok(())} fn main() { try_main().unwrap(); }
+    /// ```
+    pub fn to_iso8601(&self) -> String {
+        let months = self.total_months();
+        let mut s = String::new();
+        if months < 0 {
+            s.push('-');
+        }
+        let months = months.abs();
+        s.push('P');
+        if months / 12 != 0 {
+            s.push_str(&format!("{}Y", months / 12));
+        }
+        if months % 12 != 0 || months == 0 {
+            s.push_str(&format!("{}M", months % 12));
+        }
+        s
+    }
+}
+
+fn out_of_range() -> Error {
+    Error::OutOfRange(format!(
+        "interval year to month must be between -{0} and +{0} years",
+        MAX_YEARS
+    ))
+}
+
+// Parses the rest of ISO 8601 duration after 'P'.
+fn parse_iso8601(s: &mut Scanner) -> Option<i64> {
+    let mut months = 0i64;
+    // 0: years, 1: months
+    let mut next_unit = 0;
+    while let Some(num) = s.read_digits() {
+        let (unit, mul) = match s.char()? {
+            'Y' => (0, 12),
+            'M' => (1, 1),
+            _ => return None,
+        };
+        if unit < next_unit {
+            return None;
+        }
+        s.next();
+        next_unit = unit + 1;
+        if num > i64::max_value() as u64 {
+            return None;
+        }
+        months = months.checked_add((num as i64).checked_mul(mul)?)?;
+    }
+    if next_unit > 0 && s.char().is_none() {
+        Some(months)
+    } else {
+        None
+    }
 }
 
 impl cmp::PartialEq for IntervalYM {
@@ -153,6 +293,60 @@ impl cmp::PartialEq for IntervalYM {
     }
 }
 
+impl cmp::Eq for IntervalYM {}
+
+/// Intervals are ordered by their lengths. Intervals with the same length
+/// but different components such as `IntervalYM::new(0, 14)` and
+/// `IntervalYM::new(1, 2)` are neither equal nor ordered. Compare
+/// [normalized](#method.normalize) intervals to treat them as Oracle does.
+impl cmp::PartialOrd for IntervalYM {
+    fn partial_cmp(&self, other: &Self) -> Option<cmp::Ordering> {
+        match self.total_months().cmp(&other.total_months()) {
+            cmp::Ordering::Equal if self != other => None,
+            ord => Some(ord),
+        }
+    }
+}
+
+/// Panics when the result is out of range. Use [checked_add](#method.checked_add) not to panic.
+impl ops::Add for IntervalYM {
+    type Output = IntervalYM;
+
+    fn add(self, other: IntervalYM) -> IntervalYM {
+        self.checked_add(&other).unwrap()
+    }
+}
+
+/// Panics when the result is out of range. Use [checked_sub](#method.checked_sub) not to panic.
+impl ops::Sub for IntervalYM {
+    type Output = IntervalYM;
+
+    fn sub(self, other: IntervalYM) -> IntervalYM {
+        self.checked_sub(&other).unwrap()
+    }
+}
+
+/// Panics when the result is out of range. Use [checked_mul](#method.checked_mul) not to panic.
+impl ops::Mul<i32> for IntervalYM {
+    type Output = IntervalYM;
+
+    fn mul(self, rhs: i32) -> IntervalYM {
+        self.checked_mul(rhs).unwrap()
+    }
+}
+
+impl ops::Neg for IntervalYM {
+    type Output = IntervalYM;
+
+    fn neg(self) -> IntervalYM {
+        IntervalYM {
+            years: -self.years,
+            months: -self.months,
+            ..self
+        }
+    }
+}
+
 impl fmt::Display for IntervalYM {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         if self.years < 0 || self.months < 0 {
@@ -179,7 +373,7 @@ impl fmt::Display for IntervalYM {
 impl str::FromStr for IntervalYM {
     type Err = ParseOracleTypeError;
 
-    fn from_str(s: &str) -> Result<Self, Self::Err> {
+    fn from_str(s: &str) -> result::Result<Self, Self::Err> {
         let err = || ParseOracleTypeError::new("IntervalYM");
         let mut s = Scanner::new(s);
         let minus = match s.char() {
@@ -193,6 +387,12 @@ impl str::FromStr for IntervalYM {
             }
             _ => false,
         };
+        if let Some('P') = s.char() {
+            s.next();
+            let months = parse_iso8601(&mut s).ok_or(err())?;
+            let months = if minus { -months } else { months };
+            return IntervalYM::with_total_months(months, 9).map_err(|_| err());
+        }
         let years = s.read_digits().ok_or(err())? as i32;
         let precision = s.ndigits();
         if let Some('-') = s.char() {
@@ -289,4 +489,89 @@ mod tests {
         let it = IntervalYM::new(-1, -2);
         assert_eq!("-000000001-02".parse(), Ok(it));
     }
+
+    #[test]
+    fn arithmetic() {
+        let it = IntervalYM::new(1, 8) + IntervalYM::new(0, 6);
+        assert_eq!(it.years(), 2);
+        assert_eq!(it.months(), 2);
+        let it = IntervalYM::new(1, 0) - IntervalYM::new(2, 1);
+        assert_eq!(it.years(), -1);
+        assert_eq!(it.months(), -1);
+        assert_eq!(-it, IntervalYM::new(1, 1));
+        assert_eq!(IntervalYM::new(0, 5) * 3, IntervalYM::new(1, 3));
+        assert_eq!(IntervalYM::new(0, 5) * -3, IntervalYM::new(-1, -3));
+
+        let max = IntervalYM::new(MAX_YEARS as i32, 11);
+        assert!(max.checked_add(&IntervalYM::new(0, 1)).is_err());
+        assert!((-max).checked_sub(&IntervalYM::new(0, 1)).is_err());
+        assert!(max.checked_mul(2).is_err());
+        assert!(max.checked_mul(i32::MAX).is_err());
+        assert!(max.checked_mul(i32::MIN).is_err());
+
+        let it = IntervalYM::new(1, 0).and_prec(2) + IntervalYM::new(1, 0).and_prec(4);
+        assert_eq!(it.precision(), 4);
+    }
+
+    #[test]
+    fn normalize() {
+        let it = IntervalYM::new(1, 14).and_prec(3);
+        let normalized = it.normalize().unwrap();
+        assert_ne!(it, normalized);
+        assert_eq!(it.partial_cmp(&normalized), None);
+        assert_eq!(normalized.years(), 2);
+        assert_eq!(normalized.months(), 2);
+        assert_eq!(normalized.precision(), 3);
+        assert!(IntervalYM::new(MAX_YEARS as i32, 12).normalize().is_err());
+    }
+
+    #[test]
+    fn ordering() {
+        let mut intervals = vec![
+            IntervalYM::new(1, 0),
+            IntervalYM::new(0, -1),
+            IntervalYM::new(0, 11),
+            IntervalYM::new(-1, 0),
+        ];
+        intervals.sort_by(|a, b| a.partial_cmp(b).unwrap());
+        assert_eq!(
+            intervals,
+            vec![
+                IntervalYM::new(-1, 0),
+                IntervalYM::new(0, -1),
+                IntervalYM::new(0, 11),
+                IntervalYM::new(1, 0),
+            ]
+        );
+        assert_ne!(IntervalYM::new(0, 12), IntervalYM::new(1, 0));
+        assert_eq!(
+            IntervalYM::new(0, 12).normalize().unwrap(),
+            IntervalYM::new(1, 0)
+        );
+        assert!(IntervalYM::new(0, 12) < IntervalYM::new(1, 1));
+    }
+
+    #[test]
+    fn iso8601() {
+        assert_eq!(IntervalYM::new(0, 0).to_iso8601(), "P0M");
+        assert_eq!(IntervalYM::new(1, 0).to_iso8601(), "P1Y");
+        assert_eq!(IntervalYM::new(0, 2).to_iso8601(), "P2M");
+        assert_eq!(IntervalYM::new(1, 2).to_iso8601(), "P1Y2M");
+        assert_eq!(IntervalYM::new(-1, -2).to_iso8601(), "-P1Y2M");
+        assert_eq!(IntervalYM::new(0, 25).to_iso8601(), "P2Y1M");
+
+        let parse = |s: &str| s.parse::<IntervalYM>().ok();
+        assert_eq!(parse("P1Y"), Some(IntervalYM::new(1, 0)));
+        assert_eq!(parse("+P1Y2M"), Some(IntervalYM::new(1, 2)));
+        assert_eq!(parse("-P1Y2M"), Some(IntervalYM::new(-1, -2)));
+        assert_eq!(parse("P14M"), Some(IntervalYM::new(1, 2)));
+        assert_eq!(parse("P14M").unwrap().months(), 2);
+        assert_eq!(parse("P0M"), Some(IntervalYM::new(0, 0)));
+        assert_eq!(parse("P"), None);
+        assert_eq!(parse("P2M1Y"), None);
+        assert_eq!(parse("P1Y1Y"), None);
+        assert_eq!(parse("P1D"), None);
+        assert_eq!(parse("P1YT1H"), None);
+        assert_eq!(parse("P1000000000Y"), None);
+    }
 }
@@ -0,0 +1,656 @@
+// Rust-oracle - Rust binding for Oracle database
+//
+// URL: https://github.com/kubo/rust-oracle
+//
+//-----------------------------------------------------------------------------
+// Copyright (c) 2017-2018 Kubo Takehiro <kubo@jiubao.org>. All rights reserved.
+// This program is free software: you can modify it and/or redistribute it
+// under the terms of:
+//
+// (i)  the Universal Permissive License v 1.0 or at your option, any
+//      later version (http://oss.oracle.com/licenses/upl); and/or
+//
+// (ii) the Apache License v 2.0. (http://www.apache.org/licenses/LICENSE-2.0)
+//-----------------------------------------------------------------------------
+
+use std::char;
+use std::fmt;
+use std::result;
+use std::str;
+
+use crate::sql_type::FromSql;
+use crate::sql_type::OracleNumber;
+use crate::sql_type::OracleType;
+use crate::sql_type::Timestamp;
+use crate::sql_type::ToSql;
+use crate::sql_type::ToSqlNull;
+use crate::Connection;
+use crate::Error;
+use crate::ParseOracleTypeError;
+use crate::Result;
+use crate::SqlValue;
+
+// Maximum nesting depth of arrays and objects accepted by the parser
+const MAX_DEPTH: usize = 1000;
+
+/// JSON value
+///
+/// This is a tree of JSON data fetched from or bound to Oracle.
+/// Numbers are held as [OracleNumber][]s so that decimal values are
+/// exchanged without rounding errors.
+///
+/// JSON data is exchanged as JSON text in CLOB. This is for `VARCHAR2`,
+/// `CLOB` and `BLOB` columns with `IS JSON` check constraints.
+///
+/// The native `JSON` data type introduced in Oracle 21c isn't supported
+/// because the bundled ODPI-C is too old to handle it. Until it is
+/// supported, bind values to native `JSON` columns are implicitly
+/// converted by Oracle and the columns must be fetched by
+/// `JSON_SERIALIZE(... RETURNING CLOB)`.
+///
+/// `Timestamp` and `Binary` values are converted to JSON strings in
+/// ISO 8601 format and in hexadecimal respectively when they are
+/// sent to Oracle. JSON text fetched from Oracle doesn't tell them
+/// from other strings. Use [as_timestamp()][] and [as_binary()][] to
+/// convert such strings.
+///
+/// ```no_run
+/// # use oracle::*; use oracle::sql_type::*; fn try_main() -> Result<()> {
+/// let conn = Connection::connect("scott", "tiger", "")?;
+///
+/// // The doc column is CLOB with an IS JSON check constraint.
+/// let doc: JsonValue = conn.query_row_as("select doc from orders where id = 1", &[])?;
+/// if let Some(customer) = doc.get("customer") {
+///     println!("customer: {}", customer.as_str().unwrap_or("unknown"));
+/// }
+///
+/// // The doc column is the native JSON type in Oracle 21c or later,
+/// // which must be converted to JSON text.
+/// let doc: JsonValue = conn.query_row_as(
+///     "select json_serialize(doc returning clob) from orders21c where id = 1",
+///     &[],
+/// )?;
+///
+/// let doc: JsonValue = r#"{"customer":"Smith","items":[{"id":10,"price":12.3}]}"#.parse()?;
+/// conn.execute("insert into orders values (2, :1)", &[&doc])?;
+/// # Ok(())} fn main() { try_main().unwrap(); }
+/// ```
+///
+/// [OracleNumber]: struct.OracleNumber.html
+/// [as_timestamp()]: #method.as_timestamp
+/// [as_binary()]: #method.as_binary
+#[derive(Clone, Debug, PartialEq)]
+pub enum JsonValue {
+    /// null
+    Null,
+
+    /// true or false
+    Bool(bool),
+
+    /// number
+    Number(OracleNumber),
+
+    /// string
+    String(String),
+
+    /// date or timestamp
+    Timestamp(Timestamp),
+
+    /// binary data
+    Binary(Vec<u8>),
+
+    /// array
+    Array(Vec<JsonValue>),
+
+    /// object
+    ///
+    /// Fields are kept in the order of the JSON text.
+    Object(Vec<(String, JsonValue)>),
+}
+
+impl JsonValue {
+    /// Returns `true` when the value is null.
+    pub fn is_null(&self) -> bool {
+        *self == JsonValue::Null
+    }
+
+    /// Returns the boolean value when the value is `Bool`.
+    pub fn as_bool(&self) -> Option<bool> {
+        match *self {
+            JsonValue::Bool(val) => Some(val),
+            _ => None,
+        }
+    }
+
+    /// Returns the number when the value is `Number`.
+    pub fn as_number(&self) -> Option<&OracleNumber> {
+        match *self {
+            JsonValue::Number(ref val) => Some(val),
+            _ => None,
+        }
+    }
+
+    /// Returns the string when the value is `String`.
+    pub fn as_str(&self) -> Option<&str> {
+        match *self {
+            JsonValue::String(ref val) => Some(val),
+            _ => None,
+        }
+    }
+
+    /// Returns the timestamp when the value is `Timestamp` or a string
+    /// which can be parsed as [Timestamp][] such as `"2020-01-02T03:04:05"`.
+    ///
+    /// [Timestamp]: struct.Timestamp.html
+    pub fn as_timestamp(&self) -> Option<Timestamp> {
+        match *self {
+            JsonValue::Timestamp(val) => Some(val),
+            JsonValue::String(ref val) => val.parse().ok(),
+            _ => None,
+        }
+    }
+
+    /// Returns the binary data when the value is `Binary` or a string
+    /// in hexadecimal.
+    pub fn as_binary(&self) -> Option<Vec<u8>> {
+        match *self {
+            JsonValue::Binary(ref val) => Some(val.clone()),
+            JsonValue::String(ref val) if val.len() % 2 == 0 => {
+                crate::util::parse_str_into_raw(val).ok()
+            }
+            _ => None,
+        }
+    }
+
+    /// Returns the elements when the value is `Array`.
+    pub fn as_array(&self) -> Option<&[JsonValue]> {
+        match *self {
+            JsonValue::Array(ref val) => Some(val),
+            _ => None,
+        }
+    }
+
+    /// Returns the fields when the value is `Object`.
+    pub fn as_object(&self) -> Option<&[(String, JsonValue)]> {
+        match *self {
+            JsonValue::Object(ref val) => Some(val),
+            _ => None,
+        }
+    }
+
+    /// Returns the value of the field `name` when the value is `Object`.
+    /// When more than one field has the name, the first one is returned.
+    pub fn get(&self, name: &str) -> Option<&JsonValue> {
+        self.as_object()?
+            .iter()
+            .find(|field| field.0 == name)
+            .map(|field| &field.1)
+    }
+}
+
+impl From<bool> for JsonValue {
+    fn from(val: bool) -> JsonValue {
+        JsonValue::Bool(val)
+    }
+}
+
+impl From<OracleNumber> for JsonValue {
+    fn from(val: OracleNumber) -> JsonValue {
+        JsonValue::Number(val)
+    }
+}
+
+impl<'a> From<&'a str> for JsonValue {
+    fn from(val: &'a str) -> JsonValue {
+        JsonValue::String(val.to_string())
+    }
+}
+
+impl From<String> for JsonValue {
+    fn from(val: String) -> JsonValue {
+        JsonValue::String(val)
+    }
+}
+
+impl From<Timestamp> for JsonValue {
+    fn from(val: Timestamp) -> JsonValue {
+        JsonValue::Timestamp(val)
+    }
+}
+
+impl From<Vec<JsonValue>> for JsonValue {
+    fn from(val: Vec<JsonValue>) -> JsonValue {
+        JsonValue::Array(val)
+    }
+}
+
+fn write_json_string(f: &mut fmt::Formatter, s: &str) -> fmt::Result {
+    write!(f, "\"")?;
+    for c in s.chars() {
+        match c {
+            '"' => write!(f, "\\\"")?,
+            '\\' => write!(f, "\\\\")?,
+            '\n' => write!(f, "\\n")?,
+            '\r' => write!(f, "\\r")?,
+            '\t' => write!(f, "\\t")?,
+            '\u{08}' => write!(f, "\\b")?,
+            '\u{0c}' => write!(f, "\\f")?,
+            c if (c as u32) < 0x20 => write!(f, "\\u{:04x}", c as u32)?,
+            c => write!(f, "{}", c)?,
+        }
+    }
+    write!(f, "\"")
+}
+
+// Writes a timestamp in ISO 8601 format.
+fn write_json_timestamp(f: &mut fmt::Formatter, ts: &Timestamp) -> fmt::Result {
+    write!(
+        f,
+        "\"{:04}-{:02}-{:02}T{:02}:{:02}:{:02}",
+        ts.year(),
+        ts.month(),
+        ts.day(),
+        ts.hour(),
+        ts.minute(),
+        ts.second()
+    )?;
+    if ts.nanosecond() != 0 {
+        let frac = format!("{:09}", ts.nanosecond());
+        write!(f, ".{}", frac.trim_end_matches('0'))?;
+    }
+    if ts.with_tz() {
+        let offset = ts.tz_offset();
+        let sign = if offset < 0 { '-' } else { '+' };
+        let offset = offset.abs();
+        write!(f, "{}{:02}:{:02}", sign, offset / 3600, offset % 3600 / 60)?;
+    }
+    write!(f, "\"")
+}
+
+impl fmt::Display for JsonValue {
+    /// Formats the value as compact JSON text.
+    ///
+    /// Infinite numbers are formatted as `null` because JSON has no
+    /// notation for them, as `JSON.stringify` in JavaScript does.
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            JsonValue::Null => write!(f, "null"),
+            JsonValue::Bool(val) => write!(f, "{}", val),
+            JsonValue::Number(ref val) if val.is_infinite() => write!(f, "null"),
+            JsonValue::Number(ref val) => write!(f, "{}", val),
+            JsonValue::String(ref val) => write_json_string(f, val),
+            JsonValue::Timestamp(ref val) => write_json_timestamp(f, val),
+            JsonValue::Binary(ref val) => {
+                write!(f, "\"")?;
+                for b in val {
+                    write!(f, "{:02X}", b)?;
+                }
+                write!(f, "\"")
+            }
+            JsonValue::Array(ref elems) => {
+                write!(f, "[")?;
+                for (idx, elem) in elems.iter().enumerate() {
+                    if idx != 0 {
+                        write!(f, ",")?;
+                    }
+                    write!(f, "{}", elem)?;
+                }
+                write!(f, "]")
+            }
+            JsonValue::Object(ref fields) => {
+                write!(f, "{{")?;
+                for (idx, (name, val)) in fields.iter().enumerate() {
+                    if idx != 0 {
+                        write!(f, ",")?;
+                    }
+                    write_json_string(f, name)?;
+                    write!(f, ":{}", val)?;
+                }
+                write!(f, "}}")
+            }
+        }
+    }
+}
+
+//
+// JSON text parser
+//
+
+struct Parser<'a> {
+    bytes: &'a [u8],
+    pos: usize,
+}
+
+type ParseResult<T> = result::Result<T, ParseOracleTypeError>;
+
+fn parse_error() -> ParseOracleTypeError {
+    ParseOracleTypeError::new("JSON")
+}
+
+impl<'a> Parser<'a> {
+    fn skip_ws(&mut self) {
+        while let Some(b' ') | Some(b'\t') | Some(b'\n') | Some(b'\r') = self.peek() {
+            self.pos += 1;
+        }
+    }
+
+    fn peek(&self) -> Option<u8> {
+        self.bytes.get(self.pos).cloned()
+    }
+
+    fn expect(&mut self, b: u8) -> ParseResult<()> {
+        if self.peek() == Some(b) {
+            self.pos += 1;
+            Ok(())
+        } else {
+            Err(parse_error())
+        }
+    }
+
+    fn expect_keyword(&mut self, keyword: &str) -> ParseResult<()> {
+        if self.bytes[self.pos..].starts_with(keyword.as_bytes()) {
+            self.pos += keyword.len();
+            Ok(())
+        } else {
+            Err(parse_error())
+        }
+    }
+
+    fn parse_value(&mut self, depth: usize) -> ParseResult<JsonValue> {
+        if depth > MAX_DEPTH {
+            return Err(parse_error());
+        }
+        self.skip_ws();
+        match self.peek() {
+            Some(b'n') => self.expect_keyword("null").map(|_| JsonValue::Null),
+            Some(b't') => self.expect_keyword("true").map(|_| JsonValue::Bool(true)),
+            Some(b'f') => self.expect_keyword("false").map(|_| JsonValue::Bool(false)),
+            Some(b'"') => self.parse_string().map(JsonValue::String),
+            Some(b'[') => {
+                self.pos += 1;
+                let mut elems = Vec::new();
+                self.skip_ws();
+                if self.peek() == Some(b']') {
+                    self.pos += 1;
+                    return Ok(JsonValue::Array(elems));
+                }
+                loop {
+                    elems.push(self.parse_value(depth + 1)?);
+                    self.skip_ws();
+                    match self.peek() {
+                        Some(b',') => self.pos += 1,
+                        Some(b']') => {
+                            self.pos += 1;
+                            return Ok(JsonValue::Array(elems));
+                        }
+                        _ => return Err(parse_error()),
+                    }
+                }
+            }
+            Some(b'{') => {
+                self.pos += 1;
+                let mut fields = Vec::new();
+                self.skip_ws();
+                if self.peek() == Some(b'}') {
+                    self.pos += 1;
+                    return Ok(JsonValue::Object(fields));
+                }
+                loop {
+                    self.skip_ws();
+                    let name = self.parse_string()?;
+                    self.skip_ws();
+                    self.expect(b':')?;
+                    let val = self.parse_value(depth + 1)?;
+                    fields.push((name, val));
+                    self.skip_ws();
+                    match self.peek() {
+                        Some(b',') => self.pos += 1,
+                        Some(b'}') => {
+                            self.pos += 1;
+                            return Ok(JsonValue::Object(fields));
+                        }
+                        _ => return Err(parse_error()),
+                    }
+                }
+            }
+            Some(b'-') | Some(b'0'..=b'9') => self.parse_number(),
+            _ => Err(parse_error()),
+        }
+    }
+
+    fn skip_digits(&mut self) -> usize {
+        let start = self.pos;
+        while let Some(b'0'..=b'9') = self.peek() {
+            self.pos += 1;
+        }
+        self.pos - start
+    }
+
+    fn parse_number(&mut self) -> ParseResult<JsonValue> {
+        let start = self.pos;
+        if self.peek() == Some(b'-') {
+            self.pos += 1;
+        }
+        // JSON doesn't allow leading zeros.
+        if self.peek() == Some(b'0') {
+            self.pos += 1;
+        } else if self.skip_digits() == 0 {
+            return Err(parse_error());
+        }
+        if self.peek() == Some(b'.') {
+            self.pos += 1;
+            if self.skip_digits() == 0 {
+                return Err(parse_error());
+            }
+        }
+        if let Some(b'e') | Some(b'E') = self.peek() {
+            self.pos += 1;
+            if let Some(b'+') | Some(b'-') = self.peek() {
+                self.pos += 1;
+            }
+            if self.skip_digits() == 0 {
+                return Err(parse_error());
+            }
+        }
+        // The slice consists of ASCII characters.
+        let s = str::from_utf8(&self.bytes[start..self.pos]).unwrap();
+        s.parse().map(JsonValue::Number).map_err(|_| parse_error())
+    }
+
+    fn parse_hex4(&mut self) -> ParseResult<u32> {
+        let hex = self
+            .bytes
+            .get(self.pos..self.pos + 4)
+            .and_then(|hex| str::from_utf8(hex).ok())
+            .ok_or_else(parse_error)?;
+        let code = u32::from_str_radix(hex, 16).map_err(|_| parse_error())?;
+        self.pos += 4;
+        Ok(code)
+    }
+
+    fn parse_string(&mut self) -> ParseResult<String> {
+        self.expect(b'"')?;
+        let mut buf = Vec::new();
+        loop {
+            match self.peek() {
+                Some(b'"') => {
+                    self.pos += 1;
+                    // The input is valid UTF-8 and escapes are encoded as UTF-8.
+                    return String::from_utf8(buf).map_err(|_| parse_error());
+                }
+                Some(b'\\') => {
+                    self.pos += 1;
+                    let c = match self.peek() {
+                        Some(b'"') => '"',
+                        Some(b'\\') => '\\',
+                        Some(b'/') => '/',
+                        Some(b'b') => '\u{08}',
+                        Some(b'f') => '\u{0c}',
+                        Some(b'n') => '\n',
+                        Some(b'r') => '\r',
+                        Some(b't') => '\t',
+                        Some(b'u') => {
+                            self.pos += 1;
+                            let mut code = self.parse_hex4()?;
+                            if 0xD800 <= code && code < 0xDC00 {
+                                // surrogate pair
+                                self.expect_keyword("\\u")?;
+                                let low = self.parse_hex4()?;
+                                if low < 0xDC00 || 0xE000 <= low {
+                                    return Err(parse_error());
+                                }
+                                code = 0x10000 + ((code - 0xD800) << 10) + (low - 0xDC00);
+                            }
+                            let c = char::from_u32(code).ok_or_else(parse_error)?;
+                            let mut tmp = [0; 4];
+                            buf.extend_from_slice(c.encode_utf8(&mut tmp).as_bytes());
+                            continue;
+                        }
+                        _ => return Err(parse_error()),
+                    };
+                    self.pos += 1;
+                    buf.push(c as u8);
+                }
+                Some(b) if b >= 0x20 => {
+                    self.pos += 1;
+                    buf.push(b);
+                }
+                _ => return Err(parse_error()),
+            }
+        }
+    }
+}
+
+impl str::FromStr for JsonValue {
+    type Err = ParseOracleTypeError;
+
+    /// Parses JSON text.
+    fn from_str(s: &str) -> result::Result<Self, Self::Err> {
+        let mut parser = Parser {
+            bytes: s.as_bytes(),
+            pos: 0,
+        };
+        let val = parser.parse_value(0)?;
+        parser.skip_ws();
+        if parser.pos != s.len() {
+            return Err(parse_error());
+        }
+        Ok(val)
+    }
+}
+
+impl FromSql for JsonValue {
+    fn from_sql(val: &SqlValue) -> Result<JsonValue> {
+        let text = match *val.oracle_type()? {
+            // JSON text in binary columns is encoded in UTF-8.
+            OracleType::Raw(_) | OracleType::BLOB | OracleType::LongRaw => {
+                String::from_utf8(val.to_bytes()?)
+                    .map_err(|err| Error::ParseError(Box::new(err)))?
+            }
+            _ => val.to_string()?,
+        };
+        Ok(text.parse()?)
+    }
+}
+
+impl ToSqlNull for JsonValue {
+    fn oratype_for_null(_conn: &Connection) -> Result<OracleType> {
+        Ok(OracleType::Json)
+    }
+}
+
+impl ToSql for JsonValue {
+    fn oratype(&self, _conn: &Connection) -> Result<OracleType> {
+        Ok(OracleType::Json)
+    }
+    fn to_sql(&self, val: &mut SqlValue) -> Result<()> {
+        val.set_string(&self.to_string())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn num(s: &str) -> JsonValue {
+        JsonValue::Number(s.parse().unwrap())
+    }
+
+    #[test]
+    fn parse() {
+        let val: JsonValue = r#" {"a": [1, -2.5e1, 0.10], "b": {"c": null}, "d": true,
+                                  "e": "x\"\\\/\b\f\n\r\t\u00e9\ud83d\ude00", "f": []} "#
+            .parse()
+            .unwrap();
+        assert_eq!(
+            val,
+            JsonValue::Object(vec![
+                (
+                    "a".to_string(),
+                    JsonValue::Array(vec![num("1"), num("-25"), num("0.1")])
+                ),
+                (
+                    "b".to_string(),
+                    JsonValue::Object(vec![("c".to_string(), JsonValue::Null)])
+                ),
+                ("d".to_string(), JsonValue::Bool(true)),
+                (
+                    "e".to_string(),
+                    JsonValue::String("x\"\\/\u{08}\u{0c}\n\r\t\u{e9}\u{1f600}".to_string())
+                ),
+                ("f".to_string(), JsonValue::Array(vec![])),
+            ])
+        );
+        assert_eq!(val.get("d"), Some(&JsonValue::Bool(true)));
+        assert_eq!(val.get("z"), None);
+        for s in &[
+            "",
+            "nul",
+            "[1,]",
+            "{\"a\" 1}",
+            "{1:2}",
+            "01",
+            "1.",
+            "-",
+            "1e",
+            "\"\\x\"",
+            "\"\n\"",
+            "\"\\ud83d\"",
+            "[1] 2",
+            "1e200",
+        ] {
+            assert_eq!(s.parse::<JsonValue>(), Err(parse_error()), "{:?}", s);
+        }
+        let deep = format!("{}{}", "[".repeat(MAX_DEPTH + 2), "]".repeat(MAX_DEPTH + 2));
+        assert!(deep.parse::<JsonValue>().is_err());
+    }
+
+    #[test]
+    fn to_string() {
+        let s = r#"{"a":[1,-25,0.1],"b":{"c":null},"d":true,"e":"x\"\\\n\u001f\u00e9"}"#;
+        let val: JsonValue = s.parse().unwrap();
+        assert_eq!(
+            val.to_string(),
+            r#"{"a":[1,-25,0.1],"b":{"c":null},"d":true,"e":"x\"\\\n\u001fé"}"#
+        );
+        let ts = Timestamp::new(2020, 1, 2, 3, 4, 5, 120000000);
+        let val = JsonValue::Array(vec![
+            JsonValue::Timestamp(ts),
+            JsonValue::Timestamp(ts.and_tz_hm_offset(-9, -30)),
+            JsonValue::Binary(vec![0x01, 0xab]),
+        ]);
+        assert_eq!(
+            val.to_string(),
+            r#"["2020-01-02T03:04:05.12","2020-01-02T03:04:05.12-09:30","01AB"]"#
+        );
+        let parsed: JsonValue = val.to_string().parse().unwrap();
+        let elems = parsed.as_array().unwrap();
+        assert_eq!(elems[0].as_timestamp(), Some(ts));
+        assert_eq!(elems[2].as_binary(), Some(vec![0x01, 0xab]));
+        let val = JsonValue::Array(vec![
+            JsonValue::Number(OracleNumber::infinity()),
+            JsonValue::Number(OracleNumber::neg_infinity()),
+        ]);
+        assert_eq!(val.to_string(), "[null,null]");
+    }
+}
@@ -15,26 +15,52 @@
 
 //! SQL data types
 
+use std::time::Duration;
+use try_from::TryFrom;
+
 use crate::Connection;
 use crate::Error;
 use crate::Result;
 use crate::SqlValue;
 
+#[cfg(feature = "bigdecimal")]
+mod bigdecimal;
 #[cfg(feature = "chrono")]
 mod chrono;
+#[cfg(all(feature = "chrono", feature = "chrono-tz"))]
+mod chrono_tz;
+mod format_model;
 mod interval_ds;
 mod interval_ym;
+mod json;
 mod object;
+mod object_ref;
+mod oracle_number;
 mod oracle_type;
+#[cfg(feature = "rust_decimal")]
+mod rust_decimal;
+#[cfg(feature = "serde_json")]
+mod serde_json;
+#[cfg(feature = "time")]
+mod time;
 mod timestamp;
+#[cfg(feature = "uuid")]
+mod uuid;
 
 pub use self::interval_ds::IntervalDS;
 pub use self::interval_ym::IntervalYM;
+pub use self::json::JsonValue;
 pub use self::object::Collection;
+pub use self::object::CollectionIter;
 pub use self::object::Object;
 pub use self::object::ObjectType;
 pub use self::object::ObjectTypeAttr;
+pub use self::object::ObjectTypeDetails;
 pub(crate) use self::object::ObjectTypeInternal;
+pub use self::object::ObjectTypeKind;
+pub use self::object::ObjectValue;
+pub use self::object_ref::Ref;
+pub use self::oracle_number::OracleNumber;
 pub(crate) use self::oracle_type::NativeType;
 pub use self::oracle_type::OracleType;
 pub use self::timestamp::Timestamp;
@@ -48,26 +74,50 @@ pub use self::timestamp::Timestamp;
 /// | --- | --- |
 /// | character data types | String |
 /// | " | i8, i16, i32, i64, isize, u8, u16, u32, u64, usize, f64, f32 by using `String.parse()` |
+/// | " | i128, u128 by using [OracleNumber][] |
 /// | " | Vec\<u8> (The Oracle value must be in hexadecimal.) |
 /// | " | [Timestamp][] by `String.parse()` |
 /// | " | [IntervalDS][] by `String.parse()` |
 /// | " | [IntervalYM][] by `String.parse()` |
+/// | " | [OracleNumber][] by `String.parse()` |
+/// | " | [Ref][] by `String.parse()` (The Oracle value must be returned by `REFTOHEX()`.) |
+/// | " | [JsonValue][] by `String.parse()` (The Oracle value must be JSON text.) |
 /// | numeric data types | i8, i16, i32, i64, isize, u8, u16, u32, u64, usize, f64, f32 |
+/// | " | i128, u128 (The Oracle value must be an integer.) |
+/// | " | [OracleNumber][] |
 /// | " | String |
 /// | raw | Vec\<u8> |
 /// | " | String (The Oracle value is converted to characters in hexadecimal.) |
+/// | raw and blob | [JsonValue][] (The Oracle value must be JSON text in UTF-8.) |
 /// | timestamp data types | [Timestamp][] |
 /// | " | String |
 /// | interval day to month | [IntervalDS][] |
+/// | " | [std::time::Duration][] (The Oracle value must not be negative.) |
 /// | " | String |
 /// | interval year to month | [IntervalYM][] |
 /// | " | String |
+/// | xmltype | String |
 /// | [Oracle object] except [Oracle collection] | [Object][] |
 /// | " | String |
 /// | [Oracle collection] | [Collection][] |
+/// | " | Vec\<T> (See below.) |
 /// | " | String |
 /// | boolean (PL/SQL only) | bool |
 ///
+/// Values of `timestamp with local time zone` are converted by ODPI-C using
+/// the client time zone, not the session time zone. Cast them to
+/// `timestamp with time zone` in SQL to get values in the session time zone.
+///
+/// Varrays and nested tables are converted to `Vec<T>` where `T` is one of
+/// i8, i16, i32, i64, i128, isize, u16, u32, u64, u128, usize, f32, f64, bool,
+/// String, Vec\<u8>, [Timestamp][], [IntervalDS][], [IntervalYM][], [OracleNumber][],
+/// [Object][], [Collection][] and `Option<T>` of any [FromSql][] type.
+/// `u8` is excluded so that `Vec<u8>` keeps being converted from raw data.
+/// The date and time types added by `chrono` and `time` features below are
+/// also available as element types.
+/// Deleted elements in nested tables are skipped.
+/// Use [Collection.to_vec][] for other element types.
+///
 /// When `chrono` feature is enabled, the followings are added.
 ///
 /// | Oracle Type | Rust Type |
@@ -76,7 +126,43 @@ pub use self::timestamp::Timestamp;
 /// | " | [chrono::Date] |
 /// | " | [chrono::naive::NaiveDateTime][] |
 /// | " | [chrono::naive::NaiveDate][] |
+/// | " | [chrono::naive::NaiveTime][] |
 /// | interval day to second | [chrono::Duration][] |
+/// | " | [chrono::naive::NaiveTime][] (The Oracle value must be between 0 and 24 hours.) |
+///
+/// [chrono::DateTime][] is supported for `Utc`, `Local` and `FixedOffset`.
+/// Other time zones aren't supported because a time zone cannot be
+/// created from a fetched value without knowing its type.
+///
+/// When `chrono-tz` feature is also enabled, timestamp data types are converted to
+/// [chrono::DateTime][]\<[chrono_tz::Tz][]> by using the time zone region name
+/// or `Etc/GMT±n` for the time zone offset.
+///
+/// When `time` feature is enabled, the followings are added.
+///
+/// | Oracle Type | Rust Type |
+/// | --- | --- |
+/// | timestamp data types | [time::OffsetDateTime][] |
+/// | " | [time::PrimitiveDateTime][] |
+/// | " | [time::Date][] |
+/// | " | [time::Time][] |
+/// | interval day to second | [time::Duration][] |
+/// | " | [time::Time][] (The Oracle value must be between 0 and 24 hours.) |
+///
+/// When `rust_decimal` or `bigdecimal` feature is enabled, the followings are added.
+///
+/// | Oracle Type | Rust Type |
+/// | --- | --- |
+/// | numeric data types | [rust_decimal::Decimal][] |
+/// | " | [bigdecimal::BigDecimal][] |
+///
+/// When `serde_json` feature is enabled, character data types, raw and blob
+/// containing JSON text are converted to [serde_json::Value][].
+///
+/// When `uuid` feature is enabled, raw and blob values of 16 bytes and
+/// character data types containing 32 hexadecimal digits with or without
+/// hyphens are converted to [uuid::Uuid][]. Values of other lengths return
+/// `Err(Error::InvalidTypeConversion(...))`.
 ///
 /// This conversion is used also to get values from output parameters.
 ///
@@ -85,13 +171,30 @@ pub use self::timestamp::Timestamp;
 /// [Timestamp]: struct.Timestamp.html
 /// [IntervalDS]: struct.IntervalDS.html
 /// [IntervalYM]: struct.IntervalYM.html
+/// [std::time::Duration]: https://doc.rust-lang.org/std/time/struct.Duration.html
+/// [OracleNumber]: struct.OracleNumber.html
+/// [Ref]: struct.Ref.html
+/// [JsonValue]: enum.JsonValue.html
 /// [chrono::Date]: https://docs.rs/chrono/0.4/chrono/struct.Date.html
 /// [chrono::DateTime]: https://docs.rs/chrono/0.4/chrono/struct.DateTime.html
 /// [chrono::naive::NaiveDate]: https://docs.rs/chrono/0.4/chrono/naive/struct.NaiveDate.html
 /// [chrono::naive::NaiveDateTime]: https://docs.rs/chrono/0.4/chrono/naive/struct.NaiveDateTime.html
+/// [chrono::naive::NaiveTime]: https://docs.rs/chrono/0.4/chrono/naive/struct.NaiveTime.html
 /// [chrono::Duration]: https://docs.rs/chrono/0.4/chrono/struct.Duration.html
+/// [time::OffsetDateTime]: https://docs.rs/time/0.3/time/struct.OffsetDateTime.html
+/// [time::PrimitiveDateTime]: https://docs.rs/time/0.3/time/struct.PrimitiveDateTime.html
+/// [time::Date]: https://docs.rs/time/0.3/time/struct.Date.html
+/// [time::Time]: https://docs.rs/time/0.3/time/struct.Time.html
+/// [time::Duration]: https://docs.rs/time/0.3/time/struct.Duration.html
+/// [chrono_tz::Tz]: https://docs.rs/chrono-tz/0.10/chrono_tz/enum.Tz.html
+/// [rust_decimal::Decimal]: https://docs.rs/rust_decimal/1/rust_decimal/struct.Decimal.html
+/// [bigdecimal::BigDecimal]: https://docs.rs/bigdecimal/0.4/bigdecimal/struct.BigDecimal.html
+/// [serde_json::Value]: https://docs.rs/serde_json/1/serde_json/enum.Value.html
+/// [uuid::Uuid]: https://docs.rs/uuid/1/uuid/struct.Uuid.html
 /// [Collection]: struct.Collection.html
+/// [Collection.to_vec]: struct.Collection.html#method.to_vec
 /// [Object]: struct.Object.html
+/// [FromSql]: trait.FromSql.html
 pub trait FromSql {
     fn from_sql(val: &SqlValue) -> Result<Self>
     where
@@ -106,12 +209,16 @@ pub trait FromSql {
 /// | Rust Type | Oracle Type |
 /// | --- | --- |
 /// | str, String | nvarchar2(0) |
-/// | i8, i16, i32, i64, u8, u16, u32, u64, f32, f64 | number |
+/// | i8, i16, i32, i64, i128, u8, u16, u32, u64, u128, f32, f64 | number |
 /// | Vec\<u8> | raw(0) |
 /// | bool | boolean (PL/SQL only) |
 /// | [Timestamp][] | timestamp(9) with time zone |
 /// | [IntervalDS][] | interval day(9) to second(9) |
+/// | [std::time::Duration][] | interval day(9) to second(9) |
 /// | [IntervalYM][] | interval year(9) to month |
+/// | [OracleNumber][] | number |
+/// | [Ref][] | varchar2(0) |
+/// | [JsonValue][] | json (bound as clob) |
 ///
 /// When `chrono` feature is enabled, the followings are added.
 ///
@@ -121,16 +228,53 @@ pub trait FromSql {
 /// | [chrono::DateTime][] | timestamp(9) with time zone |
 /// | [chrono::naive::NaiveDate][] | timestamp(0) |
 /// | [chrono::naive::NaiveDateTime][] | timestamp(9) |
+/// | [chrono::naive::NaiveTime][] | interval day(9) to second(9) |
 /// | [chrono::Duration][] | interval day(9) to second(9) |
 ///
+/// When `time` feature is enabled, the followings are added.
+///
+/// | Rust Type | Oracle Type |
+/// | --- | --- |
+/// | [time::OffsetDateTime][] | timestamp(9) with time zone |
+/// | [time::PrimitiveDateTime][] | timestamp(9) |
+/// | [time::Date][] | timestamp(0) |
+/// | [time::Time][] | interval day(9) to second(9) |
+/// | [time::Duration][] | interval day(9) to second(9) |
+///
+/// When `rust_decimal` or `bigdecimal` feature is enabled, the followings are added.
+///
+/// | Rust Type | Oracle Type |
+/// | --- | --- |
+/// | [rust_decimal::Decimal][] | number |
+/// | [bigdecimal::BigDecimal][] | number |
+///
+/// When `serde_json` feature is enabled, [serde_json::Value][] is bound
+/// as json in the same way as [JsonValue][].
+///
+/// When `uuid` feature is enabled, [uuid::Uuid][] is bound as raw(16).
+///
 /// [Timestamp]: struct.Timestamp.html
 /// [IntervalDS]: struct.IntervalDS.html
 /// [IntervalYM]: struct.IntervalYM.html
+/// [std::time::Duration]: https://doc.rust-lang.org/std/time/struct.Duration.html
+/// [OracleNumber]: struct.OracleNumber.html
+/// [Ref]: struct.Ref.html
+/// [JsonValue]: enum.JsonValue.html
 /// [chrono::Date]: https://docs.rs/chrono/0.4/chrono/struct.Date.html
 /// [chrono::DateTime]: https://docs.rs/chrono/0.4/chrono/struct.DateTime.html
 /// [chrono::naive::NaiveDate]: https://docs.rs/chrono/0.4/chrono/naive/struct.NaiveDate.html
 /// [chrono::naive::NaiveDateTime]: https://docs.rs/chrono/0.4/chrono/naive/struct.NaiveDateTime.html
+/// [chrono::naive::NaiveTime]: https://docs.rs/chrono/0.4/chrono/naive/struct.NaiveTime.html
 /// [chrono::Duration]: https://docs.rs/chrono/0.4/chrono/struct.Duration.html
+/// [time::OffsetDateTime]: https://docs.rs/time/0.3/time/struct.OffsetDateTime.html
+/// [time::PrimitiveDateTime]: https://docs.rs/time/0.3/time/struct.PrimitiveDateTime.html
+/// [time::Date]: https://docs.rs/time/0.3/time/struct.Date.html
+/// [time::Time]: https://docs.rs/time/0.3/time/struct.Time.html
+/// [time::Duration]: https://docs.rs/time/0.3/time/struct.Duration.html
+/// [rust_decimal::Decimal]: https://docs.rs/rust_decimal/1/rust_decimal/struct.Decimal.html
+/// [bigdecimal::BigDecimal]: https://docs.rs/bigdecimal/0.4/bigdecimal/struct.BigDecimal.html
+/// [serde_json::Value]: https://docs.rs/serde_json/1/serde_json/enum.Value.html
+/// [uuid::Uuid]: https://docs.rs/uuid/1/uuid/struct.Uuid.html
 pub trait ToSqlNull {
     fn oratype_for_null(conn: &Connection) -> Result<OracleType>;
 }
@@ -142,12 +286,16 @@ pub trait ToSqlNull {
 /// | Rust Type | Oracle Type | Oracle Value |
 /// | --- | --- | --- |
 /// | str, String | nvarchar2(length of the rust value) | The specified value |
-/// | i8, i16, i32, i64, isize, u8, u16, u32, u64, usize, f32, f64 | number | The specified value |
+/// | i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, u128, usize, f32, f64 | number | The specified value |
 /// | Vec\<u8> | raw(length of the rust value) | The specified value |
 /// | bool | boolean (PL/SQL only) | The specified value |
 /// | [Timestamp][] | timestamp(9) with time zone | The specified value |
 /// | [IntervalDS][] | interval day(9) to second(9) | The specified value |
+/// | [std::time::Duration][] | interval day(9) to second(9) | The specified value |
 /// | [IntervalYM][] | interval year(9) to month | The specified value |
+/// | [OracleNumber][] | number | The specified value |
+/// | [Ref][] | varchar2(length of the hexadecimal representation) | The hexadecimal representation. Use `HEXTOREF()` in SQL. |
+/// | [JsonValue][] | json (bound as clob) | JSON text |
 /// | [Collection][] | type returned by [Collection.oracle_type][] | The specified value |
 /// | [Object][] | type returned by [Object.oracle_type] | The specified value |
 /// | Option\<T> where T: ToSql + [ToSqlNull][] | When the value is `Some`, the contained value decides the Oracle type. When it is `None`, ToSqlNull decides it. | When the value is `Some`, the contained value. When it is `None`, a null value.
@@ -157,6 +305,12 @@ pub trait ToSqlNull {
 /// When you need to bind output parameters such as varchar2, use `OracleType`
 /// or `(&ToSql, &OracleType)` to specify the maximum length of data types.
 ///
+/// PL/SQL boolean values are supported by Oracle client and server 12.1
+/// or later. When either is older, binding `bool` returns
+/// `Err(Error::InvalidOperation(...))`. Use `(&bool, &OracleType::Number(1, 0))`
+/// instead and declare the PL/SQL parameter as `NUMBER(1)`. It binds 1 for
+/// true and 0 for false. Get output values of such parameters as integers.
+///
 /// When `chrono` feature is enabled, the following conversions are added.
 ///
 /// | Rust Type | Oracle Type |
@@ -165,11 +319,38 @@ pub trait ToSqlNull {
 /// | [chrono::DateTime][] | timestamp(9) with time zone |
 /// | [chrono::naive::NaiveDate][] | timestamp(0) |
 /// | [chrono::naive::NaiveDateTime][] | timestamp(9) |
+/// | [chrono::naive::NaiveTime][] | interval day(9) to second(9) |
 /// | [chrono::Duration][] | interval day(9) to second(9) |
 ///
+/// When `time` feature is enabled, the followings are added.
+///
+/// | Rust Type | Oracle Type |
+/// | --- | --- |
+/// | [time::OffsetDateTime][] | timestamp(9) with time zone |
+/// | [time::PrimitiveDateTime][] | timestamp(9) |
+/// | [time::Date][] | timestamp(0) |
+/// | [time::Time][] | interval day(9) to second(9) |
+/// | [time::Duration][] | interval day(9) to second(9) |
+///
+/// When `rust_decimal` or `bigdecimal` feature is enabled, the followings are added.
+///
+/// | Rust Type | Oracle Type |
+/// | --- | --- |
+/// | [rust_decimal::Decimal][] | number |
+/// | [bigdecimal::BigDecimal][] | number |
+///
+/// When `serde_json` feature is enabled, [serde_json::Value][] is bound
+/// as json in the same way as [JsonValue][].
+///
+/// When `uuid` feature is enabled, [uuid::Uuid][] is bound as raw(16).
+///
 /// [Timestamp]: struct.Timestamp.html
 /// [IntervalDS]: struct.IntervalDS.html
 /// [IntervalYM]: struct.IntervalYM.html
+/// [std::time::Duration]: https://doc.rust-lang.org/std/time/struct.Duration.html
+/// [OracleNumber]: struct.OracleNumber.html
+/// [Ref]: struct.Ref.html
+/// [JsonValue]: enum.JsonValue.html
 /// [Collection]: struct.Collection.html
 /// [Collection.oracle_type]: struct.Collection.html#method.oracle_type
 /// [Object]: struct.Object.html
@@ -180,7 +361,17 @@ pub trait ToSqlNull {
 /// [chrono::DateTime]: https://docs.rs/chrono/0.4/chrono/struct.DateTime.html
 /// [chrono::naive::NaiveDate]: https://docs.rs/chrono/0.4/chrono/naive/struct.NaiveDate.html
 /// [chrono::naive::NaiveDateTime]: https://docs.rs/chrono/0.4/chrono/naive/struct.NaiveDateTime.html
+/// [chrono::naive::NaiveTime]: https://docs.rs/chrono/0.4/chrono/naive/struct.NaiveTime.html
 /// [chrono::Duration]: https://docs.rs/chrono/0.4/chrono/struct.Duration.html
+/// [time::OffsetDateTime]: https://docs.rs/time/0.3/time/struct.OffsetDateTime.html
+/// [time::PrimitiveDateTime]: https://docs.rs/time/0.3/time/struct.PrimitiveDateTime.html
+/// [time::Date]: https://docs.rs/time/0.3/time/struct.Date.html
+/// [time::Time]: https://docs.rs/time/0.3/time/struct.Time.html
+/// [time::Duration]: https://docs.rs/time/0.3/time/struct.Duration.html
+/// [rust_decimal::Decimal]: https://docs.rs/rust_decimal/1/rust_decimal/struct.Decimal.html
+/// [bigdecimal::BigDecimal]: https://docs.rs/bigdecimal/0.4/bigdecimal/struct.BigDecimal.html
+/// [serde_json::Value]: https://docs.rs/serde_json/1/serde_json/enum.Value.html
+/// [uuid::Uuid]: https://docs.rs/uuid/1/uuid/struct.Uuid.html
 ///
 pub trait ToSql {
     fn oratype(&self, conn: &Connection) -> Result<OracleType>;
@@ -230,17 +421,85 @@ impl_from_and_to_sql!(i8, to_i8, set_i8, OracleType::Number(0, 0));
 impl_from_and_to_sql!(i16, to_i16, set_i16, OracleType::Number(0, 0));
 impl_from_and_to_sql!(i32, to_i32, set_i32, OracleType::Number(0, 0));
 impl_from_and_to_sql!(i64, to_i64, set_i64, OracleType::Number(0, 0));
+impl_from_and_to_sql!(i128, to_i128, set_i128, OracleType::Number(0, 0));
 impl_from_and_to_sql!(isize, to_isize, set_isize, OracleType::Number(0, 0));
 impl_from_and_to_sql!(u8, to_u8, set_u8, OracleType::Number(0, 0));
 impl_from_and_to_sql!(u16, to_u16, set_u16, OracleType::Number(0, 0));
 impl_from_and_to_sql!(u32, to_u32, set_u32, OracleType::Number(0, 0));
 impl_from_and_to_sql!(u64, to_u64, set_u64, OracleType::Number(0, 0));
+impl_from_and_to_sql!(u128, to_u128, set_u128, OracleType::Number(0, 0));
 impl_from_and_to_sql!(usize, to_usize, set_usize, OracleType::Number(0, 0));
 impl_from_and_to_sql!(f64, to_f64, set_f64, OracleType::Number(0, 0));
 impl_from_and_to_sql!(f32, to_f32, set_f32, OracleType::Number(0, 0));
 impl_from_and_to_sql!(bool, to_bool, set_bool, OracleType::Boolean);
 impl_from_sql!(String, to_string);
 impl_from_sql!(Vec<u8>, to_bytes);
+
+macro_rules! impl_from_sql_for_vec {
+    ($($type:ty),*) => {
+        $(
+            impl FromSql for Vec<$type> {
+                fn from_sql(val: &SqlValue) -> Result<Vec<$type>> {
+                    val.to_collection()?.to_vec()
+                }
+            }
+        )*
+    };
+}
+
+impl_from_sql_for_vec!(
+    i8,
+    i16,
+    i32,
+    i64,
+    i128,
+    isize,
+    u16,
+    u32,
+    u64,
+    u128,
+    usize,
+    f32,
+    f64,
+    bool,
+    String,
+    Vec<u8>,
+    Timestamp,
+    IntervalDS,
+    IntervalYM,
+    OracleNumber,
+    Object,
+    Collection
+);
+
+#[cfg(feature = "chrono")]
+impl_from_sql_for_vec!(
+    ::chrono::DateTime<::chrono::Utc>,
+    ::chrono::DateTime<::chrono::Local>,
+    ::chrono::DateTime<::chrono::FixedOffset>,
+    ::chrono::naive::NaiveDateTime,
+    ::chrono::naive::NaiveDate,
+    ::chrono::naive::NaiveTime,
+    ::chrono::Duration
+);
+
+#[cfg(all(feature = "chrono", feature = "chrono-tz"))]
+impl_from_sql_for_vec!(::chrono::DateTime<::chrono_tz::Tz>);
+
+#[cfg(feature = "time")]
+impl_from_sql_for_vec!(
+    ::time::OffsetDateTime,
+    ::time::PrimitiveDateTime,
+    ::time::Date,
+    ::time::Time,
+    ::time::Duration
+);
+
+impl<T: FromSql> FromSql for Vec<Option<T>> {
+    fn from_sql(val: &SqlValue) -> Result<Vec<Option<T>>> {
+        val.to_collection()?.to_vec()
+    }
+}
 impl_from_and_to_sql!(
     Timestamp,
     to_timestamp,
@@ -262,6 +521,34 @@ impl_from_and_to_sql!(
     set_interval_ym,
     OracleType::IntervalYM(9)
 );
+impl_from_and_to_sql!(
+    OracleNumber,
+    to_oracle_number,
+    OracleNumber,
+    set_oracle_number,
+    OracleType::Number(0, 0)
+);
+
+impl FromSql for Duration {
+    fn from_sql(val: &SqlValue) -> Result<Duration> {
+        Duration::try_from(val.to_interval_ds()?)
+    }
+}
+
+impl ToSqlNull for Duration {
+    fn oratype_for_null(_conn: &Connection) -> Result<OracleType> {
+        Ok(OracleType::IntervalDS(9, 9))
+    }
+}
+
+impl ToSql for Duration {
+    fn oratype(&self, _conn: &Connection) -> Result<OracleType> {
+        Ok(OracleType::IntervalDS(9, 9))
+    }
+    fn to_sql(&self, val: &mut SqlValue) -> Result<()> {
+        val.set_interval_ds(&IntervalDS::try_from(*self)?)
+    }
+}
 
 impl ToSqlNull for String {
     fn oratype_for_null(_conn: &Connection) -> Result<OracleType> {
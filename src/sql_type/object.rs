@@ -15,13 +15,18 @@
 
 use std::cmp;
 use std::fmt;
+use std::marker::PhantomData;
 use std::ptr;
 use std::sync::Arc;
 
 use crate::binding::*;
 use crate::chkerr;
 use crate::sql_type::FromSql;
+use crate::sql_type::IntervalDS;
+use crate::sql_type::IntervalYM;
+use crate::sql_type::OracleNumber;
 use crate::sql_type::OracleType;
+use crate::sql_type::Timestamp;
 use crate::sql_type::ToSql;
 use crate::to_rust_str;
 use crate::util::write_literal;
@@ -70,7 +75,15 @@ unsafe fn release_dpi_data(data: &dpiData, native_type_num: u32) {
 /// # Ok(())} fn main() { try_main().unwrap(); }
 /// ```
 ///
+/// `clone()` returns another reference to the same collection. Changes
+/// via the clone are visible through the original. Use [deep_copy()][]
+/// to get an independent copy. `==` compares element values recursively.
+/// Use [eq_values()][] to get errors while reading the values.
+///
 /// Note: Methods in the type may be changed in future.
+///
+/// [deep_copy()]: #method.deep_copy
+/// [eq_values()]: #method.eq_values
 pub struct Collection {
     ctxt: &'static Context,
     pub(crate) handle: *mut dpiObject,
@@ -184,10 +197,10 @@ impl Collection {
         Ok(exists != 0)
     }
 
-    /// Returns the value of the element at the specified index.
-    pub fn get<T>(&self, index: i32) -> Result<T>
+    // Calls `f` with the element at the specified index.
+    fn with_element<F, R>(&self, index: i32, f: F) -> Result<R>
     where
-        T: FromSql,
+        F: FnOnce(&SqlValue) -> Result<R>,
     {
         let oratype = self.objtype.element_oracle_type().unwrap();
         let mut data = Default::default();
@@ -204,13 +217,33 @@ impl Collection {
             self.ctxt,
             dpiObject_getElementValueByIndex(self.handle, index, native_type_num, &mut data)
         );
-        let res = sql_value.get();
+        let res = f(&sql_value);
         unsafe { release_dpi_data(&data, native_type_num) };
         res
     }
 
+    /// Returns the value of the element at the specified index.
+    pub fn get<T>(&self, index: i32) -> Result<T>
+    where
+        T: FromSql,
+    {
+        self.with_element(index, |val| val.get())
+    }
+
     /// Sets the value to the element at the specified index.
     pub fn set(&mut self, index: i32, value: &dyn ToSql) -> Result<()> {
+        self.set_element(index, value)
+    }
+
+    fn check_path_index(&self, index: i32, path: &str) -> Result<()> {
+        if self.exist(index)? {
+            Ok(())
+        } else {
+            Err(Error::OutOfRange(format!("no element at {}", path)))
+        }
+    }
+
+    fn set_element(&self, index: i32, value: &dyn ToSql) -> Result<()> {
         let oratype = self.objtype.element_oracle_type().unwrap();
         let mut data = Default::default();
         let mut sql_value = SqlValue::from_oratype(self.ctxt, oratype, &mut data)?;
@@ -259,6 +292,129 @@ impl Collection {
         chkerr!(self.ctxt, dpiObject_trim(self.handle, len as u32));
         Ok(())
     }
+
+    /// Returns an iterator over pairs of indexes and element values.
+    /// Deleted elements are skipped.
+    ///
+    /// ```no_run
+    /// # use oracle::*; use oracle::sql_type::*; fn try_main() -> Result<()> {
+    /// let conn = Connection::connect("scott", "tiger", "")?;
+    /// let objtype = conn.object_type("MDSYS.SDO_ELEM_INFO_ARRAY")?;
+    /// let coll = objtype.collection_from(&[1, 2, 3])?;
+    ///
+    /// for elem in coll.iter::<i32>() {
+    ///     let (index, value) = elem?;
+    ///     println!("{}: {}", index, value); // prints "0: 1", "1: 2" and "2: 3"
+    /// }
+    /// # Ok(())} fn main() { try_main().unwrap(); }
+    /// ```
+    pub fn iter<T>(&self) -> CollectionIter<'_, T>
+    where
+        T: FromSql,
+    {
+        CollectionIter {
+            coll: self,
+            index: None,
+            done: false,
+            phantom: PhantomData,
+        }
+    }
+
+    /// Returns element values in a vector. Deleted elements are skipped.
+    pub fn to_vec<T>(&self) -> Result<Vec<T>>
+    where
+        T: FromSql,
+    {
+        self.iter()
+            .map(|elem| elem.map(|(_, value)| value))
+            .collect()
+    }
+
+    /// Converts the collection to a tree of [ObjectValue][]s recursively.
+    /// Deleted elements are skipped.
+    ///
+    /// [ObjectValue]: enum.ObjectValue.html
+    pub fn to_value_tree(&self) -> Result<ObjectValue> {
+        let mut elems = Vec::new();
+        let mut index = match self.first_index() {
+            Ok(index) => Some(index),
+            Err(Error::NoDataFound) => None,
+            Err(err) => return Err(err),
+        };
+        while let Some(idx) = index {
+            elems.push(self.with_element(idx, ObjectValue::from_sql_value)?);
+            index = match self.next_index(idx) {
+                Ok(index) => Some(index),
+                Err(Error::NoDataFound) => None,
+                Err(err) => return Err(err),
+            };
+        }
+        Ok(ObjectValue::Collection(self.objtype.to_string(), elems))
+    }
+
+    /// Returns a copy of the collection including its elements.
+    /// Unlike `clone()`, changes to the copy don't affect the original.
+    pub fn deep_copy(&self) -> Result<Collection> {
+        let mut handle = ptr::null_mut();
+        chkerr!(self.ctxt, dpiObject_copy(self.handle, &mut handle));
+        Ok(Collection::new(self.ctxt, handle, self.objtype.clone()))
+    }
+
+    /// Returns `true` when the types and all element values of the collections
+    /// are equal. Element values are compared recursively.
+    ///
+    /// This returns an error when an element value cannot be read.
+    pub fn eq_values(&self, other: &Collection) -> Result<bool> {
+        if self.handle == other.handle {
+            return Ok(true);
+        }
+        Ok(self.to_value_tree()? == other.to_value_tree()?)
+    }
+}
+
+/// An iterator over elements of a [Collection][] returned by [Collection.iter][]
+///
+/// [Collection]: struct.Collection.html
+/// [Collection.iter]: struct.Collection.html#method.iter
+pub struct CollectionIter<'a, T>
+where
+    T: FromSql,
+{
+    coll: &'a Collection,
+    index: Option<i32>,
+    done: bool,
+    phantom: PhantomData<T>,
+}
+
+impl<'a, T> Iterator for CollectionIter<'a, T>
+where
+    T: FromSql,
+{
+    type Item = Result<(i32, T)>;
+
+    fn next(&mut self) -> Option<Result<(i32, T)>> {
+        if self.done {
+            return None;
+        }
+        let index = match self.index {
+            Some(index) => self.coll.next_index(index),
+            None => self.coll.first_index(),
+        };
+        match index {
+            Ok(index) => {
+                self.index = Some(index);
+                Some(self.coll.get(index).map(|value| (index, value)))
+            }
+            Err(Error::NoDataFound) => {
+                self.done = true;
+                None
+            }
+            Err(err) => {
+                self.done = true;
+                Some(Err(err))
+            }
+        }
+    }
 }
 
 impl Clone for Collection {
@@ -268,6 +424,14 @@ impl Clone for Collection {
     }
 }
 
+/// Compares element values by [eq_values()](#method.eq_values).
+/// Collections are not equal when an element value cannot be read.
+impl cmp::PartialEq for Collection {
+    fn eq(&self, other: &Self) -> bool {
+        self.eq_values(other).unwrap_or(false)
+    }
+}
+
 impl Drop for Collection {
     fn drop(&mut self) {
         let _ = unsafe { dpiObject_release(self.handle) };
@@ -355,7 +519,15 @@ impl fmt::Debug for Collection {
 /// # Ok(())} fn main() { try_main().unwrap(); }
 /// ```
 ///
+/// `clone()` returns another reference to the same object. Changes
+/// via the clone are visible through the original. Use [deep_copy()][]
+/// to get an independent copy. `==` compares attribute values recursively.
+/// Use [eq_values()][] to get errors while reading the values.
+///
 /// Note: Methods in the type may be changed in future.
+///
+/// [deep_copy()]: #method.deep_copy
+/// [eq_values()]: #method.eq_values
 pub struct Object {
     ctxt: &'static Context,
     pub(crate) handle: *mut dpiObject,
@@ -380,6 +552,12 @@ impl Object {
         &self.objtype
     }
 
+    // Same with type_attr but the error message is the path up to the attribute.
+    fn path_attr(&self, name: &str, path: &str) -> Result<&ObjectTypeAttr> {
+        self.type_attr(name)
+            .map_err(|_| Error::InvalidAttributeName(path.to_string()))
+    }
+
     fn type_attr(&self, name: &str) -> Result<&ObjectTypeAttr> {
         for attr in self.objtype.attributes() {
             if attr.name() == name {
@@ -389,9 +567,10 @@ impl Object {
         Err(Error::InvalidAttributeName(name.to_string()))
     }
 
-    pub(crate) fn get_by_attr<T>(&self, attr: &ObjectTypeAttr) -> Result<T>
+    // Calls `f` with the value of the specified attribute.
+    fn with_attr<F, R>(&self, attr: &ObjectTypeAttr, f: F) -> Result<R>
     where
-        T: FromSql,
+        F: FnOnce(&SqlValue) -> Result<R>,
     {
         let mut data = Default::default();
         let mut buf = [0i8; 172]; // DPI_NUMBER_AS_TEXT_CHARS in odpi/src/dpiImpl.h
@@ -407,11 +586,18 @@ impl Object {
             self.ctxt,
             dpiObject_getAttributeValue(self.handle, attr.handle.raw(), native_type_num, &mut data)
         );
-        let res = sql_value.get();
+        let res = f(&sql_value);
         unsafe { release_dpi_data(&data, native_type_num) };
         res
     }
 
+    pub(crate) fn get_by_attr<T>(&self, attr: &ObjectTypeAttr) -> Result<T>
+    where
+        T: FromSql,
+    {
+        self.with_attr(attr, |val| val.get())
+    }
+
     /// Gets an value at the specified attribute.
     pub fn get<T>(&self, name: &str) -> Result<T>
     where
@@ -423,6 +609,10 @@ impl Object {
     /// Sets the value to the specified attribute.
     pub fn set(&mut self, name: &str, value: &dyn ToSql) -> Result<()> {
         let attrtype = self.type_attr(name)?;
+        self.set_by_attr(attrtype, value)
+    }
+
+    fn set_by_attr(&self, attrtype: &ObjectTypeAttr, value: &dyn ToSql) -> Result<()> {
         let mut data = Default::default();
         let mut sql_value = SqlValue::from_oratype(self.ctxt, &attrtype.oratype, &mut data)?;
         sql_value.set(value)?;
@@ -437,6 +627,97 @@ impl Object {
         );
         Ok(())
     }
+
+    /// Gets a value by a path of attribute names and collection indexes
+    /// such as `"LINES[2].ADDRESS.CITY"`.
+    ///
+    /// Attribute names are separated by dots. An index in brackets follows an
+    /// attribute name of a collection type or another index of nested collections.
+    /// Indexes are same with ones passed to [Collection.get][].
+    ///
+    /// Errors name the failing segment of the path.
+    /// `Err(Error::InvalidAttributeName(...))` is returned when an attribute
+    /// isn't found, `Err(Error::OutOfRange(...))` when an element doesn't exist
+    /// and `Err(Error::InvalidOperation(...))` when an attribute name or an index
+    /// is applied to a value which isn't an object or a collection respectively.
+    /// When an object or a collection in the middle of the path is null,
+    /// `Err(Error::NullValue)` is returned.
+    ///
+    /// [Collection.get]: struct.Collection.html#method.get
+    ///
+    /// ```no_run
+    /// # use oracle::*; use oracle::sql_type::*; fn try_main() -> Result<()> {
+    /// let conn = Connection::connect("scott", "tiger", "")?;
+    /// let geom: Object = conn.query_row_as("select shape from cities where id = 1", &[])?;
+    ///
+    /// let x: f64 = geom.get_path("SDO_POINT.X")?;
+    /// let elem_info: i32 = geom.get_path("SDO_ELEM_INFO[0]")?;
+    /// # Ok(())} fn main() { try_main().unwrap(); }
+    /// ```
+    pub fn get_path<T>(&self, path: &str) -> Result<T>
+    where
+        T: FromSql,
+    {
+        let (parent, last) = PathNode::Object(self.clone()).parent_of(path)?;
+        parent.with_child(&last, path, |val| val.get())
+    }
+
+    /// Sets the value by a path of attribute names and collection indexes
+    /// such as `"LINES[2].ADDRESS.CITY"`.
+    ///
+    /// See [get_path](#method.get_path) about the path syntax and errors.
+    pub fn set_path(&mut self, path: &str, value: &dyn ToSql) -> Result<()> {
+        let (parent, last) = PathNode::Object(self.clone()).parent_of(path)?;
+        parent.set_child(&last, path, value)
+    }
+
+    /// Converts the object to a tree of [ObjectValue][]s recursively.
+    ///
+    /// [ObjectValue]: enum.ObjectValue.html
+    pub fn to_value_tree(&self) -> Result<ObjectValue> {
+        let mut attrs = Vec::with_capacity(self.objtype.num_attributes());
+        for attr in self.objtype.attributes() {
+            let value = self.with_attr(attr, ObjectValue::from_sql_value)?;
+            attrs.push((attr.name().to_string(), value));
+        }
+        Ok(ObjectValue::Object(self.objtype.to_string(), attrs))
+    }
+
+    /// Returns a copy of the object including its attribute values.
+    /// Unlike `clone()`, changes to the copy don't affect the original.
+    ///
+    /// ```no_run
+    /// # use oracle::*; use oracle::sql_type::*; fn try_main() -> Result<()> {
+    /// let conn = Connection::connect("scott", "tiger", "")?;
+    /// let objtype = conn.object_type("MDSYS.SDO_POINT_TYPE")?;
+    /// let mut point = objtype.new_object()?;
+    /// point.set("X", &1)?;
+    ///
+    /// let mut copy = point.deep_copy()?;
+    /// assert!(copy == point);
+    /// assert!(copy.eq_values(&point)?);
+    /// copy.set("X", &2)?;
+    /// assert_eq!(point.get::<i32>("X")?, 1);
+    /// assert!(copy != point);
+    /// assert!(!copy.eq_values(&point)?);
+    /// # Ok(())} fn main() { try_main().unwrap(); }
+    /// ```
+    pub fn deep_copy(&self) -> Result<Object> {
+        let mut handle = ptr::null_mut();
+        chkerr!(self.ctxt, dpiObject_copy(self.handle, &mut handle));
+        Ok(Object::new(self.ctxt, handle, self.objtype.clone()))
+    }
+
+    /// Returns `true` when the types and all attribute values of the objects
+    /// are equal. Attribute values are compared recursively.
+    ///
+    /// This returns an error when an attribute value cannot be read.
+    pub fn eq_values(&self, other: &Object) -> Result<bool> {
+        if self.handle == other.handle {
+            return Ok(true);
+        }
+        Ok(self.to_value_tree()? == other.to_value_tree()?)
+    }
 }
 
 impl Clone for Object {
@@ -446,6 +727,14 @@ impl Clone for Object {
     }
 }
 
+/// Compares attribute values by [eq_values()](#method.eq_values).
+/// Objects are not equal when an attribute value cannot be read.
+impl cmp::PartialEq for Object {
+    fn eq(&self, other: &Self) -> bool {
+        self.eq_values(other).unwrap_or(false)
+    }
+}
+
 impl Drop for Object {
     fn drop(&mut self) {
         let _ = unsafe { dpiObject_release(self.handle) };
@@ -500,6 +789,215 @@ impl fmt::Debug for Object {
     }
 }
 
+/// A value in a tree converted from an [Object][] or a [Collection][]
+/// by `to_value_tree()`
+///
+/// [Object]: struct.Object.html
+/// [Collection]: struct.Collection.html
+///
+/// ```no_run
+/// # use oracle::*; use oracle::sql_type::*; fn try_main() -> Result<()> {
+/// let conn = Connection::connect("scott", "tiger", "")?;
+/// let geom: Object = conn.query_row_as("select shape from cities where id = 1", &[])?;
+///
+/// if let ObjectValue::Object(type_name, attrs) = geom.to_value_tree()? {
+///     println!("{}", type_name);
+///     for (name, value) in attrs {
+///         println!("  {}: {:?}", name, value);
+///     }
+/// }
+/// # Ok(())} fn main() { try_main().unwrap(); }
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub enum ObjectValue {
+    /// Null value of any type
+    Null,
+    /// Value of number, float and integer types
+    Number(OracleNumber),
+    /// Value of binary_float and binary_double
+    Double(f64),
+    /// Value of character types, clob, nclob and rowid
+    String(String),
+    /// Value of raw and blob
+    Bytes(Vec<u8>),
+    /// Value of date and timestamp types
+    Timestamp(Timestamp),
+    /// Value of interval day to second
+    IntervalDS(IntervalDS),
+    /// Value of interval year to month
+    IntervalYM(IntervalYM),
+    /// Value of boolean
+    Boolean(bool),
+    /// Object with its type name and pairs of attribute names and values
+    Object(String, Vec<(String, ObjectValue)>),
+    /// Collection with its type name and element values
+    Collection(String, Vec<ObjectValue>),
+}
+
+impl ObjectValue {
+    fn from_sql_value(val: &SqlValue) -> Result<ObjectValue> {
+        if val.is_null()? {
+            return Ok(ObjectValue::Null);
+        }
+        Ok(match *val.oracle_type()? {
+            OracleType::Number(_, _)
+            | OracleType::Float(_)
+            | OracleType::Int64
+            | OracleType::UInt64 => ObjectValue::Number(val.get()?),
+            OracleType::BinaryFloat | OracleType::BinaryDouble => ObjectValue::Double(val.get()?),
+            OracleType::Raw(_) | OracleType::LongRaw | OracleType::BLOB => {
+                ObjectValue::Bytes(val.get()?)
+            }
+            OracleType::Date
+            | OracleType::Timestamp(_)
+            | OracleType::TimestampTZ(_)
+            | OracleType::TimestampLTZ(_) => ObjectValue::Timestamp(val.get()?),
+            OracleType::IntervalDS(_, _) => ObjectValue::IntervalDS(val.get()?),
+            OracleType::IntervalYM(_) => ObjectValue::IntervalYM(val.get()?),
+            OracleType::Boolean => ObjectValue::Boolean(val.get()?),
+            OracleType::Object(ref objtype) => {
+                if objtype.is_collection() {
+                    val.get::<Collection>()?.to_value_tree()?
+                } else {
+                    val.get::<Object>()?.to_value_tree()?
+                }
+            }
+            _ => ObjectValue::String(val.get()?),
+        })
+    }
+}
+
+// A segment of a path passed to Object.get_path and Object.set_path.
+// `end` is the end position of the segment in the path.
+enum PathSegment<'a> {
+    Attr(&'a str, usize),
+    Index(i32, usize),
+}
+
+fn parse_path(path: &str) -> Result<Vec<PathSegment<'_>>> {
+    let err = || Error::InvalidOperation(format!("invalid attribute path: {}", path));
+    let bytes = path.as_bytes();
+    let mut segments = Vec::new();
+    let mut pos = 0;
+    loop {
+        let start = pos;
+        while pos < bytes.len() && bytes[pos] != b'.' && bytes[pos] != b'[' && bytes[pos] != b']' {
+            pos += 1;
+        }
+        if pos == start {
+            return Err(err());
+        }
+        segments.push(PathSegment::Attr(&path[start..pos], pos));
+        while pos < bytes.len() && bytes[pos] == b'[' {
+            let start = pos + 1;
+            pos = start;
+            while pos < bytes.len() && bytes[pos].is_ascii_digit() {
+                pos += 1;
+            }
+            if pos == start || pos == bytes.len() || bytes[pos] != b']' {
+                return Err(err());
+            }
+            let index = path[start..pos].parse().map_err(|_| err())?;
+            pos += 1;
+            segments.push(PathSegment::Index(index, pos));
+        }
+        if pos == bytes.len() {
+            return Ok(segments);
+        }
+        if bytes[pos] != b'.' {
+            return Err(err());
+        }
+        pos += 1;
+    }
+}
+
+// An object or a collection in the middle of a path
+enum PathNode {
+    Object(Object),
+    Collection(Collection),
+}
+
+impl PathNode {
+    // Returns the parent node of the last segment and the last segment.
+    fn parent_of<'a>(self, path: &'a str) -> Result<(PathNode, PathSegment<'a>)> {
+        let mut segments = parse_path(path)?;
+        let last = segments.pop().unwrap();
+        let mut node = self;
+        for seg in &segments {
+            node = node.with_child(seg, path, |val| {
+                if let OracleType::Object(ref objtype) = *val.oracle_type()? {
+                    if objtype.is_collection() {
+                        return Ok(PathNode::Collection(val.get()?));
+                    } else {
+                        return Ok(PathNode::Object(val.get()?));
+                    }
+                }
+                Err(Error::InvalidOperation(format!(
+                    "{} is neither an object nor a collection but {}",
+                    &path[..seg.end()],
+                    val.oracle_type()?
+                )))
+            })?;
+        }
+        Ok((node, last))
+    }
+
+    fn with_child<F, R>(&self, seg: &PathSegment, path: &str, f: F) -> Result<R>
+    where
+        F: FnOnce(&SqlValue) -> Result<R>,
+    {
+        match (self, seg) {
+            (PathNode::Object(obj), PathSegment::Attr(name, end)) => {
+                obj.with_attr(obj.path_attr(name, &path[..*end])?, f)
+            }
+            (PathNode::Collection(coll), PathSegment::Index(index, end)) => {
+                coll.check_path_index(*index, &path[..*end])?;
+                coll.with_element(*index, f)
+            }
+            _ => Err(self.mismatch_error(seg, path)),
+        }
+    }
+
+    fn set_child(&self, seg: &PathSegment, path: &str, value: &dyn ToSql) -> Result<()> {
+        match (self, seg) {
+            (PathNode::Object(obj), PathSegment::Attr(name, end)) => {
+                obj.set_by_attr(obj.path_attr(name, &path[..*end])?, value)
+            }
+            (PathNode::Collection(coll), PathSegment::Index(index, end)) => {
+                coll.check_path_index(*index, &path[..*end])?;
+                coll.set_element(*index, value)
+            }
+            _ => Err(self.mismatch_error(seg, path)),
+        }
+    }
+
+    fn mismatch_error(&self, seg: &PathSegment, path: &str) -> Error {
+        let parent = match *seg {
+            PathSegment::Attr(name, end) => &path[..end - name.len()],
+            PathSegment::Index(_, end) => &path[..path[..end].rfind('[').unwrap()],
+        };
+        let parent = parent.trim_end_matches('.');
+        match *self {
+            PathNode::Object(_) => {
+                Error::InvalidOperation(format!("{} is an object, which cannot be indexed", parent))
+            }
+            PathNode::Collection(_) => Error::InvalidOperation(format!(
+                "{} is a collection, which doesn't have attributes",
+                parent
+            )),
+        }
+    }
+}
+
+impl<'a> PathSegment<'a> {
+    fn end(&self) -> usize {
+        match *self {
+            PathSegment::Attr(_, end) => end,
+            PathSegment::Index(_, end) => end,
+        }
+    }
+}
+
 /// Type information about Object or Collection data type
 ///
 /// This is for not only Object type information but also
@@ -546,6 +1044,31 @@ impl ObjectType {
         })
     }
 
+    // Creates an object type for `Connection.object_type(name)`.
+    // OCI doesn't return the package name of PL/SQL types. It is
+    // taken from `name`. Only `PACKAGE.TYPE` is confirmed by
+    // `ALL_PLSQL_TYPES` because it looks same as `SCHEMA.TYPE` when
+    // the type is reached through a synonym.
+    pub(crate) fn from_dpi_object_type_by_name(
+        conn: &Connection,
+        handle: DpiObjectType,
+        name: &str,
+    ) -> Result<ObjectType> {
+        let mut internal = ObjectTypeInternal::from_dpi_object_type(conn.ctxt, handle)?;
+        internal.package_name = match package_name_in(name, &internal.schema, &internal.name) {
+            Some((package_name, true)) => Some(package_name),
+            Some((package_name, false))
+                if is_plsql_type(conn, &internal.schema, &package_name, &internal.name)? =>
+            {
+                Some(package_name)
+            }
+            _ => None,
+        };
+        Ok(ObjectType {
+            internal: Arc::new(internal),
+        })
+    }
+
     pub(crate) fn handle(&self) -> &DpiObjectType {
         &self.internal.handle
     }
@@ -555,6 +1078,15 @@ impl ObjectType {
         &self.internal.schema
     }
 
+    /// Gets the package name if it is a PL/SQL type declared in a package
+    /// and it was got by [Connection.object_type][] with the package name
+    /// such as `PKG_NAME.TYPE_NAME`. Otherwise, `None`.
+    ///
+    /// [Connection.object_type]: ../struct.Connection.html#method.object_type
+    pub fn package_name(&self) -> Option<&str> {
+        self.internal.package_name.as_ref().map(String::as_str)
+    }
+
     /// Gets object name
     pub fn name(&self) -> &str {
         &self.internal.name
@@ -619,6 +1151,27 @@ impl ObjectType {
         Ok(Object::new(ctxt, handle, self.clone()))
     }
 
+    /// Create a new collection whose elements are `values`.
+    ///
+    /// ```no_run
+    /// # use oracle::*; use oracle::sql_type::*; fn try_main() -> Result<()> {
+    /// let conn = Connection::connect("scott", "tiger", "")?;
+    /// let objtype = conn.object_type("MDSYS.SDO_ELEM_INFO_ARRAY")?;
+    /// let coll = objtype.collection_from(&[1, 3])?;
+    /// assert_eq!(coll.to_string(), "MDSYS.SDO_ELEM_INFO_ARRAY(1, 3)");
+    /// # Ok(())} fn main() { try_main().unwrap(); }
+    /// ```
+    pub fn collection_from<T>(&self, values: &[T]) -> Result<Collection>
+    where
+        T: ToSql,
+    {
+        let mut coll = self.new_collection()?;
+        for value in values {
+            coll.push(value)?;
+        }
+        Ok(coll)
+    }
+
     /// Create a new collection.
     pub fn new_collection(&self) -> Result<Collection> {
         if !self.is_collection() {
@@ -636,6 +1189,32 @@ impl ObjectType {
         );
         Ok(Collection::new(ctxt, handle, self.clone()))
     }
+
+    /// Gets type information which OCI doesn't provide from the data
+    /// dictionary.
+    ///
+    /// This queries `ALL_TYPES` and `ALL_COLL_TYPES` for SQL types, and
+    /// `ALL_PLSQL_TYPES` and `ALL_PLSQL_COLL_TYPES`, which are available
+    /// in Oracle 12.1 or later, for PL/SQL types. The result isn't cached.
+    ///
+    /// ```no_run
+    /// # use oracle::*; use oracle::sql_type::*; fn try_main() -> Result<()> {
+    /// let conn = Connection::connect("scott", "tiger", "")?;
+    /// let objtype = conn.object_type("MDSYS.SDO_ELEM_INFO_ARRAY")?;
+    /// let details = objtype.details(&conn)?;
+    /// assert_eq!(details.kind(), ObjectTypeKind::Varray);
+    /// assert_eq!(details.max_size(), Some(1048576));
+    /// # Ok(())} fn main() { try_main().unwrap(); }
+    /// ```
+    pub fn details(&self, conn: &Connection) -> Result<ObjectTypeDetails> {
+        if self.package_name().is_none() {
+            match ObjectTypeDetails::load_sql_type(conn, self.schema(), self.name()) {
+                Err(Error::NoDataFound) => (),
+                res => return res,
+            }
+        }
+        ObjectTypeDetails::load_plsql_type(conn, self.schema(), self.package_name(), self.name())
+    }
 }
 
 impl cmp::PartialEq for ObjectType {
@@ -712,6 +1291,201 @@ impl fmt::Debug for ObjectTypeAttr {
     }
 }
 
+/// Kind of object types
+///
+/// See [ObjectTypeDetails.kind()](struct.ObjectTypeDetails.html#method.kind)
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum ObjectTypeKind {
+    /// Object type created by `CREATE TYPE ... AS OBJECT`
+    Object,
+    /// PL/SQL record type
+    Record,
+    /// Varray type
+    Varray,
+    /// Nested table type
+    NestedTable,
+    /// PL/SQL associative array type, also known as index-by table
+    AssociativeArray,
+}
+
+/// Type information loaded from the data dictionary
+///
+/// See [ObjectType.details()](struct.ObjectType.html#method.details)
+#[derive(Clone, Debug, PartialEq)]
+pub struct ObjectTypeDetails {
+    kind: ObjectTypeKind,
+    max_size: Option<u64>,
+    supertype: Option<String>,
+    subtypes: Vec<String>,
+    is_final: bool,
+    is_instantiable: bool,
+}
+
+impl ObjectTypeDetails {
+    fn load_sql_type(conn: &Connection, schema: &str, name: &str) -> Result<ObjectTypeDetails> {
+        let sql = "select t.typecode, c.coll_type, c.upper_bound, t.final, t.instantiable, \
+                   t.supertype_owner, t.supertype_name \
+                   from all_types t \
+                   left join all_coll_types c \
+                   on c.owner = t.owner and c.type_name = t.type_name \
+                   where t.owner = :1 and t.type_name = :2";
+        let (typecode, coll_type, max_size, is_final, is_instantiable, super_schema, super_name) =
+            conn.query_row_as::<(
+                String,
+                Option<String>,
+                Option<u64>,
+                String,
+                String,
+                Option<String>,
+                Option<String>,
+            )>(sql, &[&schema, &name])?;
+        let supertype = match (super_schema, super_name) {
+            (Some(super_schema), Some(super_name)) => {
+                Some(format!("{}.{}", super_schema, super_name))
+            }
+            _ => None,
+        };
+        let sql = "select owner || '.' || type_name from all_types \
+                   where supertype_owner = :1 and supertype_name = :2 \
+                   order by owner, type_name";
+        let subtypes = conn
+            .query_as::<String>(sql, &[&schema, &name])?
+            .collect::<Result<Vec<_>>>()?;
+        Ok(ObjectTypeDetails {
+            kind: object_type_kind(&typecode, coll_type.as_ref().map(String::as_str))?,
+            max_size,
+            supertype,
+            subtypes,
+            is_final: is_final == "YES",
+            is_instantiable: is_instantiable == "YES",
+        })
+    }
+
+    fn load_plsql_type(
+        conn: &Connection,
+        schema: &str,
+        package_name: Option<&str>,
+        name: &str,
+    ) -> Result<ObjectTypeDetails> {
+        let mut sql = "select t.typecode, c.coll_type, c.upper_bound \
+                       from all_plsql_types t \
+                       left join all_plsql_coll_types c \
+                       on c.owner = t.owner and c.package_name = t.package_name \
+                       and c.type_name = t.type_name \
+                       where t.owner = :1 and t.type_name = :2"
+            .to_string();
+        let mut params: Vec<&dyn ToSql> = vec![&schema, &name];
+        if let Some(ref package_name) = package_name {
+            sql.push_str(" and t.package_name = :3");
+            params.push(package_name);
+        }
+        let mut rows = conn
+            .query_as::<(String, Option<String>, Option<u64>)>(&sql, &params)?
+            .collect::<Result<Vec<_>>>()?;
+        if rows.len() > 1 {
+            return Err(Error::InvalidOperation(format!(
+                "{}.{} is declared in more than one package. Specify the package name.",
+                schema, name
+            )));
+        }
+        let (typecode, coll_type, max_size) = rows.pop().ok_or(Error::NoDataFound)?;
+        Ok(ObjectTypeDetails {
+            kind: object_type_kind(&typecode, coll_type.as_ref().map(String::as_str))?,
+            max_size,
+            supertype: None,
+            subtypes: Vec::new(),
+            is_final: true,
+            is_instantiable: true,
+        })
+    }
+
+    /// Gets the kind of the type
+    pub fn kind(&self) -> ObjectTypeKind {
+        self.kind
+    }
+
+    /// Gets the maximum number of elements if it is a varray.
+    /// Otherwise, `None`.
+    pub fn max_size(&self) -> Option<u64> {
+        self.max_size
+    }
+
+    /// Gets the supertype name such as `SCHEMA.TYPE_NAME` if it is a subtype.
+    /// Otherwise, `None`.
+    pub fn supertype(&self) -> Option<&str> {
+        self.supertype.as_ref().map(String::as_str)
+    }
+
+    /// Gets the names of direct subtypes such as `SCHEMA.TYPE_NAME`.
+    pub fn subtypes(&self) -> &[String] {
+        &self.subtypes
+    }
+
+    /// True when the type is declared as `FINAL`. PL/SQL types are always final.
+    pub fn is_final(&self) -> bool {
+        self.is_final
+    }
+
+    /// True when the type is declared as `INSTANTIABLE`. PL/SQL types are always
+    /// instantiable.
+    pub fn is_instantiable(&self) -> bool {
+        self.is_instantiable
+    }
+}
+
+// Maps TYPECODE and COLL_TYPE columns in the data dictionary to ObjectTypeKind.
+fn object_type_kind(typecode: &str, coll_type: Option<&str>) -> Result<ObjectTypeKind> {
+    match (typecode, coll_type) {
+        ("OBJECT", _) => Ok(ObjectTypeKind::Object),
+        ("PL/SQL RECORD", _) => Ok(ObjectTypeKind::Record),
+        ("COLLECTION", Some("VARYING ARRAY")) => Ok(ObjectTypeKind::Varray),
+        ("COLLECTION", Some("TABLE")) => Ok(ObjectTypeKind::NestedTable),
+        ("COLLECTION", Some("PL/SQL INDEX TABLE")) => Ok(ObjectTypeKind::AssociativeArray),
+        _ => Err(Error::InvalidOperation(format!(
+            "unsupported type code: {} {}",
+            typecode,
+            coll_type.unwrap_or("")
+        ))),
+    }
+}
+
+// Gets the package name from the name passed to Connection.object_type().
+// `name` is one of `TYPE`, `SCHEMA.TYPE`, `PACKAGE.TYPE` and
+// `SCHEMA.PACKAGE.TYPE`. Unquoted parts are compared in upper case.
+// The second element is true when `name` has three parts, which are
+// always a PL/SQL type in a package.
+fn package_name_in(name: &str, schema: &str, type_name: &str) -> Option<(String, bool)> {
+    let parts: Vec<String> = name
+        .split('.')
+        .map(|part| {
+            let part = part.trim();
+            if part.len() >= 2 && part.starts_with('"') && part.ends_with('"') {
+                part[1..part.len() - 1].to_string()
+            } else {
+                part.to_uppercase()
+            }
+        })
+        .collect();
+    match parts.len() {
+        2 if parts[0] != schema && parts[1] == type_name => Some((parts[0].clone(), false)),
+        3 => Some((parts[1].clone(), true)),
+        _ => None,
+    }
+}
+
+// Returns true when `PACKAGE.TYPE` is a PL/SQL type in `schema`.
+fn is_plsql_type(conn: &Connection, schema: &str, package_name: &str, name: &str) -> Result<bool> {
+    let sql = "select count(*) from all_plsql_types \
+               where owner = :1 and package_name = :2 and type_name = :3";
+    match conn.query_row_as::<u32>(sql, &[&schema, &package_name, &name]) {
+        Ok(count) => Ok(count > 0),
+        // ALL_PLSQL_TYPES doesn't exist before Oracle 12.1, where PL/SQL
+        // types aren't supported by OCI either.
+        Err(Error::OciError(ref err)) if err.code() == 942 => Ok(false),
+        Err(err) => Err(err),
+    }
+}
+
 //
 // ObjectTypeInternal
 //
@@ -719,8 +1493,9 @@ impl fmt::Debug for ObjectTypeAttr {
 pub(crate) struct ObjectTypeInternal {
     ctxt: &'static Context,
     handle: DpiObjectType,
-    schema: String,
-    name: String,
+    pub(crate) schema: String,
+    package_name: Option<String>,
+    pub(crate) name: String,
     elem_oratype: Option<OracleType>,
     attrs: Vec<ObjectTypeAttr>,
 }
@@ -768,6 +1543,7 @@ impl ObjectTypeInternal {
             ctxt: ctxt,
             handle: handle,
             schema: to_rust_str(info.schema, info.schemaLength),
+            package_name: None,
             name: to_rust_str(info.name, info.nameLength),
             elem_oratype: elem_oratype,
             attrs: attrs,
@@ -783,7 +1559,11 @@ impl cmp::PartialEq for ObjectTypeInternal {
 
 impl fmt::Display for ObjectTypeInternal {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        write!(f, "{}.{}", self.schema, self.name)
+        if let Some(ref package_name) = self.package_name {
+            write!(f, "{}.{}.{}", self.schema, package_name, self.name)
+        } else {
+            write!(f, "{}.{}", self.schema, self.name)
+        }
     }
 }
 
@@ -792,13 +1572,12 @@ impl fmt::Debug for ObjectTypeInternal {
         if self.elem_oratype.is_some() {
             write!(
                 f,
-                "ObjectType({}.{} collection of {})",
-                self.schema,
-                self.name,
+                "ObjectType({} collection of {})",
+                self,
                 self.elem_oratype.as_ref().unwrap()
             )
         } else {
-            write!(f, "ObjectType({}.{}(", self.schema, self.name)?;
+            write!(f, "ObjectType({}(", self)?;
             let mut first = true;
             for attr in &self.attrs {
                 if first {
@@ -812,3 +1591,67 @@ impl fmt::Debug for ObjectTypeInternal {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn package_name() {
+        assert_eq!(package_name_in("UDT_REC", "SCOTT", "UDT_REC"), None);
+        assert_eq!(package_name_in("scott.udt_rec", "SCOTT", "UDT_REC"), None);
+        assert_eq!(
+            package_name_in("pkg.udt_rec", "SCOTT", "UDT_REC"),
+            Some(("PKG".to_string(), false))
+        );
+        assert_eq!(
+            package_name_in("scott.\"Pkg\".udt_rec", "SCOTT", "UDT_REC"),
+            Some(("Pkg".to_string(), true))
+        );
+    }
+
+    fn parse(path: &str) -> Option<Vec<String>> {
+        parse_path(path).ok().map(|segments| {
+            segments
+                .iter()
+                .map(|seg| match *seg {
+                    PathSegment::Attr(name, end) => format!("{}@{}", name, end),
+                    PathSegment::Index(index, end) => format!("[{}]@{}", index, end),
+                })
+                .collect()
+        })
+    }
+
+    #[test]
+    fn path_syntax() {
+        assert_eq!(parse("CITY"), Some(vec!["CITY@4".to_string()]));
+        assert_eq!(
+            parse("LINES[2].ADDRESS.CITY"),
+            Some(vec![
+                "LINES@5".to_string(),
+                "[2]@8".to_string(),
+                "ADDRESS@16".to_string(),
+                "CITY@21".to_string(),
+            ])
+        );
+        assert_eq!(
+            parse("MATRIX[0][10]"),
+            Some(vec![
+                "MATRIX@6".to_string(),
+                "[0]@9".to_string(),
+                "[10]@13".to_string(),
+            ])
+        );
+        assert_eq!(parse(""), None);
+        assert_eq!(parse("A."), None);
+        assert_eq!(parse(".A"), None);
+        assert_eq!(parse("A..B"), None);
+        assert_eq!(parse("[0]"), None);
+        assert_eq!(parse("A[]"), None);
+        assert_eq!(parse("A[-1]"), None);
+        assert_eq!(parse("A[1"), None);
+        assert_eq!(parse("A[1]B"), None);
+        assert_eq!(parse("A]"), None);
+        assert_eq!(parse("A[99999999999]"), None);
+    }
+}
@@ -0,0 +1,137 @@
+// Rust-oracle - Rust binding for Oracle database
+//
+// URL: https://github.com/kubo/rust-oracle
+//
+//-----------------------------------------------------------------------------
+// Copyright (c) 2017-2018 Kubo Takehiro <kubo@jiubao.org>. All rights reserved.
+// This program is free software: you can modify it and/or redistribute it
+// under the terms of:
+//
+// (i)  the Universal Permissive License v 1.0 or at your option, any
+//      later version (http://oss.oracle.com/licenses/upl); and/or
+//
+// (ii) the Apache License v 2.0. (http://www.apache.org/licenses/LICENSE-2.0)
+//-----------------------------------------------------------------------------
+
+use std::fmt;
+use std::result;
+use std::str;
+
+use crate::sql_type::FromSql;
+use crate::sql_type::Object;
+use crate::sql_type::OracleType;
+use crate::sql_type::ToSql;
+use crate::sql_type::ToSqlNull;
+use crate::util::parse_str_into_raw;
+use crate::Connection;
+use crate::ParseOracleTypeError;
+use crate::Result;
+use crate::SqlValue;
+
+/// Oracle-specific [REF][] data type
+///
+/// This holds a reference to a row object in an object table.
+/// ODPI-C doesn't support REF values natively, so a REF is passed
+/// between Oracle and rust as a hexadecimal string. Fetch REF columns
+/// by `REFTOHEX()` and use `HEXTOREF()` to bind them as REF values.
+///
+/// ```no_run
+/// # use oracle::*; use oracle::sql_type::*; fn try_main() -> Result<()> {
+/// let conn = Connection::connect("scott", "tiger", "")?;
+///
+/// // The type of emp_ref is REF emp_t.
+/// let emp_ref: Ref = conn.query_row_as("select reftohex(emp_ref) from projects where id = 1", &[])?;
+///
+/// // Gets the object referenced by the REF.
+/// let emp = emp_ref.dereference(&conn)?;
+/// println!("{}", emp.get::<String>("NAME")?);
+///
+/// // Binds the REF.
+/// conn.execute("update projects set emp_ref = hextoref(:1) where id = 2", &[&emp_ref])?;
+/// # Ok(())} fn main() { try_main().unwrap(); }
+/// ```
+///
+/// [REF]: https://docs.oracle.com/en/database/oracle/oracle-database/12.2/adobj/basic-components-of-oracle-objects.html#GUID-AED0D2AC-B1C3-4BA4-8B7B-F0DD8EB3A1F3
+#[derive(Clone, PartialEq, Eq, Hash)]
+pub struct Ref {
+    hex: String,
+}
+
+impl Ref {
+    /// Returns the REF value in hexadecimal.
+    pub fn as_hex(&self) -> &str {
+        &self.hex
+    }
+
+    /// Gets the object referenced by the REF.
+    ///
+    /// This executes `select deref(hextoref(:1)) from dual`.
+    /// `Err(Error::NullValue)` is returned when the referenced object
+    /// doesn't exist.
+    pub fn dereference(&self, conn: &Connection) -> Result<Object> {
+        conn.query_row_as("select deref(hextoref(:1)) from dual", &[self])
+    }
+}
+
+impl fmt::Display for Ref {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.hex)
+    }
+}
+
+impl fmt::Debug for Ref {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "Ref({})", self.hex)
+    }
+}
+
+impl str::FromStr for Ref {
+    type Err = ParseOracleTypeError;
+
+    /// Parses the hexadecimal representation of a REF, which `REFTOHEX()` returns.
+    fn from_str(s: &str) -> result::Result<Self, Self::Err> {
+        if s.is_empty() || s.len() % 2 != 0 {
+            return Err(ParseOracleTypeError::new("Ref"));
+        }
+        parse_str_into_raw(s).map_err(|_| ParseOracleTypeError::new("Ref"))?;
+        Ok(Ref {
+            hex: s.to_uppercase(),
+        })
+    }
+}
+
+impl FromSql for Ref {
+    fn from_sql(val: &SqlValue) -> Result<Ref> {
+        Ok(val.to_string()?.parse()?)
+    }
+}
+
+impl ToSqlNull for Ref {
+    fn oratype_for_null(_conn: &Connection) -> Result<OracleType> {
+        Ok(OracleType::Varchar2(0))
+    }
+}
+
+impl ToSql for Ref {
+    fn oratype(&self, _conn: &Connection) -> Result<OracleType> {
+        Ok(OracleType::Varchar2(self.hex.len() as u32))
+    }
+    fn to_sql(&self, val: &mut SqlValue) -> Result<()> {
+        val.set_string(&self.hex)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse() {
+        let r: Ref = "0000280209a1b2c3".parse().unwrap();
+        assert_eq!(r.as_hex(), "0000280209A1B2C3");
+        assert_eq!(r.to_string(), "0000280209A1B2C3");
+        assert!("".parse::<Ref>().is_err());
+        assert!("ABC".parse::<Ref>().is_err());
+        assert!("0G".parse::<Ref>().is_err());
+    }
+}
@@ -0,0 +1,846 @@
+// Rust-oracle - Rust binding for Oracle database
+//
+// URL: https://github.com/kubo/rust-oracle
+//
+//-----------------------------------------------------------------------------
+// Copyright (c) 2017-2018 Kubo Takehiro <kubo@jiubao.org>. All rights reserved.
+// This program is free software: you can modify it and/or redistribute it
+// under the terms of:
+//
+// (i)  the Universal Permissive License v 1.0 or at your option, any
+//      later version (http://oss.oracle.com/licenses/upl); and/or
+//
+// (ii) the Apache License v 2.0. (http://www.apache.org/licenses/LICENSE-2.0)
+//-----------------------------------------------------------------------------
+
+use std::cmp;
+use std::fmt;
+use std::hash;
+use std::result;
+use std::str;
+
+use crate::Error;
+use crate::ParseOracleTypeError;
+use crate::Result;
+
+/// The maximum number of significant decimal digits in Oracle numbers
+const MAX_DIGITS: usize = 40;
+
+// Decimal exponents of the smallest and largest absolute values of Oracle numbers
+// when the values are represented as `0.d1d2d3... * 10^exponent`.
+const MIN_EXPONENT: i32 = -129; // 1.0e-130
+const MAX_EXPONENT: i32 = 126; // 9.99...e125
+
+// The maximum number of base-100 digits in the Oracle NUMBER format
+const MAX_MANTISSA_LEN: usize = 20;
+// The byte terminating the mantissa of negative numbers in the Oracle NUMBER format
+const NEGATIVE_TERMINATOR: u8 = 102;
+
+macro_rules! define_fn_to_int128 {
+    ($func_name:ident, $type:ident) => {
+        // Converts the number to an integer without going through a string
+        // so that an overflow is reported as `Error::OutOfRange`.
+        pub(crate) fn $func_name(&self) -> Result<$type> {
+            let overflow =
+                || Error::OutOfRange(format!("{} overflow: {}", stringify!($type), self));
+            if self.infinite {
+                return Err(overflow());
+            }
+            if self.scale() > 0 {
+                return Err(ParseOracleTypeError::new(stringify!($type)).into());
+            }
+            if self.negative && $type::MIN == 0 {
+                return Err(overflow());
+            }
+            let mut val: $type = 0;
+            for digit in self.digits() {
+                let digit = *digit as $type;
+                val = val
+                    .checked_mul(10)
+                    .and_then(|val| {
+                        if self.negative {
+                            val.checked_sub(digit)
+                        } else {
+                            val.checked_add(digit)
+                        }
+                    })
+                    .ok_or_else(overflow)?;
+            }
+            for _ in (self.ndigits as i32)..self.exponent {
+                val = val.checked_mul(10).ok_or_else(overflow)?;
+            }
+            Ok(val)
+        }
+    };
+}
+
+/// Oracle [NUMBER][] value without loss of precision
+///
+/// This holds a decimal number of up to 40 significant digits, which
+/// covers all values which Oracle NUMBER columns can store. Unlike `f64`,
+/// values are converted from and to strings digit by digit. No arithmetic
+/// is done.
+///
+/// It also holds positive and negative infinity, which may be found in
+/// the Oracle NUMBER format. They are displayed as `~` and `-~` in the same
+/// way as `TO_CHAR` in Oracle.
+///
+/// [NUMBER]: https://docs.oracle.com/en/database/oracle/oracle-database/19/sqlrf/Data-Types.html#GUID-9401BC04-81C4-4CD5-99E7-C5E25C83F608
+///
+/// # Examples
+///
+/// ```
+/// # use oracle::*; use oracle::sql_type::*; fn try_main() -> Result<()> {
+/// let num: OracleNumber = "12345678901234567890.123456789012345678".parse()?;
+/// assert_eq!(num.to_string(), "12345678901234567890.123456789012345678");
+/// assert_eq!(num.precision(), 38);
+/// assert_eq!(num.scale(), 18);
+///
+/// // Leading and trailing zeros aren't significant.
+/// let num: OracleNumber = "-000.0012300".parse()?;
+/// assert_eq!(num.to_string(), "-0.00123");
+///
+/// // Exponent notation is accepted.
+/// let num: OracleNumber = "1.5e3".parse()?;
+/// assert_eq!(num.to_string(), "1500");
+/// assert_eq!(num, OracleNumber::from(1500));
+/// # Ok(())} fn main() { try_main().unwrap(); }
+/// ```
+///
+/// Fetch and bind NUMBER values.
+///
+/// ```no_run
+/// # use oracle::*; use oracle::sql_type::*; fn try_main() -> Result<()> {
+/// let conn = Connection::connect("scott", "tiger", "")?;
+///
+/// let sql = "select 1/3 from dual";
+/// let num = conn.query_row_as::<OracleNumber>(sql, &[])?;
+/// assert_eq!(num.to_string(), "0.3333333333333333333333333333333333333333");
+///
+/// let mut stmt = conn.prepare("begin :1 := :2 * 2; end;", &[])?;
+/// stmt.execute(&[&None::<OracleNumber>, &num])?;
+/// let num: OracleNumber = stmt.bind_value(1)?;
+/// assert_eq!(num.to_string(), "0.6666666666666666666666666666666666666666");
+/// # Ok(())} fn main() { try_main().unwrap(); }
+/// ```
+#[derive(Clone, Copy)]
+pub struct OracleNumber {
+    negative: bool,
+    // true for positive and negative infinity, whose `ndigits` and `exponent` are zero.
+    infinite: bool,
+    ndigits: u8,
+    // significant digits (0-9) without leading and trailing zeros.
+    // Elements after `ndigits` are always zero.
+    digits: [u8; MAX_DIGITS],
+    // The value is `0.d1d2d3... * 10^exponent`.
+    exponent: i32,
+}
+
+impl OracleNumber {
+    /// Zero
+    pub fn zero() -> OracleNumber {
+        OracleNumber {
+            negative: false,
+            infinite: false,
+            ndigits: 0,
+            digits: [0; MAX_DIGITS],
+            exponent: 0,
+        }
+    }
+
+    /// Positive infinity
+    pub fn infinity() -> OracleNumber {
+        OracleNumber {
+            infinite: true,
+            ..OracleNumber::zero()
+        }
+    }
+
+    /// Negative infinity
+    pub fn neg_infinity() -> OracleNumber {
+        OracleNumber {
+            negative: true,
+            infinite: true,
+            ..OracleNumber::zero()
+        }
+    }
+
+    // Creates a number from decimal digits. The value is `0.d1d2d3... * 10^exponent`.
+    // Leading and trailing zeros in `digits` are removed.
+    fn from_digits(negative: bool, digits: &[u8], exponent: i32) -> Option<OracleNumber> {
+        let start = match digits.iter().position(|d| *d != 0) {
+            Some(pos) => pos,
+            None => return Some(OracleNumber::zero()),
+        };
+        let end = digits.iter().rposition(|d| *d != 0).unwrap() + 1;
+        let exponent = exponent - start as i32;
+        let digits = &digits[start..end];
+        if digits.len() > MAX_DIGITS || exponent < MIN_EXPONENT || MAX_EXPONENT < exponent {
+            return None;
+        }
+        let mut num = OracleNumber {
+            negative: negative,
+            infinite: false,
+            ndigits: digits.len() as u8,
+            digits: [0; MAX_DIGITS],
+            exponent: exponent,
+        };
+        num.digits[..digits.len()].copy_from_slice(digits);
+        Some(num)
+    }
+
+    // Significant decimal digits without leading and trailing zeros
+    fn digits(&self) -> &[u8] {
+        &self.digits[..self.ndigits as usize]
+    }
+
+    /// Returns true when the number is zero.
+    pub fn is_zero(&self) -> bool {
+        self.ndigits == 0 && !self.infinite
+    }
+
+    /// Returns true when the number is positive or negative infinity.
+    pub fn is_infinite(&self) -> bool {
+        self.infinite
+    }
+
+    /// Returns true when the number is less than zero.
+    pub fn is_negative(&self) -> bool {
+        self.negative
+    }
+
+    /// Returns the number of significant decimal digits.
+    /// It is zero when the number is zero or infinity.
+    pub fn precision(&self) -> u8 {
+        self.ndigits
+    }
+
+    /// Returns the number of decimal digits after the decimal point.
+    /// It is negative when the number is an integer with trailing zeros
+    /// such as `1200`, whose scale is `-2`. It is zero for infinity.
+    pub fn scale(&self) -> i32 {
+        self.ndigits as i32 - self.exponent
+    }
+
+    /// Creates a number from bytes in the Oracle NUMBER format.
+    ///
+    /// The format is that of values stored in tables, which is also
+    /// returned by `UTL_RAW.CAST_FROM_NUMBER` and `DUMP`. The first byte is
+    /// the exponent in base 100 and the rest is the mantissa in base 100.
+    /// Mantissa digits of negative numbers are terminated by 102 when they
+    /// are less than 20. Note that OCINumber, the 22-byte structure used by
+    /// OCI, is the length byte followed by this format.
+    ///
+    /// Positive and negative infinity are `[0xFF, 0x65]` and `[0x00]`
+    /// respectively. Note that ODPI-C reports an error when a NUMBER
+    /// column holding infinity is fetched, so they are found only in bytes
+    /// got by `UTL_RAW.CAST_FROM_NUMBER` and so on.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use oracle::*; use oracle::sql_type::*; fn try_main() -> Result<()> {
+    /// let num = OracleNumber::from_oracle_bytes(&[194, 2, 24])?;
+    /// assert_eq!(num.to_string(), "123");
+    /// let num = OracleNumber::from_oracle_bytes(&[62, 100, 51, 102])?;
+    /// assert_eq!(num.to_string(), "-1.5");
+    /// let num = OracleNumber::from_oracle_bytes(&[0xFF, 0x65])?;
+    /// assert_eq!(num, OracleNumber::infinity());
+    /// # Ok(())} fn main() { try_main().unwrap(); }
+    /// ```
+    pub fn from_oracle_bytes(bytes: &[u8]) -> Result<OracleNumber> {
+        let err = || Error::from(ParseOracleTypeError::new("Oracle number"));
+        let (exp_byte, mut mantissa) = match bytes.split_first() {
+            Some((exp_byte, mantissa)) => (*exp_byte, mantissa),
+            None => return Err(err()),
+        };
+        match (exp_byte, mantissa) {
+            (0x80, []) => return Ok(OracleNumber::zero()),
+            (0xFF, [101]) => return Ok(OracleNumber::infinity()),
+            (0x00, []) => return Ok(OracleNumber::neg_infinity()),
+            _ => (),
+        }
+        let negative = exp_byte < 0x80;
+        if negative {
+            if let Some((&NEGATIVE_TERMINATOR, rest)) = mantissa.split_last() {
+                mantissa = rest;
+            }
+        }
+        if mantissa.is_empty() || mantissa.len() > MAX_MANTISSA_LEN {
+            return Err(err());
+        }
+        // the exponent in base 100 when the value is `m1.m2m3... * 100^exponent`
+        let exponent = if negative {
+            62 - exp_byte as i32
+        } else {
+            exp_byte as i32 - 193
+        };
+        let mut digits = [0u8; MAX_MANTISSA_LEN * 2];
+        for (i, byte) in mantissa.iter().enumerate() {
+            let val = if negative {
+                101u8.wrapping_sub(*byte)
+            } else {
+                byte.wrapping_sub(1)
+            };
+            if val >= 100 {
+                return Err(err());
+            }
+            digits[i * 2] = val / 10;
+            digits[i * 2 + 1] = val % 10;
+        }
+        // The first and last base-100 digits are not zero in normalized numbers.
+        if digits[0..2] == [0, 0] || digits[mantissa.len() * 2 - 2..mantissa.len() * 2] == [0, 0] {
+            return Err(err());
+        }
+        OracleNumber::from_digits(negative, &digits[..mantissa.len() * 2], (exponent + 1) * 2)
+            .ok_or_else(err)
+    }
+
+    /// Converts the number to bytes in the Oracle NUMBER format.
+    /// See [from_oracle_bytes](#method.from_oracle_bytes) for the format.
+    ///
+    /// This returns `Error::OutOfRange` when more than 20 base-100 digits
+    /// are required, which happens only for some numbers with 40 significant
+    /// decimal digits. Oracle rounds such numbers.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use oracle::*; use oracle::sql_type::*; fn try_main() -> Result<()> {
+    /// let num: OracleNumber = "-123".parse()?;
+    /// assert_eq!(num.to_oracle_bytes()?, vec![61, 100, 78, 102]);
+    /// # Ok(())} fn main() { try_main().unwrap(); }
+    /// ```
+    pub fn to_oracle_bytes(&self) -> Result<Vec<u8>> {
+        if self.is_zero() {
+            return Ok(vec![0x80]);
+        }
+        if self.infinite {
+            return Ok(if self.negative {
+                vec![0x00]
+            } else {
+                vec![0xFF, 0x65]
+            });
+        }
+        // Align decimal digits to base-100 digits so that the value is
+        // `0.(d1d2)(d3d4)... * 100^(exponent / 2)`.
+        let mut digits = Vec::with_capacity(MAX_DIGITS + 2);
+        let mut exponent = self.exponent;
+        if exponent % 2 != 0 {
+            digits.push(0);
+            exponent += 1;
+        }
+        digits.extend_from_slice(self.digits());
+        if digits.len() % 2 != 0 {
+            digits.push(0);
+        }
+        let mantissa_len = digits.len() / 2;
+        if mantissa_len > MAX_MANTISSA_LEN {
+            return Err(Error::OutOfRange(format!(
+                "{} cannot be represented in {} base-100 digits",
+                self, MAX_MANTISSA_LEN
+            )));
+        }
+        // the exponent in base 100 when the value is `m1.m2m3... * 100^exponent`
+        let exponent = exponent / 2 - 1;
+        let mut bytes = Vec::with_capacity(mantissa_len + 2);
+        if self.negative {
+            bytes.push((62 - exponent) as u8);
+            bytes.extend(digits.chunks(2).map(|d| 101 - (d[0] * 10 + d[1])));
+            if mantissa_len < MAX_MANTISSA_LEN {
+                bytes.push(NEGATIVE_TERMINATOR);
+            }
+        } else {
+            bytes.push((193 + exponent) as u8);
+            bytes.extend(digits.chunks(2).map(|d| d[0] * 10 + d[1] + 1));
+        }
+        Ok(bytes)
+    }
+
+    define_fn_to_int128!(to_i128, i128);
+    define_fn_to_int128!(to_u128, u128);
+
+    fn cmp_abs(&self, other: &Self) -> cmp::Ordering {
+        match (self.infinite, other.infinite) {
+            (true, true) => return cmp::Ordering::Equal,
+            (true, false) => return cmp::Ordering::Greater,
+            (false, true) => return cmp::Ordering::Less,
+            (false, false) => (),
+        }
+        match (self.is_zero(), other.is_zero()) {
+            (true, true) => cmp::Ordering::Equal,
+            (true, false) => cmp::Ordering::Less,
+            (false, true) => cmp::Ordering::Greater,
+            (false, false) => self
+                .exponent
+                .cmp(&other.exponent)
+                .then_with(|| self.digits().cmp(other.digits())),
+        }
+    }
+}
+
+impl cmp::PartialEq for OracleNumber {
+    fn eq(&self, other: &Self) -> bool {
+        self.negative == other.negative
+            && self.infinite == other.infinite
+            && self.exponent == other.exponent
+            && self.digits() == other.digits()
+    }
+}
+
+impl cmp::Eq for OracleNumber {}
+
+impl hash::Hash for OracleNumber {
+    fn hash<H: hash::Hasher>(&self, state: &mut H) {
+        self.negative.hash(state);
+        self.infinite.hash(state);
+        self.exponent.hash(state);
+        self.digits().hash(state);
+    }
+}
+
+impl cmp::PartialOrd for OracleNumber {
+    fn partial_cmp(&self, other: &Self) -> Option<cmp::Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl cmp::Ord for OracleNumber {
+    fn cmp(&self, other: &Self) -> cmp::Ordering {
+        match (self.negative, other.negative) {
+            (false, false) => self.cmp_abs(other),
+            (true, true) => other.cmp_abs(self),
+            (false, true) => cmp::Ordering::Greater,
+            (true, false) => cmp::Ordering::Less,
+        }
+    }
+}
+
+impl fmt::Display for OracleNumber {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        if self.is_zero() {
+            return write!(f, "0");
+        }
+        if self.infinite {
+            return f.pad(if self.negative { "-~" } else { "~" });
+        }
+        let mut s = String::with_capacity(MAX_DIGITS + 3);
+        if self.negative {
+            s.push('-');
+        }
+        let digits = self.digits();
+        let to_char = |d: &u8| (b'0' + *d) as char;
+        if self.exponent <= 0 {
+            s.push_str("0.");
+            for _ in self.exponent..0 {
+                s.push('0');
+            }
+            s.extend(digits.iter().map(to_char));
+        } else if self.exponent as usize >= digits.len() {
+            s.extend(digits.iter().map(to_char));
+            for _ in digits.len()..(self.exponent as usize) {
+                s.push('0');
+            }
+        } else {
+            let (int_part, frac_part) = digits.split_at(self.exponent as usize);
+            s.extend(int_part.iter().map(to_char));
+            s.push('.');
+            s.extend(frac_part.iter().map(to_char));
+        }
+        f.pad(&s)
+    }
+}
+
+impl fmt::Debug for OracleNumber {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "OracleNumber({})", self)
+    }
+}
+
+impl str::FromStr for OracleNumber {
+    type Err = ParseOracleTypeError;
+
+    /// Parses a decimal number such as `-123.45` and `1.2345e-10`.
+    /// `~` and `-~` are parsed as positive and negative infinity.
+    fn from_str(s: &str) -> result::Result<Self, Self::Err> {
+        let err = || ParseOracleTypeError::new("Oracle number");
+        match s {
+            "~" => return Ok(OracleNumber::infinity()),
+            "-~" => return Ok(OracleNumber::neg_infinity()),
+            _ => (),
+        }
+        let bytes = s.as_bytes();
+        let mut pos = 0;
+        let negative = match bytes.first() {
+            Some(b'-') => {
+                pos += 1;
+                true
+            }
+            Some(b'+') => {
+                pos += 1;
+                false
+            }
+            _ => false,
+        };
+        let mut digits = Vec::with_capacity(bytes.len());
+        // integer part
+        while pos < bytes.len() && bytes[pos].is_ascii_digit() {
+            digits.push(bytes[pos] - b'0');
+            pos += 1;
+        }
+        let mut exponent = digits.len() as i32;
+        // fractional part
+        if pos < bytes.len() && bytes[pos] == b'.' {
+            pos += 1;
+            while pos < bytes.len() && bytes[pos].is_ascii_digit() {
+                digits.push(bytes[pos] - b'0');
+                pos += 1;
+            }
+        }
+        if digits.is_empty() {
+            return Err(err());
+        }
+        // exponent part
+        if pos < bytes.len() && (bytes[pos] == b'e' || bytes[pos] == b'E') {
+            pos += 1;
+            let exp_negative = match bytes.get(pos) {
+                Some(b'-') => {
+                    pos += 1;
+                    true
+                }
+                Some(b'+') => {
+                    pos += 1;
+                    false
+                }
+                _ => false,
+            };
+            let start = pos;
+            let mut exp = 0i32;
+            while pos < bytes.len() && bytes[pos].is_ascii_digit() {
+                exp = exp
+                    .checked_mul(10)
+                    .and_then(|exp| exp.checked_add((bytes[pos] - b'0') as i32))
+                    .ok_or_else(err)?;
+                pos += 1;
+            }
+            if start == pos {
+                return Err(err());
+            }
+            exponent = if exp_negative {
+                exponent.checked_sub(exp)
+            } else {
+                exponent.checked_add(exp)
+            }
+            .ok_or_else(err)?;
+        }
+        if pos != bytes.len() {
+            return Err(err());
+        }
+        let num = OracleNumber::from_digits(negative, &digits, exponent).ok_or_else(err)?;
+        if num.is_zero() {
+            // -0 is 0.
+            Ok(OracleNumber::zero())
+        } else {
+            Ok(num)
+        }
+    }
+}
+
+macro_rules! impl_from_int {
+    ($($type:ty),*) => {
+        $(
+            impl From<$type> for OracleNumber {
+                fn from(val: $type) -> OracleNumber {
+                    // Integers in rust never exceed the range of Oracle numbers.
+                    val.to_string().parse().unwrap()
+                }
+            }
+        )*
+    };
+}
+
+impl_from_int!(i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, u128, usize);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn num(s: &str) -> OracleNumber {
+        s.parse().unwrap()
+    }
+
+    #[test]
+    fn parse() {
+        let err = Err(ParseOracleTypeError::new("Oracle number"));
+        assert_eq!(num("0").to_string(), "0");
+        assert_eq!(num("-0.000").to_string(), "0");
+        assert_eq!(num("-0.000").is_negative(), false);
+        assert_eq!(num("123").to_string(), "123");
+        assert_eq!(num("+123").to_string(), "123");
+        assert_eq!(num("-123.450").to_string(), "-123.45");
+        assert_eq!(num(".5").to_string(), "0.5");
+        assert_eq!(num("5.").to_string(), "5");
+        assert_eq!(num("00012300").to_string(), "12300");
+        assert_eq!(num("0.000123").to_string(), "0.000123");
+        assert_eq!(num("1.5e3").to_string(), "1500");
+        assert_eq!(num("1.5E-3").to_string(), "0.0015");
+        assert_eq!(num("15e+0").to_string(), "15");
+        assert_eq!(
+            num("1234567890123456789012345678901234567890").to_string(),
+            "1234567890123456789012345678901234567890"
+        );
+        assert_eq!(
+            num("-0.1234567890123456789012345678901234567890").to_string(),
+            "-0.123456789012345678901234567890123456789"
+        );
+        assert_eq!(num("1e125").to_string(), format!("1{}", "0".repeat(125)));
+        assert_eq!(num("1e-130").to_string(), format!("0.{}1", "0".repeat(129)));
+        assert_eq!(
+            "12345678901234567890123456789012345678901".parse::<OracleNumber>(),
+            err
+        );
+        assert_eq!("1e126".parse::<OracleNumber>(), err);
+        assert_eq!("1e-131".parse::<OracleNumber>(), err);
+        assert_eq!("1e99999999999".parse::<OracleNumber>(), err);
+        assert_eq!("".parse::<OracleNumber>(), err);
+        assert_eq!("-".parse::<OracleNumber>(), err);
+        assert_eq!(".".parse::<OracleNumber>(), err);
+        assert_eq!("1e".parse::<OracleNumber>(), err);
+        assert_eq!("1e+".parse::<OracleNumber>(), err);
+        assert_eq!(" 1".parse::<OracleNumber>(), err);
+        assert_eq!("1 ".parse::<OracleNumber>(), err);
+        assert_eq!("1.2.3".parse::<OracleNumber>(), err);
+        assert_eq!("0x10".parse::<OracleNumber>(), err);
+        assert_eq!(num("~"), OracleNumber::infinity());
+        assert_eq!(num("-~"), OracleNumber::neg_infinity());
+        assert_eq!(OracleNumber::infinity().to_string(), "~");
+        assert_eq!(OracleNumber::neg_infinity().to_string(), "-~");
+        assert_eq!("+~".parse::<OracleNumber>(), err);
+        assert_eq!("~1".parse::<OracleNumber>(), err);
+    }
+
+    #[test]
+    fn precision_and_scale() {
+        assert_eq!(num("0").precision(), 0);
+        assert_eq!(num("123.45").precision(), 5);
+        assert_eq!(num("123.45").scale(), 2);
+        assert_eq!(num("1200").precision(), 2);
+        assert_eq!(num("1200").scale(), -2);
+        assert_eq!(num("0.0012").precision(), 2);
+        assert_eq!(num("0.0012").scale(), 4);
+    }
+
+    #[test]
+    fn to_int128() {
+        assert_eq!(num("0").to_i128().ok(), Some(0));
+        assert_eq!(num("-12300").to_i128().ok(), Some(-12300));
+        assert_eq!(num("1.23e3").to_i128().ok(), Some(1230));
+        assert_eq!(
+            num("170141183460469231731687303715884105727")
+                .to_i128()
+                .ok(),
+            Some(i128::MAX)
+        );
+        assert_eq!(
+            num("-170141183460469231731687303715884105728")
+                .to_i128()
+                .ok(),
+            Some(i128::MIN)
+        );
+        assert_eq!(
+            num("340282366920938463463374607431768211455")
+                .to_u128()
+                .ok(),
+            Some(u128::MAX)
+        );
+        match num("170141183460469231731687303715884105728").to_i128() {
+            Err(Error::OutOfRange(_)) => (),
+            result => panic!("Unexpected result: {:?}", result),
+        }
+        match num("-170141183460469231731687303715884105729").to_i128() {
+            Err(Error::OutOfRange(_)) => (),
+            result => panic!("Unexpected result: {:?}", result),
+        }
+        match num("1e40").to_u128() {
+            Err(Error::OutOfRange(_)) => (),
+            result => panic!("Unexpected result: {:?}", result),
+        }
+        match num("-1").to_u128() {
+            Err(Error::OutOfRange(_)) => (),
+            result => panic!("Unexpected result: {:?}", result),
+        }
+        match num("1.5").to_i128() {
+            Err(Error::ParseError(_)) => (),
+            result => panic!("Unexpected result: {:?}", result),
+        }
+        match OracleNumber::infinity().to_i128() {
+            Err(Error::OutOfRange(_)) => (),
+            result => panic!("Unexpected result: {:?}", result),
+        }
+        match OracleNumber::neg_infinity().to_i128() {
+            Err(Error::OutOfRange(_)) => (),
+            result => panic!("Unexpected result: {:?}", result),
+        }
+    }
+
+    // pairs of numbers and bytes returned by `utl_raw.cast_from_number`
+    const ORACLE_BYTES: &[(&str, &[u8])] = &[
+        ("0", &[128]),
+        ("1", &[193, 2]),
+        ("-1", &[62, 100, 102]),
+        ("100", &[194, 2]),
+        ("123", &[194, 2, 24]),
+        ("-123", &[61, 100, 78, 102]),
+        ("0.1", &[192, 11]),
+        ("0.01", &[192, 2]),
+        ("0.001", &[191, 11]),
+        ("1.5", &[193, 2, 51]),
+        ("-1.5", &[62, 100, 51, 102]),
+        ("12.5", &[193, 13, 51]),
+        ("1e125", &[255, 11]),
+        ("-1e125", &[0, 91, 102]),
+        ("1e-130", &[128, 2]),
+        ("-1e-130", &[127, 100, 102]),
+        (
+            "12345678901234567890123456789012345678",
+            &[
+                211, 13, 35, 57, 79, 91, 13, 35, 57, 79, 91, 13, 35, 57, 79, 91, 13, 35, 57, 79,
+            ],
+        ),
+        (
+            "-0.123456789012345678901234567890123456789",
+            &[
+                63, 89, 67, 45, 23, 11, 89, 67, 45, 23, 11, 89, 67, 45, 23, 11, 89, 67, 45, 23, 11,
+            ],
+        ),
+        (
+            "9999999999999999999999999999999999999999e86",
+            &[
+                255, 100, 100, 100, 100, 100, 100, 100, 100, 100, 100, 100, 100, 100, 100, 100,
+                100, 100, 100, 100, 100,
+            ],
+        ),
+        (
+            "-9999999999999999999999999999999999999999e86",
+            &[
+                0, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2,
+            ],
+        ),
+        ("~", &[0xFF, 0x65]),
+        ("-~", &[0x00]),
+    ];
+
+    #[test]
+    fn from_oracle_bytes() {
+        for &(s, bytes) in ORACLE_BYTES {
+            assert_eq!(
+                OracleNumber::from_oracle_bytes(bytes).unwrap(),
+                num(s),
+                "bytes: {:?}",
+                bytes
+            );
+        }
+        for bytes in &[
+            &[][..],
+            &[193],          // no mantissa
+            &[193, 0],       // invalid positive digit
+            &[193, 102],     // invalid positive digit
+            &[62, 1, 102],   // invalid negative digit
+            &[193, 1, 2],    // leading zero
+            &[193, 2, 1],    // trailing zero
+            &[62, 101, 102], // leading zero
+            &[
+                128, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2,
+            ], // too long
+        ] {
+            match OracleNumber::from_oracle_bytes(bytes) {
+                Err(Error::ParseError(_)) => (),
+                result => panic!("Unexpected result for {:?}: {:?}", bytes, result),
+            }
+        }
+        let num = OracleNumber::from_oracle_bytes(&[0xFF, 0x65]).unwrap();
+        assert!(num.is_infinite());
+        assert!(!num.is_negative());
+        let num = OracleNumber::from_oracle_bytes(&[0x00]).unwrap();
+        assert!(num.is_infinite());
+        assert!(num.is_negative());
+    }
+
+    #[test]
+    fn to_oracle_bytes() {
+        for &(s, bytes) in ORACLE_BYTES {
+            assert_eq!(num(s).to_oracle_bytes().unwrap(), bytes, "number: {}", s);
+        }
+        // 40 significant digits which need 21 base-100 digits
+        match num("1.234567890123456789012345678901234567891").to_oracle_bytes() {
+            Err(Error::OutOfRange(_)) => (),
+            result => panic!("Unexpected result: {:?}", result),
+        }
+    }
+
+    #[test]
+    fn oracle_bytes_round_trip() {
+        let mut prev: Option<(OracleNumber, Vec<u8>)> = None;
+        for i in -100_000..=100_000 {
+            for exp in &[-130, -3, 0, 3, 110] {
+                let n = num(&format!("{}e{}", i, exp));
+                let bytes = n.to_oracle_bytes().unwrap();
+                assert_eq!(OracleNumber::from_oracle_bytes(&bytes).unwrap(), n);
+            }
+            // The byte order is same with the numeric order.
+            let n = OracleNumber::from(i);
+            let bytes = n.to_oracle_bytes().unwrap();
+            if let Some((prev_n, prev_bytes)) = prev {
+                assert!(prev_n < n);
+                assert!(prev_bytes < bytes, "{} < {}", prev_n, n);
+            }
+            prev = Some((n, bytes));
+        }
+    }
+
+    #[test]
+    fn compare() {
+        let mut nums = vec![
+            num("1"),
+            num("-1"),
+            num("0"),
+            num("10"),
+            num("-0.5"),
+            num("0.5"),
+            num("-10"),
+            num("1.05"),
+            num("~"),
+            num("1.5"),
+            num("-~"),
+        ];
+        nums.sort();
+        let strs: Vec<_> = nums.iter().map(|n| n.to_string()).collect();
+        assert_eq!(
+            strs,
+            vec!["-~", "-10", "-1", "-0.5", "0", "0.5", "1", "1.05", "1.5", "10", "~"]
+        );
+        assert_eq!(num("1.50"), num("15e-1"));
+        assert_ne!(num("1.5"), num("-1.5"));
+    }
+
+    #[test]
+    fn from_int() {
+        assert_eq!(OracleNumber::from(0).to_string(), "0");
+        assert_eq!(
+            OracleNumber::from(i64::MIN).to_string(),
+            "-9223372036854775808"
+        );
+        assert_eq!(
+            OracleNumber::from(u64::MAX).to_string(),
+            "18446744073709551615"
+        );
+        assert_eq!(
+            OracleNumber::from(i128::MIN).to_string(),
+            "-170141183460469231731687303715884105728"
+        );
+        assert_eq!(
+            OracleNumber::from(u128::MAX).to_string(),
+            "340282366920938463463374607431768211455"
+        );
+    }
+}
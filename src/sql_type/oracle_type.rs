@@ -42,7 +42,6 @@ pub enum NativeType {
     Object(ObjectType),
     #[allow(dead_code)]
     Stmt,
-    #[allow(dead_code)]
     Boolean, // bool in rust
     Rowid,
 }
@@ -151,6 +150,13 @@ pub enum OracleType {
     /// Timestamp data type in local session time zone. Clients in different
     /// session time zones retrieves different timestamp.
     ///
+    /// Values are fetched as [Timestamp][]s in the session time zone, which
+    /// is changed by `ALTER SESSION SET TIME_ZONE`, with the offset of the
+    /// session time zone. Bind values with time zone offsets are converted
+    /// to the session time zone by Oracle.
+    ///
+    /// [Timestamp]: struct.Timestamp.html
+    ///
     /// `fsprec` is fractional seconds precision between 0 and 9. When it is
     /// 6, `(fsprec)` is omitted in text represention.
     TimestampLTZ(u8),
@@ -202,9 +208,62 @@ pub enum OracleType {
 
     /// Not an Oracle type, used only internally to bind/define values as u64
     UInt64,
+
+    /// JSON text in `IS JSON` columns
+    ///
+    /// Values of this type are bound as JSON text in CLOB. This isn't
+    /// the native JSON data type in Oracle 21c, which the bundled ODPI-C
+    /// doesn't support yet. Oracle converts bind values to native JSON
+    /// columns implicitly. Native JSON columns must be fetched by
+    /// `JSON_SERIALIZE(... RETURNING CLOB)`. See [JsonValue][].
+    ///
+    /// [JsonValue]: enum.JsonValue.html
+    Json,
+
+    /// XMLTYPE
+    ///
+    /// XMLTYPE columns are fetched as strings. The whole value is read at
+    /// once because streamed CLOB fetch isn't supported. Strings bound with
+    /// this type are sent as CLOB, which Oracle converts to XMLTYPE
+    /// implicitly in SQL statements. Use `XMLTYPE(:1)` in PL/SQL blocks.
+    ///
+    /// This is used only for select-list columns. Object attributes and
+    /// collection elements of XMLTYPE are `OracleType::Object`.
+    ///
+    /// ```no_run
+    /// # use oracle::*; use oracle::sql_type::*; fn try_main() -> Result<()> {
+    /// let conn = Connection::connect("scott", "tiger", "")?;
+    /// let payload = "<Envelope><Body>hello</Body></Envelope>";
+    /// conn.execute(
+    ///     "insert into soap_messages(id, payload) values (1, :1)",
+    ///     &[&(&payload, &OracleType::Xml)],
+    /// )?;
+    /// let payload: String =
+    ///     conn.query_row_as("select payload from soap_messages where id = 1", &[])?;
+    /// # Ok(())} fn main() { try_main().unwrap(); }
+    /// ```
+    Xml,
 }
 
 impl OracleType {
+    // Same with from_type_info except that XMLTYPE is mapped to Xml.
+    // Only select-list columns are mapped because they are fetched as
+    // strings. Object attributes and collection elements of XMLTYPE
+    // are kept as object types.
+    pub(crate) fn from_column_type_info(
+        ctxt: &'static Context,
+        info: &dpiDataTypeInfo,
+    ) -> Result<OracleType> {
+        match OracleType::from_type_info(ctxt, info)? {
+            OracleType::Object(ref objtype)
+                if objtype.schema() == "SYS" && objtype.name() == "XMLTYPE" =>
+            {
+                Ok(OracleType::Xml)
+            }
+            oratype => Ok(oratype),
+        }
+    }
+
     pub(crate) fn from_type_info(
         ctxt: &'static Context,
         info: &dpiDataTypeInfo,
@@ -299,8 +358,7 @@ impl OracleType {
             OracleType::BFILE => Ok((DPI_ORACLE_TYPE_BFILE, NativeType::BLOB, 0, 0)),
             //            OracleType::RefCursor =>
             //                Ok((DPI_ORACLE_TYPE_STMT, NativeType::Stmt, 0, 0)),
-            //            OracleType::Boolean =>
-            //                Ok((DPI_ORACLE_TYPE_BOOLEAN, NativeType::Boolean, 0, 0)),
+            OracleType::Boolean => Ok((DPI_ORACLE_TYPE_BOOLEAN, NativeType::Boolean, 0, 0)),
             OracleType::Object(ref objtype) => Ok((
                 DPI_ORACLE_TYPE_OBJECT,
                 NativeType::Object(objtype.clone()),
@@ -311,6 +369,8 @@ impl OracleType {
             OracleType::LongRaw => Ok((DPI_ORACLE_TYPE_LONG_RAW, NativeType::Raw, 0, 0)),
             OracleType::Int64 => Ok((DPI_ORACLE_TYPE_NATIVE_INT, NativeType::Int64, 0, 0)),
             OracleType::UInt64 => Ok((DPI_ORACLE_TYPE_NATIVE_UINT, NativeType::UInt64, 0, 0)),
+            OracleType::Json => Ok((DPI_ORACLE_TYPE_CLOB, NativeType::CLOB, 0, 0)),
+            OracleType::Xml => Ok((DPI_ORACLE_TYPE_CLOB, NativeType::CLOB, 0, 0)),
             _ => Err(Error::InternalError(format!(
                 "Unsupported Oracle type {}",
                 self
@@ -393,6 +453,8 @@ impl fmt::Display for OracleType {
             OracleType::LongRaw => write!(f, "LONG RAW"),
             OracleType::Int64 => write!(f, "INT64 used internally"),
             OracleType::UInt64 => write!(f, "UINT64 used internally"),
+            OracleType::Json => write!(f, "JSON"),
+            OracleType::Xml => write!(f, "XMLTYPE"),
         }
     }
 }
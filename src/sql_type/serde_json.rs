@@ -0,0 +1,149 @@
+// Rust-oracle - Rust binding for Oracle database
+//
+// URL: https://github.com/kubo/rust-oracle
+//
+//-----------------------------------------------------------------------------
+// Copyright (c) 2017-2018 Kubo Takehiro <kubo@jiubao.org>. All rights reserved.
+// This program is free software: you can modify it and/or redistribute it
+// under the terms of:
+//
+// (i)  the Universal Permissive License v 1.0 or at your option, any
+//      later version (http://oss.oracle.com/licenses/upl); and/or
+//
+// (ii) the Apache License v 2.0. (http://www.apache.org/licenses/LICENSE-2.0)
+//-----------------------------------------------------------------------------
+
+use crate::sql_type::FromSql;
+use crate::sql_type::JsonValue;
+use crate::sql_type::OracleType;
+use crate::sql_type::ToSql;
+use crate::sql_type::ToSqlNull;
+use crate::Connection;
+use crate::Error;
+use crate::Result;
+use crate::SqlValue;
+use serde_json::Map;
+use serde_json::Number;
+use serde_json::Value;
+use try_from::TryFrom;
+
+//
+// JsonValue <-> serde_json::Value
+//
+
+impl From<JsonValue> for Value {
+    /// Converts `JsonValue` to `serde_json::Value`.
+    ///
+    /// Numbers which `serde_json` holds as `f64` may lose precision.
+    /// Infinite numbers are converted to `Value::Null` in the same way
+    /// as `serde_json` converts infinite `f64` values.
+    /// Timestamps and binary data are converted to strings in the same
+    /// way as JSON text sent to Oracle.
+    fn from(val: JsonValue) -> Value {
+        match val {
+            JsonValue::Null => Value::Null,
+            JsonValue::Bool(val) => Value::Bool(val),
+            JsonValue::Number(ref val) if val.is_infinite() => Value::Null,
+            JsonValue::Number(val) => Value::Number(
+                serde_json::from_str::<Number>(&val.to_string())
+                    .expect("OracleNumber is formatted as a valid JSON number"),
+            ),
+            JsonValue::String(val) => Value::String(val),
+            val @ JsonValue::Timestamp(_) | val @ JsonValue::Binary(_) => {
+                serde_json::from_str(&val.to_string())
+                    .expect("timestamps and binary data are formatted as JSON strings")
+            }
+            JsonValue::Array(elems) => Value::Array(elems.into_iter().map(Value::from).collect()),
+            JsonValue::Object(fields) => Value::Object(
+                fields
+                    .into_iter()
+                    .map(|(name, val)| (name, Value::from(val)))
+                    .collect::<Map<String, Value>>(),
+            ),
+        }
+    }
+}
+
+impl TryFrom<Value> for JsonValue {
+    type Err = Error;
+
+    /// Converts `serde_json::Value` to `JsonValue`.
+    ///
+    /// `Err(Error::OutOfRange(...))` is returned when a number
+    /// cannot be represented by [OracleNumber](struct.OracleNumber.html).
+    fn try_from(val: Value) -> Result<JsonValue> {
+        Ok(match val {
+            Value::Null => JsonValue::Null,
+            Value::Bool(val) => JsonValue::Bool(val),
+            Value::Number(val) => JsonValue::Number(val.to_string().parse().map_err(|_| {
+                Error::OutOfRange(format!("{} cannot be converted to OracleNumber", val))
+            })?),
+            Value::String(val) => JsonValue::String(val),
+            Value::Array(elems) => JsonValue::Array(
+                elems
+                    .into_iter()
+                    .map(JsonValue::try_from)
+                    .collect::<Result<Vec<_>>>()?,
+            ),
+            Value::Object(fields) => JsonValue::Object(
+                fields
+                    .into_iter()
+                    .map(|(name, val)| Ok((name, JsonValue::try_from(val)?)))
+                    .collect::<Result<Vec<_>>>()?,
+            ),
+        })
+    }
+}
+
+//
+// serde_json::Value
+//
+
+impl FromSql for Value {
+    fn from_sql(val: &SqlValue) -> Result<Value> {
+        Ok(JsonValue::from_sql(val)?.into())
+    }
+}
+
+impl ToSqlNull for Value {
+    fn oratype_for_null(_conn: &Connection) -> Result<OracleType> {
+        Ok(OracleType::Json)
+    }
+}
+
+impl ToSql for Value {
+    fn oratype(&self, _conn: &Connection) -> Result<OracleType> {
+        Ok(OracleType::Json)
+    }
+    fn to_sql(&self, val: &mut SqlValue) -> Result<()> {
+        val.set_string(&self.to_string())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::sql_type::OracleNumber;
+    use crate::sql_type::Timestamp;
+
+    #[test]
+    fn conversion() {
+        let text = r#"{"a":[1,-2.5,"x"],"b":{"c":null,"d":true}}"#;
+        let val: JsonValue = text.parse().unwrap();
+        let json = Value::from(val.clone());
+        assert_eq!(json, serde_json::from_str::<Value>(text).unwrap());
+        assert_eq!(JsonValue::try_from(json).unwrap(), val);
+
+        let val = JsonValue::Array(vec![
+            JsonValue::Timestamp(Timestamp::new(2020, 1, 2, 3, 4, 5, 0)),
+            JsonValue::Binary(vec![0x01, 0xab]),
+        ]);
+        assert_eq!(
+            Value::from(val),
+            serde_json::json!(["2020-01-02T03:04:05", "01AB"])
+        );
+
+        let val = JsonValue::Number(OracleNumber::infinity());
+        assert_eq!(Value::from(val), Value::Null);
+    }
+}
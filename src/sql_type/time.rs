@@ -0,0 +1,253 @@
+// Rust-oracle - Rust binding for Oracle database
+//
+// URL: https://github.com/kubo/rust-oracle
+//
+//-----------------------------------------------------------------------------
+// Copyright (c) 2017-2018 Kubo Takehiro <kubo@jiubao.org>. All rights reserved.
+// This program is free software: you can modify it and/or redistribute it
+// under the terms of:
+//
+// (i)  the Universal Permissive License v 1.0 or at your option, any
+//      later version (http://oss.oracle.com/licenses/upl); and/or
+//
+// (ii) the Apache License v 2.0. (http://www.apache.org/licenses/LICENSE-2.0)
+//-----------------------------------------------------------------------------
+
+use time::Date;
+use time::Duration;
+use time::Month;
+use time::OffsetDateTime;
+use time::PrimitiveDateTime;
+use time::Time;
+use time::UtcOffset;
+
+use crate::sql_type::FromSql;
+use crate::sql_type::IntervalDS;
+use crate::sql_type::OracleType;
+use crate::sql_type::Timestamp;
+use crate::sql_type::ToSql;
+use crate::sql_type::ToSqlNull;
+use crate::Connection;
+use crate::Error;
+use crate::Result;
+use crate::SqlValue;
+
+fn out_of_range(err: time::error::ComponentRange) -> Error {
+    Error::OutOfRange(err.to_string())
+}
+
+fn date_from_timestamp(ts: &Timestamp) -> Result<Date> {
+    let month = match ts.month() {
+        1 => Month::January,
+        2 => Month::February,
+        3 => Month::March,
+        4 => Month::April,
+        5 => Month::May,
+        6 => Month::June,
+        7 => Month::July,
+        8 => Month::August,
+        9 => Month::September,
+        10 => Month::October,
+        11 => Month::November,
+        12 => Month::December,
+        month => return Err(Error::OutOfRange(format!("invalid month: {}", month))),
+    };
+    Date::from_calendar_date(ts.year(), month, ts.day() as u8).map_err(out_of_range)
+}
+
+fn time_from_timestamp(ts: &Timestamp) -> Result<Time> {
+    Time::from_hms_nano(
+        ts.hour() as u8,
+        ts.minute() as u8,
+        ts.second() as u8,
+        ts.nanosecond(),
+    )
+    .map_err(out_of_range)
+}
+
+fn timestamp_from_date_time(date: &Date, time: &Time) -> Timestamp {
+    Timestamp::new(
+        date.year(),
+        u8::from(date.month()) as u32,
+        date.day() as u32,
+        time.hour() as u32,
+        time.minute() as u32,
+        time.second() as u32,
+        time.nanosecond(),
+    )
+}
+
+//
+// time::OffsetDateTime
+//
+
+impl FromSql for OffsetDateTime {
+    fn from_sql(val: &SqlValue) -> Result<OffsetDateTime> {
+        let ts = val.to_timestamp()?;
+        let offset = UtcOffset::from_whole_seconds(ts.tz_offset()).map_err(out_of_range)?;
+        Ok(
+            PrimitiveDateTime::new(date_from_timestamp(&ts)?, time_from_timestamp(&ts)?)
+                .assume_offset(offset),
+        )
+    }
+}
+
+impl ToSqlNull for OffsetDateTime {
+    fn oratype_for_null(_conn: &Connection) -> Result<OracleType> {
+        Ok(OracleType::TimestampTZ(9))
+    }
+}
+
+impl ToSql for OffsetDateTime {
+    fn oratype(&self, _conn: &Connection) -> Result<OracleType> {
+        Ok(OracleType::TimestampTZ(9))
+    }
+
+    fn to_sql(&self, val: &mut SqlValue) -> Result<()> {
+        let ts = timestamp_from_date_time(&self.date(), &self.time());
+        let ts = ts.and_tz_offset(self.offset().whole_seconds());
+        val.set_timestamp(&ts)
+    }
+}
+
+//
+// time::PrimitiveDateTime
+//
+
+impl FromSql for PrimitiveDateTime {
+    fn from_sql(val: &SqlValue) -> Result<PrimitiveDateTime> {
+        let ts = val.to_timestamp()?;
+        Ok(PrimitiveDateTime::new(
+            date_from_timestamp(&ts)?,
+            time_from_timestamp(&ts)?,
+        ))
+    }
+}
+
+impl ToSqlNull for PrimitiveDateTime {
+    fn oratype_for_null(_conn: &Connection) -> Result<OracleType> {
+        Ok(OracleType::Timestamp(9))
+    }
+}
+
+impl ToSql for PrimitiveDateTime {
+    fn oratype(&self, _conn: &Connection) -> Result<OracleType> {
+        Ok(OracleType::Timestamp(9))
+    }
+
+    fn to_sql(&self, val: &mut SqlValue) -> Result<()> {
+        let ts = timestamp_from_date_time(&self.date(), &self.time());
+        val.set_timestamp(&ts)
+    }
+}
+
+//
+// time::Date
+//
+
+impl FromSql for Date {
+    fn from_sql(val: &SqlValue) -> Result<Date> {
+        let ts = val.to_timestamp()?;
+        date_from_timestamp(&ts)
+    }
+}
+
+impl ToSqlNull for Date {
+    fn oratype_for_null(_conn: &Connection) -> Result<OracleType> {
+        Ok(OracleType::Timestamp(0))
+    }
+}
+
+impl ToSql for Date {
+    fn oratype(&self, _conn: &Connection) -> Result<OracleType> {
+        Ok(OracleType::Timestamp(0))
+    }
+
+    fn to_sql(&self, val: &mut SqlValue) -> Result<()> {
+        let ts = timestamp_from_date_time(self, &Time::MIDNIGHT);
+        val.set_timestamp(&ts)
+    }
+}
+
+//
+// time::Time
+//
+
+impl FromSql for Time {
+    fn from_sql(val: &SqlValue) -> Result<Time> {
+        if let OracleType::IntervalDS(_, _) = *val.oracle_type()? {
+            let it = val.to_interval_ds()?;
+            let nanos = it.time_of_day_nanoseconds()?;
+            let secs = nanos / 1_000_000_000;
+            Time::from_hms_nano(
+                (secs / 3600) as u8,
+                (secs / 60 % 60) as u8,
+                (secs % 60) as u8,
+                (nanos % 1_000_000_000) as u32,
+            )
+            .map_err(out_of_range)
+        } else {
+            let ts = val.to_timestamp()?;
+            time_from_timestamp(&ts)
+        }
+    }
+}
+
+impl ToSqlNull for Time {
+    fn oratype_for_null(_conn: &Connection) -> Result<OracleType> {
+        Ok(OracleType::IntervalDS(9, 9))
+    }
+}
+
+impl ToSql for Time {
+    fn oratype(&self, _conn: &Connection) -> Result<OracleType> {
+        Ok(OracleType::IntervalDS(9, 9))
+    }
+
+    fn to_sql(&self, val: &mut SqlValue) -> Result<()> {
+        let it = IntervalDS::new(
+            0,
+            self.hour() as i32,
+            self.minute() as i32,
+            self.second() as i32,
+            self.nanosecond() as i32,
+        );
+        val.set_interval_ds(&it)
+    }
+}
+
+//
+// time::Duration
+//
+
+impl FromSql for Duration {
+    fn from_sql(val: &SqlValue) -> Result<Duration> {
+        let it = val.to_interval_ds()?;
+        let nanos = it.total_nanoseconds();
+        Ok(Duration::new(
+            (nanos / 1_000_000_000) as i64,
+            (nanos % 1_000_000_000) as i32,
+        ))
+    }
+}
+
+impl ToSqlNull for Duration {
+    fn oratype_for_null(_conn: &Connection) -> Result<OracleType> {
+        Ok(OracleType::IntervalDS(9, 9))
+    }
+}
+
+impl ToSql for Duration {
+    fn oratype(&self, _conn: &Connection) -> Result<OracleType> {
+        Ok(OracleType::IntervalDS(9, 9))
+    }
+
+    fn to_sql(&self, val: &mut SqlValue) -> Result<()> {
+        let nanos =
+            self.whole_seconds() as i128 * 1_000_000_000 + self.subsec_nanoseconds() as i128;
+        match IntervalDS::from_total_nanoseconds(nanos) {
+            Some(it) => val.set_interval_ds(&it),
+            None => Err(Error::OutOfRange(format!("too large days: {}", self))),
+        }
+    }
+}
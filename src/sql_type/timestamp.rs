@@ -15,22 +15,243 @@
 
 use std::cmp;
 use std::fmt;
+use std::ops;
+use std::result;
 use std::str;
 
 use crate::binding::dpiTimestamp;
+use crate::sql_type::format_model;
+use crate::sql_type::IntervalDS;
+use crate::sql_type::IntervalYM;
 use crate::sql_type::OracleType;
 use crate::util::Scanner;
+use crate::Error;
 use crate::ParseOracleTypeError;
+use crate::Result;
+
+//
+// Calendar used by Oracle
+//
+// Dates before 1582-10-15 are in the Julian calendar and others are in
+// the Gregorian calendar. Leap years before Christ are 1 BC, 5 BC, 9 BC
+// and so on, which are represented as -1, -5, -9. Oracle counts days
+// as if there is a non-leap year 0 between 1 BC and 1 AD.
+//
+// Days are counted as Julian day numbers used by the 'J' format element,
+// which are days since January 1, 4712 BC plus one.
+//
+
+// Julian day number of 1582-10-15, the first day of the Gregorian calendar
+const FIRST_GREGORIAN_DAY: i64 = 2299161;
+// Julian day number of 0001-01-01
+const FIRST_AD_DAY: i64 = 1721424;
+// Julian day number of -4712-01-01
+pub(crate) const MIN_JULIAN_DAY: i64 = 1;
+// Julian day number of 9999-12-31
+pub(crate) const MAX_JULIAN_DAY: i64 = 5373484;
+
+const NANOS_PER_SEC: i128 = 1_000_000_000;
+const NANOS_PER_DAY: i128 = 86400 * NANOS_PER_SEC;
+
+// The longest name in the time zone database is
+// America/Argentina/ComodRivadavia.
+const MAX_TZ_REGION_LEN: usize = 32;
+
+// Time zone region name stored inline to keep `Timestamp` `Copy`.
+#[derive(Clone, Copy)]
+struct TzRegion {
+    len: u8,
+    buf: [u8; MAX_TZ_REGION_LEN],
+}
+
+impl TzRegion {
+    fn new(name: &str) -> Result<TzRegion> {
+        if !is_tz_region_name(name) {
+            return Err(Error::OutOfRange(format!(
+                "invalid time zone region name: {}",
+                name
+            )));
+        }
+        let mut buf = [0; MAX_TZ_REGION_LEN];
+        buf[..name.len()].copy_from_slice(name.as_bytes());
+        Ok(TzRegion {
+            len: name.len() as u8,
+            buf,
+        })
+    }
+
+    fn as_str(&self) -> &str {
+        str::from_utf8(&self.buf[..self.len as usize]).expect("region names are ASCII")
+    }
+}
+
+impl fmt::Debug for TzRegion {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        fmt::Debug::fmt(self.as_str(), f)
+    }
+}
+
+// Region names start with a letter such as Europe/Madrid and Etc/GMT+5.
+fn is_tz_region_name(name: &str) -> bool {
+    name.len() <= MAX_TZ_REGION_LEN
+        && name.starts_with(|c: char| c.is_ascii_alphabetic())
+        && name.chars().all(is_tz_region_char)
+}
+
+pub(crate) fn is_tz_region_char(c: char) -> bool {
+    c.is_ascii_alphanumeric() || c == '/' || c == '_' || c == '-' || c == '+'
+}
+
+fn is_leap_year(year: i32) -> bool {
+    if year > 1582 {
+        (year % 4 == 0 && year % 100 != 0) || year % 400 == 0
+    } else if year > 0 {
+        year % 4 == 0
+    } else if year == 0 {
+        false
+    } else {
+        (year + 1) % 4 == 0
+    }
+}
+
+pub(crate) fn days_in_month(year: i32, month: u32) -> u32 {
+    match month {
+        1 | 3 | 5 | 7 | 8 | 10 | 12 => 31,
+        4 | 6 | 9 | 11 => 30,
+        _ => {
+            if is_leap_year(year) {
+                29
+            } else {
+                28
+            }
+        }
+    }
+}
+
+// Division rounding towards negative infinity and the remainder, which is
+// never negative.
+fn div_floor(a: i128, b: i128) -> (i128, i128) {
+    let (q, r) = (a / b, a % b);
+    if r < 0 {
+        (q - 1, r + b)
+    } else {
+        (q, r)
+    }
+}
+
+// Julian day number of a date in the proleptic Gregorian calendar or
+// the proleptic Julian calendar. The year is an astronomical year number.
+fn civil_to_jdn(year: i64, month: u32, day: u32, gregorian: bool) -> i64 {
+    let a = (14 - month as i64) / 12;
+    let y = year + 4800 - a;
+    let m = month as i64 + 12 * a - 3;
+    let jdn = day as i64 + (153 * m + 2) / 5 + 365 * y + y / 4;
+    if gregorian {
+        jdn - y / 100 + y / 400 - 32045
+    } else {
+        jdn - 32083
+    }
+}
+
+// Inverse of civil_to_jdn
+fn jdn_to_civil(jdn: i64, gregorian: bool) -> (i64, u32, u32) {
+    let (b, c) = if gregorian {
+        let a = jdn + 32044;
+        let b = (4 * a + 3) / 146097;
+        (b, a - 146097 * b / 4)
+    } else {
+        (0, jdn + 32082)
+    };
+    let d = (4 * c + 3) / 1461;
+    let e = c - 1461 * d / 4;
+    let m = (5 * e + 2) / 153;
+    let day = e - (153 * m + 2) / 5 + 1;
+    let month = m + 3 - 12 * (m / 10);
+    let year = 100 * b + d - 4800 + m / 10;
+    (year, month as u32, day as u32)
+}
+
+// Julian day number of a date in the Oracle calendar
+pub(crate) fn date_to_julian_day(year: i32, month: u32, day: u32) -> i64 {
+    if (year, month, day) >= (1582, 10, 15) {
+        civil_to_jdn(year as i64, month, day, true)
+    } else if year > 0 {
+        civil_to_jdn(year as i64, month, day, false)
+    } else if year == 0 {
+        // year 0 has same days with year 1.
+        civil_to_jdn(1, month, day, false) - 365
+    } else {
+        // Years before Christ are shifted by the non-leap year 0.
+        civil_to_jdn(year as i64 + 1, month, day, false) - 365
+    }
+}
+
+// Inverse of date_to_julian_day
+pub(crate) fn julian_day_to_date(jdn: i64) -> (i32, u32, u32) {
+    if jdn >= FIRST_GREGORIAN_DAY {
+        let (year, month, day) = jdn_to_civil(jdn, true);
+        (year as i32, month, day)
+    } else if jdn >= FIRST_AD_DAY {
+        let (year, month, day) = jdn_to_civil(jdn, false);
+        (year as i32, month, day)
+    } else {
+        let (year, month, day) = jdn_to_civil(jdn + 365, false);
+        if year == 1 {
+            (0, month, day)
+        } else {
+            (year as i32 - 1, month, day)
+        }
+    }
+}
+
+// Checks whether the date exists in the Oracle calendar.
+pub(crate) fn check_date(year: i32, month: u32, day: u32) -> Result<()> {
+    if year < -4712 || 9999 < year || year == 0 {
+        return Err(Error::OutOfRange(
+            "year must be between -4712 and +9999, and not be 0".to_string(),
+        ));
+    }
+    if month < 1 || 12 < month {
+        return Err(Error::OutOfRange("not a valid month".to_string()));
+    }
+    if day < 1 || days_in_month(year, month) < day {
+        return Err(Error::OutOfRange(
+            "day of month must be between 1 and last day of month".to_string(),
+        ));
+    }
+    if year == 1582 && month == 10 && 5 <= day && day <= 14 {
+        return Err(Error::OutOfRange(format!(
+            "1582-10-{:02} doesn't exist in the calendar",
+            day
+        )));
+    }
+    Ok(())
+}
 
 /// Oracle-specific [Datetime][] data type
 ///
 /// [Datetime]: https://docs.oracle.com/database/122/NLSPG/datetime-data-types-and-time-zone-support.htm#NLSPG-GUID-3A1B7AC6-2EDB-4DDC-9C9D-223D4C72AC74
 ///
-/// This struct doesn't have arithmetic methods and they won't be added to avoid
-/// reinventing the wheel. If you need methods such as adding an interval to a
-/// timestamp, enable `chrono` feature and use [chrono::Date][], [chrono::DateTime][],
+/// This struct has basic arithmetic and comparison as Oracle does.
+/// Intervals are added by [add_interval_ds][], [add_interval_ym][], [add_months][]
+/// or `+` and `-` operators. The difference of two timestamps is calculated by
+/// [interval_since][] or `-` operator. Dates before 1582-10-15 are in the Julian
+/// calendar as Oracle does.
+///
+/// A time zone region name such as `Europe/Madrid` may be set by [and_tz_region][]
+/// in addition to the time zone offset. It is kept as it is. The offset isn't
+/// calculated from the region name. See [and_tz_region][] about fetching and
+/// binding it.
+///
+/// If you need more functionality such as calendar calculation, enable `chrono`
+/// feature and use [chrono::Date][], [chrono::DateTime][],
 /// [chrono::naive::NaiveDate][] or [chrono::naive::NaiveDateTime][] instead.
 ///
+/// [add_interval_ds]: #method.add_interval_ds
+/// [add_interval_ym]: #method.add_interval_ym
+/// [add_months]: #method.add_months
+/// [interval_since]: #method.interval_since
+/// [and_tz_region]: #method.and_tz_region
 /// [chrono::Date]: https://docs.rs/chrono/0.4/chrono/struct.Date.html
 /// [chrono::DateTime]: https://docs.rs/chrono/0.4/chrono/struct.DateTime.html
 /// [chrono::naive::NaiveDate]: https://docs.rs/chrono/0.4/chrono/naive/struct.NaiveDate.html
@@ -61,6 +282,24 @@ use crate::ParseOracleTypeError;
 /// // Precisions are ignored when intervals are compared.
 /// assert_eq!(ts1, ts3);
 ///
+/// // Add an interval.
+/// let ts5 = ts1 + IntervalDS::new(1, 2, 3, 4, 500000000);
+/// assert_eq!(ts5.to_string(), "2017-08-10 13:25:38.000000000");
+/// assert!(ts1 < ts5);
+///
+/// // Subtract timestamps.
+/// assert_eq!(ts5 - ts1, IntervalDS::new(1, 2, 3, 4, 500000000));
+///
+/// // Timestamps with time zone are compared in UTC.
+/// assert!(ts1.and_tz_hm_offset(9, 0) < ts1.and_tz_hm_offset(0, 0));
+///
+/// // Negative years are years BC. There is no year 0 as Oracle.
+/// // The alternate format `{:#}` uses an era indicator instead of the sign.
+/// let ts6 = Timestamp::try_new(-44, 3, 15, 12, 0, 0, 0)?.and_prec(0);
+/// assert_eq!(ts6.to_string(), "-44-03-15 12:00:00");
+/// assert_eq!(format!("{:#}", ts6), "0044-03-15 12:00:00 BC");
+/// assert_eq!("0044-03-15 12:00:00 BC".parse::<Timestamp>()?, ts6);
+///
 /// // Create a timestamp from string.
 /// let ts4: Timestamp = "2017-08-09 11:22:33.500 -08:00".parse()?;
 ///
@@ -106,6 +345,7 @@ pub struct Timestamp {
     tz_minute_offset: i32,
     precision: u8,
     with_tz: bool,
+    tz_region: Option<TzRegion>,
 }
 
 impl Timestamp {
@@ -128,6 +368,8 @@ impl Timestamp {
             tz_minute_offset: ts.tzMinuteOffset as i32,
             precision: precision,
             with_tz: with_tz,
+            // ODPI-C returns only the time zone offset.
+            tz_region: None,
         }
     }
 
@@ -137,7 +379,7 @@ impl Timestamp {
     ///
     /// | argument | valid values |
     /// |---|---|
-    /// | `year` | -4713 to 9999 |
+    /// | `year` | -4712 to 9999 except 0 |
     /// | `month` | 1 to 12 |
     /// | `day` | 1 to 31 |
     /// | `hour` | 0 to 23 |
@@ -145,6 +387,9 @@ impl Timestamp {
     /// | `second` | 0 to 59 |
     /// | `nanosecond` | 0 to 999,999,999 |
     ///
+    /// The arguments aren't checked. Use [try_new][] to check them.
+    ///
+    /// [try_new]: #method.try_new
     pub fn new(
         year: i32,
         month: u32,
@@ -166,9 +411,89 @@ impl Timestamp {
             tz_minute_offset: 0,
             precision: 9,
             with_tz: false,
+            tz_region: None,
         }
     }
 
+    /// Creates a timestamp after checking the arguments.
+    ///
+    /// The arguments are valid when the date exists in the calendar used by
+    /// Oracle. There is no year 0. Negative years are years BC. Dates before
+    /// 1582-10-15 are in the Julian calendar and the days from 1582-10-05 to
+    /// 1582-10-14 don't exist.
+    ///
+    /// ```
+    /// # use oracle::*; use oracle::sql_type::*; fn try_main() -> Result<()> {
+    /// assert!(Timestamp::try_new(2020, 2, 29, 0, 0, 0, 0).is_ok());
+    /// assert!(Timestamp::try_new(2019, 2, 29, 0, 0, 0, 0).is_err());
+    /// assert!(Timestamp::try_new(1500, 2, 29, 0, 0, 0, 0).is_ok()); // Julian calendar
+    /// assert!(Timestamp::try_new(1582, 10, 10, 0, 0, 0, 0).is_err());
+    /// assert!(Timestamp::try_new(0, 1, 1, 0, 0, 0, 0).is_err());
+    /// assert!(Timestamp::try_new(-4712, 1, 1, 0, 0, 0, 0).is_ok());
+    /// # Ok(())} fn main() { try_main().unwrap(); }
+    /// ```
+    pub fn try_new(
+        year: i32,
+        month: u32,
+        day: u32,
+        hour: u32,
+        minute: u32,
+        second: u32,
+        nanosecond: u32,
+    ) -> Result<Timestamp> {
+        check_date(year, month, day)?;
+        if hour > 23 {
+            return Err(Error::OutOfRange(
+                "hour must be between 0 and 23".to_string(),
+            ));
+        }
+        if minute > 59 {
+            return Err(Error::OutOfRange(
+                "minutes must be between 0 and 59".to_string(),
+            ));
+        }
+        if second > 59 {
+            return Err(Error::OutOfRange(
+                "seconds must be between 0 and 59".to_string(),
+            ));
+        }
+        if nanosecond > 999_999_999 {
+            return Err(Error::OutOfRange(
+                "nanoseconds must be between 0 and 999,999,999".to_string(),
+            ));
+        }
+        Ok(Timestamp::new(
+            year, month, day, hour, minute, second, nanosecond,
+        ))
+    }
+
+    /// Creates a timestamp at midnight from a Julian day number as
+    /// `TO_DATE(jdn, 'J')` does.
+    ///
+    /// The Julian day number is the number of days since January 1, 4712 BC
+    /// plus one. It must be between 1 (-4712-01-01) and 5373484 (9999-12-31).
+    ///
+    /// ```
+    /// # use oracle::*; use oracle::sql_type::*; fn try_main() -> Result<()> {
+    /// let ts = Timestamp::from_julian_day(2451545)?;
+    /// assert_eq!(ts, Timestamp::new(2000, 1, 1, 0, 0, 0, 0));
+    /// assert_eq!(ts.to_julian_day(), 2451545);
+    ///
+    /// let ts = Timestamp::from_julian_day(1)?;
+    /// assert_eq!(ts.to_string(), "-4712-01-01 00:00:00");
+    /// # Ok(())} fn main() { try_main().unwrap(); }
+    /// ```
+    pub fn from_julian_day(jdn: i64) -> Result<Timestamp> {
+        if jdn < MIN_JULIAN_DAY || MAX_JULIAN_DAY < jdn {
+            return Err(Error::OutOfRange(format!(
+                "julian date must be between {} and {}",
+                MIN_JULIAN_DAY, MAX_JULIAN_DAY
+            )));
+        }
+        let (year, month, day) = julian_day_to_date(jdn);
+        Ok(Timestamp::new(year, month, day, 0, 0, 0, 0).and_prec(0))
+    }
+
     /// Creates a timestamp with time zone.
     ///
     /// `offset` is time zone offset seconds from UTC.
@@ -197,6 +522,63 @@ impl Timestamp {
         }
     }
 
+    /// Creates a timestamp with time zone region name such as `Europe/Madrid`.
+    ///
+    /// The time zone offset isn't changed. Set it by [and_tz_offset][] or
+    /// [and_tz_hm_offset][] if needed. The text representation includes the
+    /// region name instead of the offset as `TZR` format element in Oracle.
+    ///
+    /// The region name must start with an ASCII letter and consist of ASCII
+    /// alphanumeric characters, `/`, `_`, `-` and `+` up to 32 bytes as names
+    /// in the time zone database. Otherwise, `Err(Error::OutOfRange(...))` is
+    /// returned.
+    ///
+    /// ODPI-C, which rust-oracle depends on, passes only time zone offsets
+    /// of `TIMESTAMP WITH TIME ZONE` values. The region name is lost when the
+    /// timestamp is fetched or bound as `TIMESTAMP WITH TIME ZONE`. To keep it,
+    /// fetch the value converted to a string by `TO_CHAR` with `TZR` and
+    /// bind the timestamp as a string such as `(&ts, &OracleType::Varchar2(64))`
+    /// converted by `TO_TIMESTAMP_TZ` as in the example below.
+    ///
+    /// [and_tz_offset]: #method.and_tz_offset
+    /// [and_tz_hm_offset]: #method.and_tz_hm_offset
+    ///
+    /// ```
+    /// # use oracle::*; use oracle::sql_type::*; fn try_main() -> Result<()> {
+    /// let ts = Timestamp::new(2019, 7, 1, 10, 0, 0, 0)
+    ///     .and_tz_hm_offset(2, 0)
+    ///     .and_tz_region("Europe/Madrid")?
+    ///     .and_prec(0);
+    /// assert_eq!(ts.tz_region(), Some("Europe/Madrid"));
+    /// assert_eq!(ts.to_string(), "2019-07-01 10:00:00 Europe/Madrid");
+    /// # Ok(())} fn main() { try_main().unwrap(); }
+    /// ```
+    ///
+    /// ```no_run
+    /// # use oracle::*; use oracle::sql_type::*; fn try_main() -> Result<()> {
+    /// let conn = Connection::connect("scott", "tiger", "")?;
+    ///
+    /// // Fetch a timestamp with the region name.
+    /// let sql = "select to_char(timestamp '2019-07-01 10:00:00 Europe/Madrid', \
+    ///                           'YYYY-MM-DD HH24:MI:SS.FF TZH:TZM TZR') from dual";
+    /// let ts = conn.query_row_as::<Timestamp>(sql, &[])?;
+    /// assert_eq!(ts.tz_offset(), 2 * 3600);
+    /// assert!(ts.tz_region().is_some());
+    ///
+    /// // Bind it back with the region name.
+    /// let sql = "select to_char(to_timestamp_tz(:1, 'YYYY-MM-DD HH24:MI:SS.FF TZR'), 'TZR') \
+    ///              from dual";
+    /// let region = conn.query_row_as::<String>(sql, &[&(&ts, &OracleType::Varchar2(64))])?;
+    /// # Ok(())} fn main() { try_main().unwrap(); }
+    /// ```
+    pub fn and_tz_region(&self, region: &str) -> Result<Timestamp> {
+        Ok(Timestamp {
+            tz_region: Some(TzRegion::new(region)?),
+            with_tz: true,
+            ..*self
+        })
+    }
+
     /// Creates a timestamp with precision.
     ///
     /// The precision affects text representation of Timestamp.
@@ -209,7 +591,87 @@ impl Timestamp {
         }
     }
 
-    /// Returns the year number from -4713 to 9999.
+    /// Parses a string with a datetime format model as `TO_TIMESTAMP_TZ` does.
+    ///
+    /// The following format elements are supported. Month and day names are
+    /// in English. The first day of week is Sunday. They are same with Oracle
+    /// when `NLS_DATE_LANGUAGE` is `AMERICAN` and `NLS_TERRITORY` is `AMERICA`.
+    ///
+    /// | Element | Description |
+    /// | --- | --- |
+    /// | `-` `/` `,` `.` `;` `:` and so on | Punctuation. Any punctuation characters match them unless `FX` is specified. |
+    /// | `"text"` | Quoted text |
+    /// | `AD`, `BC`, `A.D.`, `B.C.` | Era indicator |
+    /// | `AM`, `PM`, `A.M.`, `P.M.` | Meridian indicator |
+    /// | `CC`, `SCC` | Century (format only) |
+    /// | `D` | Day of week (1-7) |
+    /// | `DAY`, `DY` | Name and abbreviated name of day |
+    /// | `DD`, `DDD` | Day of month (1-31) and day of year (1-366) |
+    /// | `FF`, `FF1` to `FF9` | Fractional seconds. `FF` uses the precision of the timestamp when formatting. |
+    /// | `FM`, `FX` | Fill mode and format exact. They are toggled each time they appear. |
+    /// | `HH`, `HH12`, `HH24` | Hour of day (1-12 or 0-23) |
+    /// | `I`, `IY`, `IYY`, `IYYY`, `IW` | ISO year and week (format only) |
+    /// | `J` | Julian day number, days since January 1, 4712 BC plus one |
+    /// | `MI`, `SS`, `SSSSS` | Minute, second and seconds past midnight |
+    /// | `MM`, `MON`, `MONTH`, `RM` | Month, name, abbreviated name and roman numeral of month |
+    /// | `Q`, `W`, `WW` | Quarter, week of month and week of year (format only) |
+    /// | `RR`, `RRRR` | Two-digit year in the nearest century to the current year |
+    /// | `SYYYY`, `YYYY`, `Y,YYY`, `YYY`, `YY`, `Y` | Year |
+    /// | `TZH`, `TZM`, `TZR` | Time zone hour, minute and region |
+    /// | `X` | Radix character (`.`) |
+    ///
+    /// The current year and month in UTC are used when they are omitted.
+    /// Note that Oracle uses the local date of the database server instead,
+    /// so the completed year or month may differ from Oracle's around the
+    /// beginning of a month.
+    /// The precision is set to the number of digits of fractional seconds.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use oracle::*; use oracle::sql_type::*; fn try_main() -> Result<()> {
+    /// let ts = Timestamp::parse_with_format(
+    ///     "09-AUG-2017 11:22:33.500 -08:00",
+    ///     "DD-MON-YYYY HH24:MI:SS.FF3 TZH:TZM",
+    /// )?;
+    /// assert_eq!(ts, Timestamp::new(2017, 8, 9, 11, 22, 33, 500000000).and_tz_hm_offset(-8, 0));
+    ///
+    /// let ts = Timestamp::parse_with_format("August 9, 2017 11:22 PM", "Month DD, YYYY HH:MI AM")?;
+    /// assert_eq!(ts, Timestamp::new(2017, 8, 9, 23, 22, 0, 0));
+    /// # Ok(())} fn main() { try_main().unwrap(); }
+    /// ```
+    pub fn parse_with_format(s: &str, fmt: &str) -> Result<Timestamp> {
+        format_model::parse(s, fmt)
+    }
+
+    /// Formats the timestamp with a datetime format model as `TO_CHAR` does.
+    ///
+    /// See [parse_with_format][] for supported format elements.
+    ///
+    /// [parse_with_format]: #method.parse_with_format
+    ///
+    /// An error is returned when the timestamp has out-of-range fields,
+    /// which [new][] doesn't check.
+    ///
+    /// [new]: #method.new
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use oracle::*; use oracle::sql_type::*; fn try_main() -> Result<()> {
+    /// let ts = Timestamp::new(2017, 8, 9, 23, 22, 33, 500000000);
+    /// assert_eq!(ts.format("DD-MON-YYYY HH24:MI:SS.FF3")?, "09-AUG-2017 23:22:33.500");
+    /// assert_eq!(ts.format("Day, Month DD, YYYY HH:MI AM")?,
+    ///            "Wednesday, August    09, 2017 11:22 PM");
+    /// assert_eq!(ts.format("FMDay, Month DD, YYYY HH:MI AM")?,
+    ///            "Wednesday, August 9, 2017 11:22 PM");
+    /// # Ok(())} fn main() { try_main().unwrap(); }
+    /// ```
+    pub fn format(&self, fmt: &str) -> Result<String> {
+        format_model::format(self, fmt)
+    }
+
+    /// Returns the year number from -4712 to 9999 except 0. Negative numbers are years BC.
     pub fn year(&self) -> i32 {
         self.year
     }
@@ -254,6 +716,11 @@ impl Timestamp {
         self.tz_minute_offset
     }
 
+    /// Returns time zone region name if it is set.
+    pub fn tz_region(&self) -> Option<&str> {
+        self.tz_region.as_ref().map(TzRegion::as_str)
+    }
+
     /// Returns precision
     pub fn precision(&self) -> u8 {
         self.precision
@@ -269,6 +736,171 @@ impl Timestamp {
     pub fn tz_offset(&self) -> i32 {
         self.tz_hour_offset * 3600 + self.tz_minute_offset * 60
     }
+
+    /// Returns the Julian day number of the date as `TO_CHAR(ts, 'J')` does.
+    /// The time is ignored.
+    pub fn to_julian_day(&self) -> i64 {
+        date_to_julian_day(self.year, self.month, self.day)
+    }
+
+    // Returns nanoseconds since -4712-01-01 00:00:00 minus one day in local time.
+    fn local_nanoseconds(&self) -> i128 {
+        let secs = date_to_julian_day(self.year, self.month, self.day) * 86400
+            + (self.hour * 3600 + self.minute * 60 + self.second) as i64;
+        secs as i128 * NANOS_PER_SEC + self.nanosecond as i128
+    }
+
+    // Returns nanoseconds since -4712-01-01 00:00:00 minus one day in UTC.
+    fn utc_nanoseconds(&self) -> i128 {
+        self.local_nanoseconds() - self.tz_offset() as i128 * NANOS_PER_SEC
+    }
+
+    // Creates a timestamp with the date and time calculated from local_nanoseconds().
+    // Other fields are same with self.
+    fn with_local_nanoseconds(&self, nanos: i128) -> Result<Timestamp> {
+        let (jdn, nanos_of_day) = div_floor(nanos, NANOS_PER_DAY);
+        if jdn < MIN_JULIAN_DAY as i128 || jdn > MAX_JULIAN_DAY as i128 {
+            return Err(Error::OutOfRange(
+                "timestamp must be between -4712-01-01 and 9999-12-31".to_string(),
+            ));
+        }
+        let (year, month, day) = julian_day_to_date(jdn as i64);
+        let secs = (nanos_of_day / NANOS_PER_SEC) as u32;
+        Ok(Timestamp {
+            year,
+            month,
+            day,
+            hour: secs / 3600,
+            minute: secs / 60 % 60,
+            second: secs % 60,
+            nanosecond: (nanos_of_day % NANOS_PER_SEC) as u32,
+            ..*self
+        })
+    }
+
+    /// Adds an interval day to second.
+    ///
+    /// This returns `Err(Error::OutOfRange(...))` when the result is
+    /// out of range of Oracle timestamps.
+    pub fn add_interval_ds(&self, interval: &IntervalDS) -> Result<Timestamp> {
+        self.with_local_nanoseconds(self.local_nanoseconds() + interval.total_nanoseconds())
+    }
+
+    /// Adds an interval year to month.
+    ///
+    /// This returns `Err(Error::OutOfRange(...))` when the day doesn't exist
+    /// in the resulting month as Oracle raises `ORA-01839: date not valid
+    /// for month specified`. Use [add_months](#method.add_months) to adjust
+    /// the day to the end of month instead.
+    ///
+    /// ```
+    /// # use oracle::*; use oracle::sql_type::*; fn try_main() -> Result<()> {
+    /// let ts = Timestamp::new(2019, 1, 31, 0, 0, 0, 0);
+    /// assert!(ts.add_interval_ym(&IntervalYM::new(0, 1)).is_err());
+    /// assert_eq!(ts.add_months(1)?, Timestamp::new(2019, 2, 28, 0, 0, 0, 0));
+    /// # Ok(())} fn main() { try_main().unwrap(); }
+    /// ```
+    pub fn add_interval_ym(&self, interval: &IntervalYM) -> Result<Timestamp> {
+        let (year, month) = self.shift_months(interval.total_months())?;
+        if self.day > days_in_month(year, month) {
+            return Err(Error::OutOfRange(format!(
+                "date not valid for month specified: {}-{:02}-{:02}",
+                year, month, self.day
+            )));
+        }
+        Ok(Timestamp {
+            year,
+            month,
+            ..*self
+        })
+    }
+
+    /// Adds months as Oracle function `ADD_MONTHS` does.
+    ///
+    /// When the day is the last day of the month or the resulting month has
+    /// fewer days than the day, the day of the result is the last day of the
+    /// resulting month.
+    pub fn add_months(&self, months: i32) -> Result<Timestamp> {
+        let (year, month) = self.shift_months(months as i64)?;
+        let last_day = days_in_month(year, month);
+        let day = if self.day >= days_in_month(self.year, self.month) || self.day > last_day {
+            last_day
+        } else {
+            self.day
+        };
+        Ok(Timestamp {
+            year,
+            month,
+            day,
+            ..*self
+        })
+    }
+
+    fn shift_months(&self, months: i64) -> Result<(i32, u32)> {
+        let months = self.year as i128 * 12 + (self.month as i128 - 1) + months as i128;
+        let (year, month0) = div_floor(months, 12);
+        if year < -4712 || 9999 < year {
+            return Err(Error::OutOfRange(
+                "timestamp must be between -4712-01-01 and 9999-12-31".to_string(),
+            ));
+        }
+        Ok((year as i32, month0 as u32 + 1))
+    }
+
+    /// Returns the interval from `other` to `self`.
+    ///
+    /// When both have time zones, the interval is calculated in UTC.
+    pub fn interval_since(&self, other: &Timestamp) -> IntervalDS {
+        // The number of days never exceeds the range of IntervalDS.
+        IntervalDS::from_total_nanoseconds(self.utc_nanoseconds() - other.utc_nanoseconds())
+            .unwrap()
+    }
+
+    /// Converts the timestamp to another time zone offset.
+    ///
+    /// `offset` is time zone offset seconds from UTC. The point in time
+    /// doesn't change as `AT TIME ZONE` clause in Oracle. The time zone
+    /// region name is cleared.
+    ///
+    /// ```
+    /// # use oracle::*; use oracle::sql_type::*; fn try_main() -> Result<()> {
+    /// let ts = Timestamp::new(2019, 1, 1, 3, 0, 0, 0).and_tz_hm_offset(9, 0);
+    /// let ts = ts.to_tz_offset(-5 * 3600)?;
+    /// assert_eq!(ts.to_string(), "2018-12-31 13:00:00.000000000 -05:00");
+    /// # Ok(())} fn main() { try_main().unwrap(); }
+    /// ```
+    pub fn to_tz_offset(&self, offset: i32) -> Result<Timestamp> {
+        if offset % 60 != 0 || offset < -(12 * 3600 + 59 * 60) || 14 * 3600 + 59 * 60 < offset {
+            return Err(Error::OutOfRange(format!(
+                "invalid time zone offset: {} seconds",
+                offset
+            )));
+        }
+        let ts = Timestamp {
+            tz_region: None,
+            ..self.and_tz_offset(offset)
+        };
+        ts.with_local_nanoseconds(self.utc_nanoseconds() + offset as i128 * NANOS_PER_SEC)
+    }
+
+    /// Truncates fractional seconds to the precision and sets the precision.
+    ///
+    /// ```
+    /// # use oracle::*; use oracle::sql_type::*; fn try_main() -> Result<()> {
+    /// let ts = Timestamp::new(2019, 1, 2, 3, 4, 5, 123456789).trunc(3);
+    /// assert_eq!(ts.nanosecond(), 123000000);
+    /// assert_eq!(ts.to_string(), "2019-01-02 03:04:05.123");
+    /// # Ok(())} fn main() { try_main().unwrap(); }
+    /// ```
+    pub fn trunc(&self, precision: u8) -> Timestamp {
+        let precision = cmp::min(precision, 9);
+        let unit = 10u32.pow(9 - precision as u32);
+        Timestamp {
+            nanosecond: self.nanosecond / unit * unit,
+            precision,
+            ..*self
+        }
+    }
 }
 
 impl cmp::PartialEq for Timestamp {
@@ -285,12 +917,84 @@ impl cmp::PartialEq for Timestamp {
     }
 }
 
+impl cmp::Eq for Timestamp {}
+
+/// Timestamps are compared in UTC. When they are at the same point in time
+/// but have different time zone offsets, one with smaller offset is less
+/// to be consistent with `PartialEq`.
+impl cmp::Ord for Timestamp {
+    fn cmp(&self, other: &Self) -> cmp::Ordering {
+        self.utc_nanoseconds()
+            .cmp(&other.utc_nanoseconds())
+            .then_with(|| self.tz_offset().cmp(&other.tz_offset()))
+    }
+}
+
+impl cmp::PartialOrd for Timestamp {
+    fn partial_cmp(&self, other: &Self) -> Option<cmp::Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+/// Panics when the result is out of range. Use [add_interval_ds](#method.add_interval_ds) not to panic.
+impl ops::Add<IntervalDS> for Timestamp {
+    type Output = Timestamp;
+
+    fn add(self, other: IntervalDS) -> Timestamp {
+        self.add_interval_ds(&other).unwrap()
+    }
+}
+
+/// Panics when the result is out of range. Use [add_interval_ds](#method.add_interval_ds) not to panic.
+impl ops::Sub<IntervalDS> for Timestamp {
+    type Output = Timestamp;
+
+    fn sub(self, other: IntervalDS) -> Timestamp {
+        self.with_local_nanoseconds(self.local_nanoseconds() - other.total_nanoseconds())
+            .unwrap()
+    }
+}
+
+/// Panics when the result is out of range or the day doesn't exist in the resulting month.
+/// Use [add_interval_ym](#method.add_interval_ym) not to panic.
+impl ops::Add<IntervalYM> for Timestamp {
+    type Output = Timestamp;
+
+    fn add(self, other: IntervalYM) -> Timestamp {
+        self.add_interval_ym(&other).unwrap()
+    }
+}
+
+/// Panics when the result is out of range or the day doesn't exist in the resulting month.
+/// Use [add_interval_ym](#method.add_interval_ym) not to panic.
+impl ops::Sub<IntervalYM> for Timestamp {
+    type Output = Timestamp;
+
+    fn sub(self, other: IntervalYM) -> Timestamp {
+        self.add_interval_ym(&IntervalYM::new(-other.years(), -other.months()))
+            .unwrap()
+    }
+}
+
+impl ops::Sub<Timestamp> for Timestamp {
+    type Output = IntervalDS;
+
+    fn sub(self, other: Timestamp) -> IntervalDS {
+        self.interval_since(&other)
+    }
+}
+
 impl fmt::Display for Timestamp {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        if f.alternate() {
+            write!(f, "{:04}", self.year.abs())?;
+        } else {
+            write!(f, "{}", self.year)?;
+        }
         write!(
             f,
-            "{}-{:02}-{:02} {:02}:{:02}:{:02}",
-            self.year, self.month, self.day, self.hour, self.minute, self.second
+            "-{:02}-{:02} {:02}:{:02}:{:02}",
+            self.month, self.day, self.hour, self.minute, self.second
         )?;
         match self.precision {
             1 => write!(f, ".{:01}", self.nanosecond / 100000000)?,
@@ -304,7 +1008,12 @@ impl fmt::Display for Timestamp {
             9 => write!(f, ".{:09}", self.nanosecond)?,
             _ => (),
         }
-        if self.with_tz {
+        if f.alternate() && self.year < 0 {
+            write!(f, " BC")?;
+        }
+        if let Some(region) = self.tz_region() {
+            write!(f, " {}", region)?;
+        } else if self.with_tz {
             let sign = if self.tz_hour_offset < 0 || self.tz_minute_offset < 0 {
                 '-'
             } else {
@@ -322,10 +1031,30 @@ impl fmt::Display for Timestamp {
     }
 }
 
+// Reads an era indicator preceded by an optional space and returns true for BC.
+fn read_era(s: &mut Scanner) -> Option<bool> {
+    for &(era, bc) in &[("B.C.", true), ("BC", true), ("A.D.", false), ("AD", false)] {
+        let mut t = s.clone();
+        if let Some(' ') = t.char() {
+            t.next();
+        }
+        if t.read_ignore_ascii_case(era) {
+            match t.char() {
+                None | Some(' ') | Some('+') | Some('-') => {
+                    *s = t;
+                    return Some(bc);
+                }
+                _ => (),
+            }
+        }
+    }
+    None
+}
+
 impl str::FromStr for Timestamp {
     type Err = ParseOracleTypeError;
 
-    fn from_str(s: &str) -> Result<Self, Self::Err> {
+    fn from_str(s: &str) -> result::Result<Self, Self::Err> {
         let err = || ParseOracleTypeError::new("Timestamp");
         let mut s = Scanner::new(s);
         let minus = if let Some('-') = s.char() {
@@ -363,9 +1092,11 @@ impl str::FromStr for Timestamp {
         let mut tz_min: i32 = 0;
         let mut precision = 0;
         let mut with_tz = false;
+        let mut tz_region = None;
+        let mut bc = read_era(&mut s);
         if let Some(c) = s.char() {
             match c {
-                'T' | ' ' => {
+                'T' | ' ' if bc.is_none() => {
                     s.next();
                     hour = s.read_digits().ok_or(err())?;
                     if let Some(':') = s.char() {
@@ -398,6 +1129,9 @@ impl str::FromStr for Timestamp {
                     precision = 9;
                 }
             }
+            if bc.is_none() {
+                bc = read_era(&mut s);
+            }
             if let Some(' ') = s.char() {
                 s.next();
             }
@@ -428,18 +1162,47 @@ impl str::FromStr for Timestamp {
                     tz_min = -tz_min;
                     with_tz = true;
                 }
-                Some('Z') => {
+                _ => (),
+            }
+            if with_tz {
+                if let Some(' ') = s.char() {
                     s.next();
-                    with_tz = true;
                 }
-                _ => (),
             }
-            if s.char().is_some() {
-                return Err(err());
+            // time zone region name, or the offset again because `TZR` format
+            // element is formatted as the offset when the value has no region name.
+            let mut region = String::new();
+            while let Some(c) = s.char() {
+                if !is_tz_region_char(c) && c != ':' {
+                    return Err(err());
+                }
+                region.push(c);
+                s.next();
+            }
+            if region.starts_with(|c| c == '+' || c == '-') {
+                let sign = if tz_hour < 0 || tz_min < 0 { '-' } else { '+' };
+                let offset = format!("{}{:02}:{:02}", sign, tz_hour.abs(), tz_min.abs());
+                if !with_tz || region != offset {
+                    return Err(err());
+                }
+            } else if !region.is_empty() {
+                if !is_tz_region_name(&region) {
+                    return Err(err());
+                }
+                if region != "Z" {
+                    tz_region = Some(region);
+                }
+                with_tz = true;
             }
         }
+        let year = match (bc, minus) {
+            (Some(true), false) if year != 0 => -(year as i32),
+            (Some(_), true) | (Some(true), false) => return Err(err()),
+            (_, true) => -(year as i32),
+            (_, false) => year as i32,
+        };
         let mut ts = Timestamp::new(
-            if minus { -(year as i32) } else { year as i32 },
+            year,
             month as u32,
             day as u32,
             hour as u32,
@@ -451,6 +1214,9 @@ impl str::FromStr for Timestamp {
         if with_tz {
             ts = ts.and_tz_hm_offset(tz_hour, tz_min);
         }
+        if let Some(region) = tz_region {
+            ts = ts.and_tz_region(&region).map_err(|_| err())?;
+        }
         Ok(ts)
     }
 }
@@ -582,4 +1348,311 @@ mod tests {
         ts.tz_minute_offset = 45;
         assert_eq!("-123-03-04 05:06:07.123 +00:45".parse(), Ok(ts));
     }
+
+    #[test]
+    fn tz_region() {
+        let ts = Timestamp::new(2019, 7, 1, 10, 0, 0, 0).and_prec(0);
+        let ts_tzr = ts
+            .and_tz_hm_offset(2, 0)
+            .and_tz_region("Europe/Madrid")
+            .unwrap();
+        assert_eq!(ts_tzr.tz_region(), Some("Europe/Madrid"));
+        assert_eq!(ts_tzr.tz_offset(), 7200);
+        assert_eq!(ts_tzr.to_string(), "2019-07-01 10:00:00 Europe/Madrid");
+        // The region name is ignored by comparison.
+        assert_eq!(ts_tzr, ts.and_tz_hm_offset(2, 0));
+
+        let parse = |s: &str| s.parse::<Timestamp>().unwrap();
+        let ts = parse("2019-07-01 10:00:00 Europe/Madrid");
+        assert_eq!(ts.tz_region(), Some("Europe/Madrid"));
+        assert_eq!(ts.tz_offset(), 0);
+        assert!(ts.with_tz());
+        let ts = parse("2019-07-01 10:00:00.5 +02:00 Europe/Madrid");
+        assert_eq!(ts.tz_region(), Some("Europe/Madrid"));
+        assert_eq!(ts.tz_offset(), 7200);
+        assert_eq!(ts.nanosecond(), 500000000);
+        let ts = parse("2019-07-01 10:00:00 -05:00 America/Argentina/Buenos_Aires");
+        assert_eq!(ts.tz_region(), Some("America/Argentina/Buenos_Aires"));
+        assert_eq!(ts.tz_offset(), -5 * 3600);
+        let ts = parse("2019-07-01 10:00:00 Etc/GMT+5");
+        assert_eq!(ts.tz_region(), Some("Etc/GMT+5"));
+        let ts = parse("2019-07-01 10:00:00Z");
+        assert_eq!(ts.tz_region(), None);
+        assert!(ts.with_tz());
+        assert!("2019-07-01 10:00:00 +02:00 Europe:Madrid"
+            .parse::<Timestamp>()
+            .is_err());
+        assert!("2019-07-01 10:00:00 Europe/Madrid x"
+            .parse::<Timestamp>()
+            .is_err());
+        // TZR of a value without a region name repeats the offset.
+        let ts = parse("2019-07-01 10:00:00 -02:30 -02:30");
+        assert_eq!(ts.tz_region(), None);
+        assert_eq!(ts.tz_offset(), -9000);
+        assert!("2019-07-01 10:00:00 +02:30 -02:30"
+            .parse::<Timestamp>()
+            .is_err());
+        // Only a region name or the same offset may follow an offset.
+        for s in &[
+            "2019-07-01 10:00:00 +02:00 12345",
+            "2019-07-01 10:00:00 +02:00 +03:00",
+            "2019-07-01 10:00:00 +02:00 America/Argentina/ComodRivadavia_",
+        ] {
+            assert!(s.parse::<Timestamp>().is_err(), "{}", s);
+        }
+        assert!(ts.and_tz_region("Europe:Madrid").is_err());
+        assert!(ts.and_tz_region("").is_err());
+
+        // AT TIME ZONE with an offset clears the region name.
+        let ts = parse("2019-07-01 10:00:00 +02:00 Europe/Madrid");
+        assert_eq!(ts.to_tz_offset(0).unwrap().tz_region(), None);
+    }
+
+    #[test]
+    fn try_new() {
+        assert!(Timestamp::try_new(2019, 12, 31, 23, 59, 59, 999999999).is_ok());
+        assert!(Timestamp::try_new(-4712, 1, 1, 0, 0, 0, 0).is_ok());
+        assert!(Timestamp::try_new(-4713, 12, 31, 0, 0, 0, 0).is_err());
+        assert!(Timestamp::try_new(10000, 1, 1, 0, 0, 0, 0).is_err());
+        assert!(Timestamp::try_new(0, 1, 1, 0, 0, 0, 0).is_err());
+        assert!(Timestamp::try_new(-1, 2, 29, 0, 0, 0, 0).is_ok());
+        assert!(Timestamp::try_new(-2, 2, 29, 0, 0, 0, 0).is_err());
+        assert!(Timestamp::try_new(1500, 2, 29, 0, 0, 0, 0).is_ok());
+        assert!(Timestamp::try_new(1700, 2, 29, 0, 0, 0, 0).is_err());
+        assert!(Timestamp::try_new(1582, 10, 4, 0, 0, 0, 0).is_ok());
+        assert!(Timestamp::try_new(1582, 10, 5, 0, 0, 0, 0).is_err());
+        assert!(Timestamp::try_new(1582, 10, 14, 0, 0, 0, 0).is_err());
+        assert!(Timestamp::try_new(1582, 10, 15, 0, 0, 0, 0).is_ok());
+        assert!(Timestamp::try_new(2019, 0, 1, 0, 0, 0, 0).is_err());
+        assert!(Timestamp::try_new(2019, 13, 1, 0, 0, 0, 0).is_err());
+        assert!(Timestamp::try_new(2019, 4, 31, 0, 0, 0, 0).is_err());
+        assert!(Timestamp::try_new(2019, 1, 0, 0, 0, 0, 0).is_err());
+        assert!(Timestamp::try_new(2019, 1, 1, 24, 0, 0, 0).is_err());
+        assert!(Timestamp::try_new(2019, 1, 1, 0, 60, 0, 0).is_err());
+        assert!(Timestamp::try_new(2019, 1, 1, 0, 0, 60, 0).is_err());
+        assert!(Timestamp::try_new(2019, 1, 1, 0, 0, 0, 1_000_000_000).is_err());
+    }
+
+    #[test]
+    fn from_to_julian_day() {
+        assert_eq!(
+            Timestamp::from_julian_day(1).unwrap(),
+            Timestamp::new(-4712, 1, 1, 0, 0, 0, 0)
+        );
+        assert_eq!(
+            Timestamp::from_julian_day(2299161).unwrap(),
+            Timestamp::new(1582, 10, 15, 0, 0, 0, 0)
+        );
+        assert_eq!(
+            Timestamp::from_julian_day(5373484).unwrap(),
+            Timestamp::new(9999, 12, 31, 0, 0, 0, 0)
+        );
+        assert!(Timestamp::from_julian_day(0).is_err());
+        assert!(Timestamp::from_julian_day(5373485).is_err());
+        let ts = Timestamp::new(1582, 10, 4, 23, 59, 59, 999999999);
+        assert_eq!(ts.to_julian_day(), 2299160);
+    }
+
+    #[test]
+    fn era() {
+        let ts = Timestamp::new(-44, 3, 15, 12, 0, 0, 0).and_prec(0);
+        assert_eq!(format!("{:#}", ts), "0044-03-15 12:00:00 BC");
+        assert_eq!(
+            format!("{:#}", ts.and_tz_hm_offset(1, 0)),
+            "0044-03-15 12:00:00 BC +01:00"
+        );
+        let ts2 = Timestamp::new(5, 1, 2, 0, 0, 0, 0).and_prec(0);
+        assert_eq!(format!("{:#}", ts2), "0005-01-02 00:00:00");
+        assert_eq!(ts2.to_string(), "5-01-02 00:00:00");
+
+        let parse = |s: &str| s.parse::<Timestamp>();
+        assert_eq!(parse("0044-03-15 12:00:00 BC"), Ok(ts));
+        assert_eq!(parse("44-03-15 12:00:00 b.c."), Ok(ts));
+        assert_eq!(parse("-44-03-15 12:00:00"), Ok(ts));
+        assert_eq!(
+            parse("0044-03-15 12:00:00 BC +01:00"),
+            Ok(ts.and_tz_hm_offset(1, 0))
+        );
+        assert_eq!(
+            parse("0044-03-15 12:00:00 BC Europe/Rome")
+                .unwrap()
+                .tz_region(),
+            Some("Europe/Rome")
+        );
+        let date = Timestamp::new(-44, 3, 15, 0, 0, 0, 0).and_prec(0);
+        assert_eq!(parse("0044-03-15 BC"), Ok(date));
+        assert_eq!(
+            parse("0044-03-15 AD"),
+            Ok(Timestamp::new(44, 3, 15, 0, 0, 0, 0).and_prec(0))
+        );
+        assert!(parse("-0044-03-15 BC").is_err());
+        assert!(parse("0000-03-15 BC").is_err());
+        assert!(parse("0044-03-15 BCE").is_err());
+        // The round trip in both formats
+        for ts in &[ts, ts2, Timestamp::new(-4712, 1, 1, 0, 0, 0, 0)] {
+            assert_eq!(parse(&ts.to_string()), Ok(*ts));
+            assert_eq!(parse(&format!("{:#}", ts)), Ok(*ts));
+        }
+    }
+
+    #[test]
+    fn julian_day() {
+        assert_eq!(date_to_julian_day(-4712, 1, 1), 1);
+        assert_eq!(date_to_julian_day(-1, 12, 31), 1721058);
+        assert_eq!(date_to_julian_day(0, 1, 1), 1721059);
+        assert_eq!(date_to_julian_day(1, 1, 1), 1721424);
+        assert_eq!(date_to_julian_day(1582, 10, 4), 2299160);
+        assert_eq!(date_to_julian_day(1582, 10, 15), 2299161);
+        assert_eq!(date_to_julian_day(2000, 1, 1), 2451545);
+        assert_eq!(date_to_julian_day(9999, 12, 31), 5373484);
+
+        // Check all days in the range.
+        let mut date = (-4712, 1, 1);
+        for jdn in MIN_JULIAN_DAY..=MAX_JULIAN_DAY {
+            assert_eq!(julian_day_to_date(jdn), date);
+            assert_eq!(date_to_julian_day(date.0, date.1, date.2), jdn);
+            date = if date == (1582, 10, 4) {
+                (1582, 10, 15)
+            } else if date.2 < days_in_month(date.0, date.1) {
+                (date.0, date.1, date.2 + 1)
+            } else if date.1 < 12 {
+                (date.0, date.1 + 1, 1)
+            } else {
+                (date.0 + 1, 1, 1)
+            };
+        }
+    }
+
+    #[test]
+    fn leap_year() {
+        assert!(is_leap_year(2000));
+        assert!(!is_leap_year(1900));
+        assert!(is_leap_year(1500));
+        assert!(!is_leap_year(0));
+        assert!(is_leap_year(-1));
+        assert!(!is_leap_year(-4));
+        assert!(is_leap_year(-4709));
+    }
+
+    #[test]
+    fn add_interval() {
+        let ts = Timestamp::new(2020, 2, 28, 23, 59, 59, 500000000);
+        assert_eq!(
+            ts + IntervalDS::new(0, 0, 0, 0, 500000000),
+            Timestamp::new(2020, 2, 29, 0, 0, 0, 0)
+        );
+        assert_eq!(
+            ts + IntervalDS::new(1, 0, 0, 1, 0),
+            Timestamp::new(2020, 3, 1, 0, 0, 0, 500000000)
+        );
+        assert_eq!(
+            ts - IntervalDS::new(59, 23, 59, 59, 500000000),
+            Timestamp::new(2019, 12, 31, 0, 0, 0, 0)
+        );
+        assert_eq!(
+            Timestamp::new(1582, 10, 4, 12, 0, 0, 0) + IntervalDS::new(1, 0, 0, 0, 0),
+            Timestamp::new(1582, 10, 15, 12, 0, 0, 0)
+        );
+        assert_eq!(
+            Timestamp::new(1, 1, 1, 0, 0, 0, 0) - IntervalDS::new(366, 0, 0, 0, 0),
+            Timestamp::new(-1, 12, 31, 0, 0, 0, 0)
+        );
+        let ts = Timestamp::new(9999, 12, 31, 23, 59, 59, 999999999);
+        match ts.add_interval_ds(&IntervalDS::new(0, 0, 0, 0, 1)) {
+            Err(Error::OutOfRange(_)) => (),
+            result => panic!("Unexpected result: {:?}", result),
+        }
+
+        let ts = Timestamp::new(2019, 1, 31, 1, 2, 3, 4).and_tz_hm_offset(9, 0);
+        assert_eq!(
+            ts + IntervalYM::new(1, 2),
+            Timestamp::new(2020, 3, 31, 1, 2, 3, 4).and_tz_hm_offset(9, 0)
+        );
+        assert_eq!(
+            ts - IntervalYM::new(2019, 1),
+            Timestamp::new(-1, 12, 31, 1, 2, 3, 4).and_tz_hm_offset(9, 0)
+        );
+        match ts.add_interval_ym(&IntervalYM::new(0, 1)) {
+            Err(Error::OutOfRange(_)) => (),
+            result => panic!("Unexpected result: {:?}", result),
+        }
+        assert_eq!(
+            Timestamp::new(2020, 2, 29, 0, 0, 0, 0)
+                .add_interval_ym(&IntervalYM::new(-4, 0))
+                .unwrap(),
+            Timestamp::new(2016, 2, 29, 0, 0, 0, 0)
+        );
+        assert!(Timestamp::new(2020, 2, 29, 0, 0, 0, 0)
+            .add_interval_ym(&IntervalYM::new(1, 0))
+            .is_err());
+    }
+
+    #[test]
+    fn add_months() {
+        let date = |y, m, d| Timestamp::new(y, m, d, 0, 0, 0, 0);
+        assert_eq!(date(2019, 1, 31).add_months(1).unwrap(), date(2019, 2, 28));
+        assert_eq!(date(2019, 2, 28).add_months(1).unwrap(), date(2019, 3, 31));
+        assert_eq!(date(2019, 2, 27).add_months(1).unwrap(), date(2019, 3, 27));
+        assert_eq!(date(2020, 2, 29).add_months(12).unwrap(), date(2021, 2, 28));
+        assert_eq!(
+            date(2019, 3, 15).add_months(-15).unwrap(),
+            date(2017, 12, 15)
+        );
+        assert!(date(9999, 12, 1).add_months(1).is_err());
+    }
+
+    #[test]
+    fn sub_timestamp() {
+        let ts1 = Timestamp::new(2019, 3, 1, 0, 0, 0, 0);
+        let ts2 = Timestamp::new(2019, 2, 28, 12, 30, 0, 500000000);
+        assert_eq!(ts1 - ts2, IntervalDS::new(0, 11, 29, 59, 500000000));
+        assert_eq!(ts2 - ts1, IntervalDS::new(0, -11, -29, -59, -500000000));
+        let ts1 = ts1.and_tz_hm_offset(9, 0);
+        let ts2 = ts1.and_tz_hm_offset(-5, -30);
+        assert_eq!(ts2 - ts1, IntervalDS::new(0, 14, 30, 0, 0));
+        assert_eq!(
+            Timestamp::new(9999, 12, 31, 0, 0, 0, 0) - Timestamp::new(-4712, 1, 1, 0, 0, 0, 0),
+            IntervalDS::new(5373483, 0, 0, 0, 0)
+        );
+    }
+
+    #[test]
+    fn compare() {
+        let ts = Timestamp::new(2019, 1, 1, 0, 0, 0, 0);
+        assert!(ts < Timestamp::new(2019, 1, 1, 0, 0, 0, 1));
+        assert!(ts > Timestamp::new(2018, 12, 31, 23, 59, 59, 999999999));
+        assert!(Timestamp::new(-1, 1, 1, 0, 0, 0, 0) < Timestamp::new(1, 1, 1, 0, 0, 0, 0));
+        // the same point in time
+        let ts1 = ts.and_tz_hm_offset(9, 0);
+        let ts2 = Timestamp::new(2018, 12, 31, 15, 0, 0, 0).and_tz_hm_offset(0, 0);
+        assert_ne!(ts1, ts2);
+        assert!(ts2 < ts1);
+        assert!(ts1 < ts.and_tz_hm_offset(0, 0));
+    }
+
+    #[test]
+    fn to_tz_offset() {
+        let ts = Timestamp::new(2019, 1, 1, 3, 30, 0, 0).and_tz_hm_offset(9, 0);
+        assert_eq!(
+            ts.to_tz_offset(-5 * 3600 - 30 * 60).unwrap(),
+            Timestamp::new(2018, 12, 31, 13, 0, 0, 0).and_tz_hm_offset(-5, -30)
+        );
+        assert_eq!(
+            ts.to_tz_offset(0).unwrap(),
+            Timestamp::new(2018, 12, 31, 18, 30, 0, 0).and_tz_hm_offset(0, 0)
+        );
+        assert!(ts.to_tz_offset(15 * 3600).is_err());
+        assert!(ts.to_tz_offset(30).is_err());
+    }
+
+    #[test]
+    fn trunc() {
+        let ts = Timestamp::new(2019, 1, 2, 3, 4, 5, 123456789);
+        assert_eq!(ts.trunc(0).nanosecond(), 0);
+        assert_eq!(ts.trunc(0).precision(), 0);
+        assert_eq!(ts.trunc(1).nanosecond(), 100000000);
+        assert_eq!(ts.trunc(6).nanosecond(), 123456000);
+        assert_eq!(ts.trunc(9).nanosecond(), 123456789);
+        assert_eq!(ts.trunc(10).precision(), 9);
+    }
 }
@@ -0,0 +1,78 @@
+// Rust-oracle - Rust binding for Oracle database
+//
+// URL: https://github.com/kubo/rust-oracle
+//
+//-----------------------------------------------------------------------------
+// Copyright (c) 2017-2018 Kubo Takehiro <kubo@jiubao.org>. All rights reserved.
+// This program is free software: you can modify it and/or redistribute it
+// under the terms of:
+//
+// (i)  the Universal Permissive License v 1.0 or at your option, any
+//      later version (http://oss.oracle.com/licenses/upl); and/or
+//
+// (ii) the Apache License v 2.0. (http://www.apache.org/licenses/LICENSE-2.0)
+//-----------------------------------------------------------------------------
+
+use crate::sql_type::FromSql;
+use crate::sql_type::OracleType;
+use crate::sql_type::ToSql;
+use crate::sql_type::ToSqlNull;
+use crate::Connection;
+use crate::Error;
+use crate::Result;
+use crate::SqlValue;
+use uuid::Uuid;
+
+//
+// uuid::Uuid
+//
+
+fn is_binary_type(oratype: &OracleType) -> bool {
+    match *oratype {
+        OracleType::Raw(_) | OracleType::BLOB | OracleType::BFILE | OracleType::LongRaw => true,
+        _ => false,
+    }
+}
+
+fn invalid_conversion(oratype: &OracleType) -> Error {
+    Error::InvalidTypeConversion(oratype.to_string(), "Uuid".to_string())
+}
+
+impl FromSql for Uuid {
+    fn from_sql(val: &SqlValue) -> Result<Uuid> {
+        let oratype = val.oracle_type()?;
+        if is_binary_type(oratype) {
+            // 16 bytes such as values returned by SYS_GUID()
+            let bytes = val.to_bytes()?;
+            return Uuid::from_slice(&bytes).map_err(|_| invalid_conversion(oratype));
+        }
+        // 32 hexadecimal digits with or without hyphens. Trailing spaces
+        // are padded by CHAR columns.
+        let s = val.to_string()?;
+        let s = s.trim_end_matches(' ');
+        match s.len() {
+            32 | 36 => Uuid::parse_str(s).map_err(|err| Error::ParseError(Box::new(err))),
+            _ => Err(invalid_conversion(oratype)),
+        }
+    }
+}
+
+impl ToSqlNull for Uuid {
+    fn oratype_for_null(_conn: &Connection) -> Result<OracleType> {
+        Ok(OracleType::Raw(16))
+    }
+}
+
+impl ToSql for Uuid {
+    fn oratype(&self, _conn: &Connection) -> Result<OracleType> {
+        Ok(OracleType::Raw(16))
+    }
+    fn to_sql(&self, val: &mut SqlValue) -> Result<()> {
+        if is_binary_type(val.oracle_type()?) {
+            val.set_bytes(self.as_bytes())
+        } else {
+            // hyphenated lowercase such as 67e55044-10b1-426f-9247-bb680e5fe0c8
+            val.set_string(&self.to_string())
+        }
+    }
+}
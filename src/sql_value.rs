@@ -13,11 +13,12 @@
 // (ii) the Apache License v 2.0. (http://www.apache.org/licenses/LICENSE-2.0)
 //-----------------------------------------------------------------------------
 
-use std::cell::RefCell;
 use std::fmt;
 use std::ptr;
-use std::rc::Rc;
 use std::str;
+use std::sync::atomic::AtomicUsize;
+use std::sync::atomic::Ordering;
+use std::sync::Arc;
 use try_from::TryInto;
 
 use crate::binding::*;
@@ -29,6 +30,7 @@ use crate::sql_type::IntervalYM;
 use crate::sql_type::NativeType;
 use crate::sql_type::Object;
 use crate::sql_type::ObjectType;
+use crate::sql_type::OracleNumber;
 use crate::sql_type::OracleType;
 use crate::sql_type::Timestamp;
 use crate::sql_type::ToSql;
@@ -37,6 +39,7 @@ use crate::to_rust_str;
 use crate::util::check_number_format;
 use crate::util::parse_str_into_raw;
 use crate::util::set_hex_string;
+use crate::AssertSend;
 use crate::Connection;
 use crate::Context;
 use crate::DpiConn;
@@ -110,7 +113,7 @@ macro_rules! define_fn_set_int {
 }
 
 pub enum BufferRowIndex {
-    Shared(Rc<RefCell<u32>>),
+    Shared(Arc<AtomicUsize>),
     Owned(u32),
 }
 
@@ -142,6 +145,12 @@ pub struct SqlValue {
     keep_dpiobj: *mut dpiObject,
 }
 
+// The dpiVar handle and the data buffer are owned by a SqlValue.
+// It may be moved to another thread but not shared.
+unsafe impl Send for SqlValue {}
+
+impl AssertSend for SqlValue {}
+
 impl SqlValue {
     // for column and bind values
     pub(crate) fn new(ctxt: &'static Context) -> SqlValue {
@@ -263,7 +272,7 @@ impl SqlValue {
 
     fn buffer_row_index(&self) -> u32 {
         match self.buffer_row_index {
-            BufferRowIndex::Shared(ref idx) => *idx.borrow(),
+            BufferRowIndex::Shared(ref idx) => idx.load(Ordering::Relaxed) as u32,
             BufferRowIndex::Owned(idx) => idx,
         }
     }
@@ -272,6 +281,16 @@ impl SqlValue {
         unsafe { self.data.offset(self.buffer_row_index() as isize) }
     }
 
+    // Returns the data at the specified position in the buffer
+    // regardless of the current buffer row index.
+    pub(crate) fn data_at(&self, idx: u32) -> *mut dpiData {
+        unsafe { self.data.offset(idx as isize) }
+    }
+
+    pub(crate) fn native_type(&self) -> &NativeType {
+        &self.native_type
+    }
+
     pub(crate) fn native_type_num(&self) -> dpiNativeTypeNum {
         self.native_type.to_native_type_num()
     }
@@ -815,6 +834,43 @@ impl SqlValue {
         }
     }
 
+    /// Gets the SQL value as i128. The Oracle type must be
+    /// numeric or string (excluding LOB) types.
+    pub(crate) fn to_i128(&self) -> Result<i128> {
+        match self.native_type {
+            NativeType::Int64 => Ok(self.get_i64_unchecked()? as i128),
+            NativeType::UInt64 => Ok(self.get_u64_unchecked()? as i128),
+            NativeType::Float => flt_to_int!(self.get_f32_unchecked()?, f32, i128),
+            NativeType::Double => flt_to_int!(self.get_f64_unchecked()?, f64, i128),
+            NativeType::Char | NativeType::CLOB | NativeType::Number => {
+                self.get_string()?.parse::<OracleNumber>()?.to_i128()
+            }
+            _ => self.invalid_conversion_to_rust_type("i128"),
+        }
+    }
+
+    /// Gets the SQL value as u128. The Oracle type must be
+    /// numeric or string (excluding LOB) types.
+    pub(crate) fn to_u128(&self) -> Result<u128> {
+        match self.native_type {
+            NativeType::Int64 => {
+                let val = self.get_i64_unchecked()?;
+                if val >= 0 {
+                    Ok(val as u128)
+                } else {
+                    Err(Error::OutOfRange(format!("u128 overflow: {}", val)))
+                }
+            }
+            NativeType::UInt64 => Ok(self.get_u64_unchecked()? as u128),
+            NativeType::Float => flt_to_int!(self.get_f32_unchecked()?, f32, u128),
+            NativeType::Double => flt_to_int!(self.get_f64_unchecked()?, f64, u128),
+            NativeType::Char | NativeType::CLOB | NativeType::Number => {
+                self.get_string()?.parse::<OracleNumber>()?.to_u128()
+            }
+            _ => self.invalid_conversion_to_rust_type("u128"),
+        }
+    }
+
     /// Gets the SQL value as f32. The Oracle type must be
     /// numeric or string (excluding LOB) types.
     pub(crate) fn to_f32(&self) -> Result<f32> {
@@ -845,6 +901,21 @@ impl SqlValue {
         }
     }
 
+    /// Gets the SQL value as OracleNumber. The Oracle type must be
+    /// numeric or string (excluding LOB) types.
+    pub(crate) fn to_oracle_number(&self) -> Result<OracleNumber> {
+        match self.native_type {
+            NativeType::Int64 => Ok(self.get_i64_unchecked()?.into()),
+            NativeType::UInt64 => Ok(self.get_u64_unchecked()?.into()),
+            NativeType::Float => Ok(self.get_f32_unchecked()?.to_string().parse()?),
+            NativeType::Double => Ok(self.get_f64_unchecked()?.to_string().parse()?),
+            NativeType::Char | NativeType::CLOB | NativeType::Number => {
+                Ok(self.get_string()?.parse()?)
+            }
+            _ => self.invalid_conversion_to_rust_type("OracleNumber"),
+        }
+    }
+
     /// Gets the SQL value as string. ...
     pub(crate) fn to_string(&self) -> Result<String> {
         match self.native_type {
@@ -998,6 +1069,38 @@ impl SqlValue {
         /// numeric or string (excluding LOB) types.
         : set_f64, f64);
 
+    /// Sets i128 to the SQL value. The Oracle type must be
+    /// numeric or string (excluding LOB) types.
+    pub(crate) fn set_i128(&mut self, val: &i128) -> Result<()> {
+        match self.native_type {
+            NativeType::Int64 => self.set_i64_unchecked(flt_to_int!(*val, i128, i64)?),
+            NativeType::UInt64 => self.set_u64_unchecked(flt_to_int!(*val, i128, u64)?),
+            NativeType::Float => self.set_f32_unchecked(*val as f32),
+            NativeType::Double => self.set_f64_unchecked(*val as f64),
+            NativeType::Char | NativeType::Number => self.set_string_unchecked(&val.to_string()),
+            _ => self.invalid_conversion_from_rust_type("i128"),
+        }
+    }
+
+    /// Sets u128 to the SQL value. The Oracle type must be
+    /// numeric or string (excluding LOB) types.
+    pub(crate) fn set_u128(&mut self, val: &u128) -> Result<()> {
+        match self.native_type {
+            NativeType::Int64 => {
+                if *val <= i64::MAX as u128 {
+                    self.set_i64_unchecked(*val as i64)
+                } else {
+                    Err(Error::OutOfRange(format!("i64 overflow: {}", val)))
+                }
+            }
+            NativeType::UInt64 => self.set_u64_unchecked(flt_to_int!(*val, u128, u64)?),
+            NativeType::Float => self.set_f32_unchecked(*val as f32),
+            NativeType::Double => self.set_f64_unchecked(*val as f64),
+            NativeType::Char | NativeType::Number => self.set_string_unchecked(&val.to_string()),
+            _ => self.invalid_conversion_from_rust_type("u128"),
+        }
+    }
+
     /// Sets &str to the SQL value. ...
     pub(crate) fn set_string(&mut self, val: &str) -> Result<()> {
         match self.native_type {
@@ -1020,6 +1123,19 @@ impl SqlValue {
         }
     }
 
+    /// Sets OracleNumber to the SQL value. The Oracle type must be
+    /// numeric or string (excluding LOB) types.
+    pub(crate) fn set_oracle_number(&mut self, val: &OracleNumber) -> Result<()> {
+        match self.native_type {
+            NativeType::Int64 => self.set_i64_unchecked(val.to_string().parse()?),
+            NativeType::UInt64 => self.set_u64_unchecked(val.to_string().parse()?),
+            NativeType::Float => self.set_f32_unchecked(val.to_string().parse()?),
+            NativeType::Double => self.set_f64_unchecked(val.to_string().parse()?),
+            NativeType::Char | NativeType::Number => self.set_string_unchecked(&val.to_string()),
+            _ => self.invalid_conversion_from_rust_type("OracleNumber"),
+        }
+    }
+
     /// Sets &[u8] to the SQL value. ...
     pub(crate) fn set_bytes(&mut self, val: &[u8]) -> Result<()> {
         match self.native_type {
@@ -1030,10 +1146,13 @@ impl SqlValue {
     }
 
     /// Sets Timestamp to the SQL value. The Oracle type must be
-    /// `DATE`, `TIMESTAMP`, or `TIMESTAMP WITH TIME ZONE`.
+    /// `DATE`, `TIMESTAMP`, `TIMESTAMP WITH TIME ZONE` or string types.
+    /// It is set as text including the time zone region name to string types.
     pub(crate) fn set_timestamp(&mut self, val: &Timestamp) -> Result<()> {
         match self.native_type {
             NativeType::Timestamp => self.set_timestamp_unchecked(val),
+            NativeType::Char => self.set_string_unchecked(&val.to_string()),
+            NativeType::CLOB => self.set_string_to_clob_unchecked(&val.to_string()),
             _ => self.invalid_conversion_from_rust_type("Timestamp"),
         }
     }
@@ -1077,6 +1196,8 @@ impl SqlValue {
     pub(crate) fn set_bool(&mut self, val: &bool) -> Result<()> {
         match self.native_type {
             NativeType::Boolean => self.set_bool_unchecked(*val),
+            NativeType::Int64 => self.set_i64_unchecked(if *val { 1 } else { 0 }),
+            NativeType::Number => self.set_string_unchecked(if *val { "1" } else { "0" }),
             _ => self.invalid_conversion_from_rust_type("bool"),
         }
     }
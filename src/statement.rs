@@ -13,14 +13,15 @@
 // (ii) the Apache License v 2.0. (http://www.apache.org/licenses/LICENSE-2.0)
 //-----------------------------------------------------------------------------
 
-use std::cell::RefCell;
+use std::cmp;
 use std::fmt;
 use std::ptr;
-use std::rc::Rc;
+use std::sync::atomic::AtomicUsize;
+use std::sync::atomic::Ordering;
+use std::sync::Arc;
 
 use crate::binding::*;
 use crate::chkerr;
-use crate::new_odpi_str;
 use crate::private;
 use crate::sql_type::FromSql;
 use crate::sql_type::OracleType;
@@ -28,6 +29,8 @@ use crate::sql_type::ToSql;
 use crate::sql_value::BufferRowIndex;
 use crate::to_odpi_str;
 use crate::to_rust_str;
+use crate::AssertSend;
+use crate::ColumnarBatch;
 use crate::Connection;
 use crate::Error;
 use crate::Result;
@@ -54,9 +57,41 @@ pub enum StmtParam {
     ///
     /// If the query returns only onw row, you should use
     /// `StmtParam::FetchArraySize(1)`.
+    ///
+    /// The default value for statements in a connection can be changed
+    /// by [Connection.set_fetch_array_size][].
+    ///
+    /// [Connection.set_fetch_array_size]: struct.Connection.html#method.set_fetch_array_size
     FetchArraySize(u32),
 
-    /// Reserved for when statement caching is supported.
+    /// The number of rows prefetched by the Oracle client library
+    ///
+    /// Rows are prefetched along with execution of the statement
+    /// and other network round trips. When the query returns only a few
+    /// rows, prefetching them in the execution saves a round trip
+    /// to fetch rows. Zero disables prefetching.
+    ///
+    /// The default value for statements in a connection can be changed
+    /// by [Connection.set_prefetch_rows][]. When neither is set, the
+    /// default value of the Oracle client library is used.
+    ///
+    /// [Connection.set_prefetch_rows]: struct.Connection.html#method.set_prefetch_rows
+    PrefetchRows(u32),
+
+    /// Specifies the key to search for a statement in the statement cache.
+    ///
+    /// When a statement was returned to the cache under the same tag
+    /// by [Statement.close_with_tag][], it is reused without parsing the
+    /// SQL. Otherwise the SQL is prepared as usual. The statement cache
+    /// size is set by [Connection.set_stmt_cache_size][].
+    ///
+    /// There are no counters of cache hits and misses because the Oracle
+    /// client library doesn't report whether a statement was found in the
+    /// cache. The session statistic `parse count (total)` in `V$MYSTAT`
+    /// doesn't increase when a cached statement is reused.
+    ///
+    /// [Statement.close_with_tag]: struct.Statement.html#method.close_with_tag
+    /// [Connection.set_stmt_cache_size]: struct.Connection.html#method.set_stmt_cache_size
     Tag(String),
 
     /// Reserved for when scrollable cursors are supported.
@@ -163,20 +198,31 @@ impl fmt::Display for StatementType {
 }
 
 /// Statement
+///
+/// A statement is `Send` but not `Sync`. A prepared statement may be
+/// handed to a worker thread as long as the connection outlives it.
+/// It cannot be used by more than one thread at the same time.
 pub struct Statement<'conn> {
     pub(crate) conn: &'conn Connection,
     handle: *mut dpiStmt,
     pub(crate) column_info: Vec<ColumnInfo>,
     pub(crate) row: Option<Row>,
-    shared_buffer_row_index: Rc<RefCell<u32>>,
+    shared_buffer_row_index: Arc<AtomicUsize>,
     statement_type: StatementType,
     is_returning: bool,
     bind_count: usize,
     bind_names: Vec<String>,
     bind_values: Vec<SqlValue>,
     fetch_array_size: u32,
+    prefetch_rows: Option<u32>,
 }
 
+// The raw statement handle and bind values are owned by a Statement and
+// ODPI-C handles created in threaded mode may be used from any thread.
+unsafe impl<'conn> Send for Statement<'conn> {}
+
+impl<'conn> AssertSend for Statement<'conn> {}
+
 impl<'conn> Statement<'conn> {
     pub(crate) fn new(
         conn: &'conn Connection,
@@ -184,22 +230,27 @@ impl<'conn> Statement<'conn> {
         params: &[StmtParam],
     ) -> Result<Statement<'conn>> {
         let sql = to_odpi_str(sql);
-        let mut fetch_array_size = DPI_DEFAULT_FETCH_ARRAY_SIZE;
+        let mut fetch_array_size = conn.fetch_array_size;
+        let mut prefetch_rows = conn.prefetch_rows;
         let mut scrollable = 0;
-        let mut tag = new_odpi_str();
+        let mut tag = "";
         for param in params {
             match param {
                 &StmtParam::FetchArraySize(size) => {
                     fetch_array_size = size;
                 }
+                &StmtParam::PrefetchRows(rows) => {
+                    prefetch_rows = Some(rows);
+                }
                 &StmtParam::Scrollable => {
                     scrollable = 1;
                 }
                 &StmtParam::Tag(ref name) => {
-                    tag = to_odpi_str(name);
+                    tag = name;
                 }
             }
         }
+        let tag_odpi = to_odpi_str(tag);
         let mut handle: *mut dpiStmt = ptr::null_mut();
         chkerr!(
             conn.ctxt,
@@ -208,8 +259,8 @@ impl<'conn> Statement<'conn> {
                 scrollable,
                 sql.ptr,
                 sql.len,
-                tag.ptr,
-                tag.len,
+                tag_odpi.ptr,
+                tag_odpi.len,
                 &mut handle
             )
         );
@@ -245,13 +296,14 @@ impl<'conn> Statement<'conn> {
             handle: handle,
             column_info: Vec::new(),
             row: None,
-            shared_buffer_row_index: Rc::new(RefCell::new(0)),
+            shared_buffer_row_index: Arc::new(AtomicUsize::new(0)),
             statement_type: StatementType::from_enum(info.statementType),
             is_returning: info.isReturning != 0,
             bind_count: bind_count,
             bind_names: bind_names,
             bind_values: bind_values,
             fetch_array_size: fetch_array_size,
+            prefetch_rows: prefetch_rows,
         })
     }
 
@@ -260,6 +312,60 @@ impl<'conn> Statement<'conn> {
         self.close_internal("")
     }
 
+    /// Closes the statement and returns it to the statement cache
+    /// under the specified tag.
+    ///
+    /// The statement is reused when another statement is prepared with
+    /// [StmtParam::Tag][] of the same tag. The tag is ignored when
+    /// [Statement.exclude_from_cache][] was called.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use oracle::*; fn try_main() -> Result<()> {
+    /// let conn = Connection::connect("scott", "tiger", "")?;
+    /// let sql = "select ename from emp where empno = :1";
+    /// let tag = StmtParam::Tag("emp_by_empno".to_string());
+    ///
+    /// let mut stmt = conn.prepare(sql, &[tag.clone()])?;
+    /// let ename: String = stmt.query_row_as(&[&7369])?;
+    /// stmt.close_with_tag("emp_by_empno")?;
+    ///
+    /// // The statement closed above is reused.
+    /// let mut stmt = conn.prepare(sql, &[tag])?;
+    /// let ename: String = stmt.query_row_as(&[&7499])?;
+    /// stmt.close_with_tag("emp_by_empno")?;
+    /// # Ok(())} fn main() { try_main().unwrap(); }
+    /// ```
+    ///
+    /// [StmtParam::Tag]: enum.StmtParam.html#variant.Tag
+    /// [Statement.exclude_from_cache]: #method.exclude_from_cache
+    pub fn close_with_tag(&mut self, tag: &str) -> Result<()> {
+        self.close_internal(tag)
+    }
+
+    /// Excludes the statement from the statement cache.
+    ///
+    /// When the statement is closed or dropped, it is removed from
+    /// the cache instead of being returned to it. This is useful for
+    /// statements executed only once, which would otherwise push
+    /// frequently used statements out of the cache.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use oracle::*; fn try_main() -> Result<()> {
+    /// let conn = Connection::connect("scott", "tiger", "")?;
+    /// let mut stmt = conn.prepare("alter session set nls_date_format = 'YYYY-MM-DD'", &[])?;
+    /// stmt.exclude_from_cache()?;
+    /// stmt.execute(&[])?;
+    /// # Ok(())} fn main() { try_main().unwrap(); }
+    /// ```
+    pub fn exclude_from_cache(&mut self) -> Result<()> {
+        chkerr!(self.conn.ctxt, dpi_ext_dpiStmt_deleteFromCache(self.handle));
+        Ok(())
+    }
+
     fn close_internal(&mut self, tag: &str) -> Result<()> {
         let tag = to_odpi_str(tag);
 
@@ -367,6 +473,87 @@ impl<'conn> Statement<'conn> {
         rows.next().unwrap_or(Err(Error::NoDataFound))
     }
 
+    /// Fetches at most `max_rows` rows of the executed select statement
+    /// and returns them column by column. It returns `Ok(None)` when
+    /// no more rows are found and `Err` when `max_rows` is zero.
+    ///
+    /// The statement must be executed by [query][] or other query methods
+    /// in advance. Rows are fetched in units of the fetch array size
+    /// set by [StmtParam::FetchArraySize][] without creating [Row][]s,
+    /// and column values other than LOBs are read from the fetch
+    /// buffers directly. See [ColumnData][] about the types of column
+    /// values.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use oracle::*; fn try_main() -> Result<()> {
+    /// let conn = Connection::connect("scott", "tiger", "")?;
+    /// let sql = "select empno, ename from emp";
+    /// let mut stmt = conn.prepare(sql, &[StmtParam::FetchArraySize(1000)])?;
+    /// stmt.query(&[])?;
+    /// while let Some(batch) = stmt.fetch_columnar(10000)? {
+    ///     if let ColumnData::String(ref enames) = *batch.column("ename")? {
+    ///         for ename in enames {
+    ///             println!("{:?}", ename);
+    ///         }
+    ///     }
+    /// }
+    /// # Ok(())} fn main() { try_main().unwrap(); }
+    /// ```
+    ///
+    /// [query]: #method.query
+    /// [StmtParam::FetchArraySize]: enum.StmtParam.html#variant.FetchArraySize
+    /// [Row]: struct.Row.html
+    /// [ColumnData]: enum.ColumnData.html
+    pub fn fetch_columnar(&mut self, max_rows: u32) -> Result<Option<ColumnarBatch>> {
+        if max_rows == 0 {
+            return Err(Error::InvalidOperation(
+                "The `max_rows` argument of `fetch_columnar` must not be zero".to_string(),
+            ));
+        }
+        let row = match self.row {
+            Some(ref row) => row,
+            None => {
+                return Err(Error::InvalidOperation(
+                    "Could not use the `fetch_columnar` method before execution".to_string(),
+                ));
+            }
+        };
+        // The vectors grow when max_rows is larger than the fetch array size.
+        let capacity = cmp::min(max_rows, self.fetch_array_size) as usize;
+        let mut batch = ColumnarBatch::new(&self.column_info, &row.column_values, capacity)?;
+        while (batch.num_rows() as u32) < max_rows {
+            let mut buffer_row_index = 0;
+            let mut num_rows_fetched = 0;
+            let mut more_rows = 0;
+            chkerr!(
+                self.conn.ctxt,
+                dpiStmt_fetchRows(
+                    self.handle,
+                    max_rows - batch.num_rows() as u32,
+                    &mut buffer_row_index,
+                    &mut num_rows_fetched,
+                    &mut more_rows
+                )
+            );
+            batch.extend(
+                &row.column_values,
+                buffer_row_index,
+                num_rows_fetched,
+                &self.shared_buffer_row_index,
+            )?;
+            if more_rows == 0 || num_rows_fetched == 0 {
+                break;
+            }
+        }
+        if batch.num_rows() != 0 {
+            Ok(Some(batch))
+        } else {
+            Ok(None)
+        }
+    }
+
     /// Binds values by position and executes the statement.
     /// It will retunrs `Err` when the statemnet is a select statement.
     ///
@@ -474,6 +661,12 @@ impl<'conn> Statement<'conn> {
             self.conn.ctxt,
             dpiStmt_setFetchArraySize(self.handle, self.fetch_array_size)
         );
+        if let Some(rows) = self.prefetch_rows {
+            chkerr!(
+                self.conn.ctxt,
+                dpi_ext_dpiStmt_setPrefetchRows(self.handle, rows)
+            );
+        }
         chkerr!(
             self.conn.ctxt,
             dpiStmt_execute(self.handle, exec_mode, &mut num_query_columns)
@@ -486,7 +679,8 @@ impl<'conn> Statement<'conn> {
             );
             match fncode {
                 SQLFNCODE_CREATE_TYPE | SQLFNCODE_ALTER_TYPE | SQLFNCODE_DROP_TYPE => {
-                    self.conn.clear_object_type_cache()?
+                    self.conn.clear_object_type_cache()?;
+                    self.conn.object_type_cache().invalidate_all();
                 }
                 _ => (),
             }
@@ -509,14 +703,20 @@ impl<'conn> Statement<'conn> {
                         BufferRowIndex::Shared(self.shared_buffer_row_index.clone());
                     let oratype = self.column_info[i].oracle_type();
                     let oratype_i64 = OracleType::Int64;
+                    let oratype_long = OracleType::Long;
                     let oratype = match *oratype {
                         // When the column type is number whose prec is less than 18
                         // and the scale is zero, define it as int64.
+                        // Others are fetched as decimal strings, which are converted
+                        // to i128, u128 and OracleNumber without loss of precision.
                         OracleType::Number(prec, 0)
                             if 0 < prec && prec < DPI_MAX_INT64_PRECISION as u8 =>
                         {
                             &oratype_i64
                         }
+                        // XMLTYPE columns are fetched as strings. ODPI-C cannot
+                        // fetch them as objects.
+                        OracleType::Xml => &oratype_long,
                         _ => oratype,
                     };
                     val.init_handle(&self.conn.handle, oratype, self.fetch_array_size)?;
@@ -611,7 +811,11 @@ impl<'conn> Statement<'conn> {
         I: BindIndex,
     {
         let pos = bindidx.idx(&self)?;
-        if self.bind_values[pos].init_handle(&self.conn.handle, &value.oratype(self.conn)?, 1)? {
+        let oratype = value.oratype(self.conn)?;
+        if oratype == OracleType::Boolean {
+            self.conn.check_boolean_support()?;
+        }
+        if self.bind_values[pos].init_handle(&self.conn.handle, &oratype, 1)? {
             chkerr!(
                 self.conn.ctxt,
                 bindidx.bind(self.handle, self.bind_values[pos].handle)
@@ -721,7 +925,8 @@ impl<'conn> Statement<'conn> {
         let mut buffer_row_index = 0;
         if unsafe { dpiStmt_fetch(self.handle, &mut found, &mut buffer_row_index) } == 0 {
             if found != 0 {
-                *self.shared_buffer_row_index.borrow_mut() = buffer_row_index;
+                self.shared_buffer_row_index
+                    .store(buffer_row_index as usize, Ordering::Relaxed);
                 // if self.row.is_none(), dpiStmt_fetch() returns non-zero.
                 Some(Ok(self.row.as_ref().unwrap()))
             } else {
@@ -880,7 +1085,7 @@ impl ColumnInfo {
         );
         Ok(ColumnInfo {
             name: to_rust_str(info.name, info.nameLength),
-            oracle_type: OracleType::from_type_info(stmt.conn.ctxt, &info.typeInfo)?,
+            oracle_type: OracleType::from_column_type_info(stmt.conn.ctxt, &info.typeInfo)?,
             nullable: info.nullOk != 0,
         })
     }
@@ -23,6 +23,7 @@ use crate::Error;
 use crate::ParseOracleTypeError;
 use crate::Result;
 
+#[derive(Clone)]
 pub struct Scanner<'a> {
     chars: str::Chars<'a>,
     char: Option<char>,
@@ -81,6 +82,21 @@ impl<'a> Scanner<'a> {
     pub fn ndigits(&self) -> u32 {
         self.ndigits
     }
+
+    /// Consumes `s` if the rest starts with it ignoring ASCII case.
+    pub fn read_ignore_ascii_case(&mut self, s: &str) -> bool {
+        let mut chars = self.chars.clone();
+        let mut char = self.char;
+        for c in s.chars() {
+            match char {
+                Some(x) if x.eq_ignore_ascii_case(&c) => char = chars.next(),
+                _ => return false,
+            }
+        }
+        self.chars = chars;
+        self.char = char;
+        true
+    }
 }
 
 pub fn check_number_format(s: &str) -> result::Result<(), ParseOracleTypeError> {
@@ -178,7 +194,9 @@ pub fn write_literal(
             | OracleType::BLOB
             | OracleType::BFILE
             | OracleType::Long
-            | OracleType::LongRaw => {
+            | OracleType::LongRaw
+            | OracleType::Json
+            | OracleType::Xml => {
                 write!(f, "\"")?;
                 for c in s.chars() {
                     if c == '"' {
@@ -220,6 +238,14 @@ mod tests {
         s.next();
         assert_eq!(s.read_digits(), Some(4567890));
         assert_eq!(s.char(), None);
+
+        let mut s = Scanner::new("b.c. 1");
+        assert!(!s.read_ignore_ascii_case("BC"));
+        assert_eq!(s.char(), Some('b'));
+        assert!(s.read_ignore_ascii_case("B.C."));
+        assert_eq!(s.char(), Some(' '));
+        assert!(!s.read_ignore_ascii_case(" 12"));
+        assert_eq!(s.char(), Some(' '));
     }
 
     #[test]
@@ -32,3 +32,28 @@ create type &main_user..udt_issue19_obj as object (
 /
 create type &main_user..udt_issue19_col as varray(10) of float;
 /
+
+create table &main_user..TestSubObjects of &main_user..udt_SubObject;
+
+insert into &main_user..TestSubObjects values (1, 'first');
+insert into &main_user..TestSubObjects values (2, 'second');
+commit;
+
+create type &main_user..udt_BaseObject as object (
+    BaseNumberValue                     number
+) not final not instantiable;
+/
+create type &main_user..udt_DerivedObject under &main_user..udt_BaseObject (
+    DerivedStringValue                  varchar2(60)
+);
+/
+
+create table &main_user..TestXml (
+    IntCol                              number(9) not null,
+    XmlCol                              xmltype
+);
+
+create type &main_user..udt_XmlObject as object (
+    XmlValue                            xmltype
+);
+/
@@ -1,6 +1,7 @@
 mod common;
 
 use oracle::sql_type::{IntervalDS, IntervalYM, OracleType, Timestamp};
+use oracle::Error;
 
 macro_rules! test_in_out {
     ($stmt:expr, $type:ty, $val:expr) => {
@@ -34,8 +35,10 @@ fn in_out_same_values() {
     test_in_out!(stmt, u64, 123456789u64);
     test_in_out!(stmt, f32, -123.5f32);
     test_in_out!(stmt, f64, 123456789123.5f64);
-    //test_in_out!(stmt, bool, true);
-    //test_in_out!(stmt, bool, false);
+    if common::check_oracle_version("in_out_same_values(bool)", &conn, 12, 1) {
+        test_in_out!(stmt, bool, true);
+        test_in_out!(stmt, bool, false);
+    }
     test_in_out!(stmt, String, "123456789", &OracleType::Varchar2(9));
     test_in_out!(
         stmt,
@@ -52,6 +55,45 @@ fn in_out_same_values() {
     test_in_out!(stmt, IntervalYM, IntervalYM::new(10, 2));
 }
 
+#[test]
+fn plsql_boolean() {
+    let conn = common::connect().unwrap();
+    let mut stmt = conn
+        .prepare(
+            "declare
+               b boolean := (:1 = 1);
+             begin
+               b := not b;
+               :2 := case when b then 1 else 0 end;
+             end;",
+            &[],
+        )
+        .unwrap();
+    let number1 = OracleType::Number(1, 0);
+
+    // NUMBER(1) works with any Oracle version.
+    stmt.execute(&[&(&true, &number1), &(&false, &number1)])
+        .unwrap();
+    let outval: i32 = stmt.bind_value(2).unwrap();
+    assert_eq!(outval, 0);
+    stmt.execute(&[&(&false, &number1), &(&false, &number1)])
+        .unwrap();
+    let outval: i32 = stmt.bind_value(2).unwrap();
+    assert_eq!(outval, 1);
+
+    let mut stmt = conn.prepare("begin :1 := not :2; end;", &[]).unwrap();
+    if common::check_oracle_version("plsql_boolean", &conn, 12, 1) {
+        stmt.execute(&[&None::<bool>, &true]).unwrap();
+        let outval: bool = stmt.bind_value(1).unwrap();
+        assert!(!outval);
+    } else {
+        match stmt.execute(&[&None::<bool>, &true]) {
+            Err(Error::InvalidOperation(_)) => (),
+            res => panic!("unexpected result: {:?}", res),
+        }
+    }
+}
+
 macro_rules! test_to_string {
     ($stmt:expr, $val:expr) => {
         $stmt.bind(1, &OracleType::Varchar2(4000)).expect("bind(1)");
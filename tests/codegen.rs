@@ -0,0 +1,66 @@
+// Rust-oracle - Rust binding for Oracle database
+//
+// URL: https://github.com/kubo/rust-oracle
+//
+//-----------------------------------------------------------------------------
+// Copyright (c) 2017-2018 Kubo Takehiro <kubo@jiubao.org>. All rights reserved.
+// This program is free software: you can modify it and/or redistribute it
+// under the terms of:
+//
+// (i)  the Universal Permissive License v 1.0 or at your option, any
+//      later version (http://oss.oracle.com/licenses/upl); and/or
+//
+// (ii) the Apache License v 2.0. (http://www.apache.org/licenses/LICENSE-2.0)
+//-----------------------------------------------------------------------------
+
+// The generated code is checked by unit tests in src/codegen.rs.
+// This compiles it and runs it against the database.
+
+mod common;
+
+mod emp {
+    include!("codegen/emp.rs");
+}
+
+mod udt_subobject {
+    include!("codegen/udt_subobject.rs");
+}
+
+use emp::Emp;
+use oracle::sql_type::OracleNumber;
+use udt_subobject::UdtSubobject;
+
+#[test]
+fn generated_row_value() {
+    let conn = common::connect().unwrap();
+    let row: Emp = conn
+        .query_row_as("select 7369 empno, 'SMITH' ename from dual", &[])
+        .unwrap();
+    assert_eq!(
+        row,
+        Emp {
+            empno: 7369,
+            ename: Some("SMITH".to_string()),
+        }
+    );
+}
+
+#[test]
+fn generated_object() {
+    let conn = common::connect().unwrap();
+    let obj = UdtSubobject {
+        subnumbervalue: Some(OracleNumber::from(12i32)),
+        substringvalue: Some("ab".to_string()),
+    };
+    let fetched: UdtSubobject = conn.query_row_as("select :1 from dual", &[&obj]).unwrap();
+    assert_eq!(fetched, obj);
+
+    let null_attrs = UdtSubobject {
+        subnumbervalue: None,
+        substringvalue: None,
+    };
+    let fetched: UdtSubobject = conn
+        .query_row_as("select :1 from dual", &[&null_attrs])
+        .unwrap();
+    assert_eq!(fetched, null_attrs);
+}
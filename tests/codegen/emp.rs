@@ -0,0 +1,15 @@
+/// A row returned by `select empno, ename from emp`
+#[derive(Clone, Debug, PartialEq)]
+pub struct Emp {
+    pub empno: i16,
+    pub ename: Option<String>,
+}
+
+impl oracle::RowValue for Emp {
+    fn get(row: &oracle::Row) -> oracle::Result<Emp> {
+        Ok(Emp {
+            empno: row.get(0)?,
+            ename: row.get(1)?,
+        })
+    }
+}
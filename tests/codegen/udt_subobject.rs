@@ -0,0 +1,43 @@
+/// Rust type for UDT_SUBOBJECT
+#[derive(Clone, Debug, PartialEq)]
+pub struct UdtSubobject {
+    pub subnumbervalue: Option<oracle::sql_type::OracleNumber>,
+    pub substringvalue: Option<String>,
+}
+
+impl oracle::sql_type::FromSql for UdtSubobject {
+    fn from_sql(val: &oracle::SqlValue) -> oracle::Result<UdtSubobject> {
+        let obj: oracle::sql_type::Object = val.get()?;
+        Ok(UdtSubobject {
+            subnumbervalue: obj.get("SUBNUMBERVALUE")?,
+            substringvalue: obj.get("SUBSTRINGVALUE")?,
+        })
+    }
+}
+
+impl oracle::sql_type::ToSqlNull for UdtSubobject {
+    fn oratype_for_null(conn: &oracle::Connection) -> oracle::Result<oracle::sql_type::OracleType> {
+        Ok(oracle::sql_type::OracleType::Object(conn.object_type("UDT_SUBOBJECT")?))
+    }
+}
+
+impl oracle::sql_type::ToSql for UdtSubobject {
+    fn oratype(&self, conn: &oracle::Connection) -> oracle::Result<oracle::sql_type::OracleType> {
+        <UdtSubobject as oracle::sql_type::ToSqlNull>::oratype_for_null(conn)
+    }
+    fn to_sql(&self, val: &mut oracle::SqlValue) -> oracle::Result<()> {
+        let mut obj = match *val.oracle_type()? {
+            oracle::sql_type::OracleType::Object(ref objtype) => objtype.new_object()?,
+            ref oratype => {
+                return Err(oracle::Error::InvalidTypeConversion("UdtSubobject".to_string(), oratype.to_string()))
+            }
+        };
+        if let Some(ref value) = self.subnumbervalue {
+            obj.set("SUBNUMBERVALUE", value)?;
+        }
+        if let Some(ref value) = self.substringvalue {
+            obj.set("SUBSTRINGVALUE", value)?;
+        }
+        val.set(&obj)
+    }
+}
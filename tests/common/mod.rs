@@ -56,6 +56,12 @@ pub fn connect() -> Result<Connection, Error> {
     Connection::connect(&main_user(), &main_password(), &connect_string())
 }
 
+#[allow(dead_code)]
+pub fn session_stat(conn: &Connection, name: &str) -> i64 {
+    let sql = "select s.value from v$mystat s join v$statname n on s.statistic# = n.statistic# where n.name = :1";
+    conn.query_row_as(sql, &[&name]).unwrap()
+}
+
 #[allow(dead_code)]
 pub fn check_oracle_version(test_name: &str, conn: &Connection, major: i32, minor: i32) -> bool {
     let ver = Version::new(major, minor, 0, 0, 0);
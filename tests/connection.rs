@@ -15,7 +15,7 @@
 
 mod common;
 
-use oracle::{ConnStatus, Connector};
+use oracle::{ConnStatus, Connector, ErrorKind};
 
 #[test]
 fn app_context() {
@@ -145,3 +145,106 @@ fn status() {
     conn.close().unwrap();
     assert_eq!(conn.status().unwrap(), ConnStatus::Closed);
 }
+
+#[test]
+fn error_kind() {
+    let conn = common::connect().unwrap();
+    let err = conn
+        .execute(
+            "insert into TestStrings(IntCol, StringCol, RawCol, FixedCharCol) values (1, 'x', '00', 'x')",
+            &[],
+        )
+        .unwrap_err();
+    assert_eq!(err.kind(), ErrorKind::UniqueViolation);
+    assert!(err.is_unique_violation());
+    assert_eq!(err.db_error().unwrap().code(), 1);
+
+    conn.query("select * from TestStrings where IntCol = 1 for update", &[])
+        .unwrap();
+    let conn2 = common::connect().unwrap();
+    let err = conn2
+        .query(
+            "select * from TestStrings where IntCol = 1 for update nowait",
+            &[],
+        )
+        .unwrap_err();
+    assert!(err.is_resource_busy());
+    assert!(!err.is_session_dead());
+    conn.rollback().unwrap();
+
+    let err = conn
+        .query_row("select * from no_such_table", &[])
+        .unwrap_err();
+    assert_eq!(err.kind(), ErrorKind::Other);
+    assert_eq!(oracle::Error::NoDataFound.kind(), ErrorKind::Other);
+}
+
+#[cfg(feature = "r2d2")]
+#[test]
+fn r2d2_pool() {
+    use oracle::r2d2::ConnectionManager;
+    let manager = ConnectionManager::new(Connector::new(
+        common::main_user(),
+        common::main_password(),
+        common::connect_string(),
+    ));
+    let pool = r2d2::Pool::builder()
+        .max_size(2)
+        .test_on_check_out(true)
+        .build(manager)
+        .unwrap();
+    let conn = pool.get().unwrap();
+    assert_eq!(conn.status().unwrap(), ConnStatus::Normal);
+    let val = conn.query_row_as::<i32>("select 1 from dual", &[]).unwrap();
+    assert_eq!(val, 1);
+}
+
+// deadpool itself needs a Rust version newer than the crate's MSRV.
+#[cfg(feature = "deadpool")]
+#[allow(clippy::incompatible_msrv)]
+fn block_on<F: std::future::Future>(fut: F) -> F::Output {
+    use std::sync::Arc;
+    use std::task::{Context, Poll, Wake, Waker};
+    use std::thread::{self, Thread};
+
+    struct ThreadWaker(Thread);
+
+    impl Wake for ThreadWaker {
+        fn wake(self: Arc<Self>) {
+            self.0.unpark();
+        }
+    }
+
+    let waker = Waker::from(Arc::new(ThreadWaker(thread::current())));
+    let mut cx = Context::from_waker(&waker);
+    let mut fut = Box::pin(fut);
+    loop {
+        match fut.as_mut().poll(&mut cx) {
+            Poll::Ready(val) => return val,
+            Poll::Pending => thread::park(),
+        }
+    }
+}
+
+#[cfg(feature = "deadpool")]
+#[test]
+fn deadpool_pool() {
+    use oracle::deadpool::{Manager, Pool};
+    // No runtime is set, so connections are established and checked
+    // on the calling thread and no async runtime is needed here.
+    let manager = Manager::new(Connector::new(
+        common::main_user(),
+        common::main_password(),
+        common::connect_string(),
+    ));
+    let pool = Pool::builder(manager).max_size(2).build().unwrap();
+    let conn = block_on(pool.get()).unwrap();
+    assert_eq!(conn.status().unwrap(), ConnStatus::Normal);
+    let val = conn.query_row_as::<i32>("select 1 from dual", &[]).unwrap();
+    assert_eq!(val, 1);
+    drop(conn);
+    // The returned connection is checked and reused.
+    let conn = block_on(pool.get()).unwrap();
+    assert_eq!(conn.status().unwrap(), ConnStatus::Normal);
+    assert_eq!(pool.status().size, 1);
+}
@@ -15,7 +15,7 @@
 
 mod common;
 
-use oracle::sql_type::{IntervalDS, IntervalYM, OracleType, Timestamp};
+use oracle::sql_type::{IntervalDS, IntervalYM, JsonValue, OracleNumber, OracleType, Timestamp};
 use oracle::Error;
 
 macro_rules! chk_num_from {
@@ -331,6 +331,130 @@ fn numeric_to_sql() {
     chk_num_to!(stmt, usize);
 }
 
+#[test]
+fn int128_from_to_sql() {
+    let conn = common::connect().unwrap();
+    // Oracle numbers keep 38 significant digits at least.
+    let big_i128 = 12345678901234567890123456789012345678i128;
+
+    test_from_sql!(
+        &conn,
+        "cast(12345678901234567890123456789012345678 as number(38))",
+        &OracleType::Number(38, 0),
+        &big_i128
+    );
+    test_from_sql!(
+        &conn,
+        "to_number('-12345678901234567890123456789012345678')",
+        &OracleType::Number(0, 0),
+        &-big_i128
+    );
+    test_from_sql!(
+        &conn,
+        "cast(12345678901234567 as number(17))",
+        &OracleType::Number(17, 0),
+        &12345678901234567u128
+    );
+
+    let row = conn
+        .query_row("select 1e39, -1, 1.5 from dual", &[])
+        .unwrap();
+    match row.get::<usize, i128>(0) {
+        Err(Error::OutOfRange(_)) => (),
+        result => panic!("Unexpected result: {:?}", result),
+    }
+    match row.get::<usize, u128>(1) {
+        Err(Error::OutOfRange(_)) => (),
+        result => panic!("Unexpected result: {:?}", result),
+    }
+    match row.get::<usize, i128>(2) {
+        Err(Error::ParseError(_)) => (),
+        result => panic!("Unexpected result: {:?}", result),
+    }
+
+    test_to_sql!(
+        &conn,
+        &big_i128,
+        "to_char(:1)",
+        "12345678901234567890123456789012345678"
+    );
+    test_to_sql!(
+        &conn,
+        &-big_i128,
+        "to_char(:1)",
+        "-12345678901234567890123456789012345678"
+    );
+    test_to_sql!(
+        &conn,
+        &99999999999999999999999999999999999999u128,
+        "to_char(:1)",
+        "99999999999999999999999999999999999999"
+    );
+}
+
+#[test]
+fn oracle_number_from_to_sql() {
+    let conn = common::connect().unwrap();
+
+    for s in &[
+        "0",
+        "-1",
+        "12345678901234567890123456789012345678",
+        "-0.12345678901234567890123456789012345678",
+        "1.5e125",
+        "1e-130",
+    ] {
+        let num: OracleNumber = s.parse().unwrap();
+        test_from_sql!(
+            &conn,
+            &format!("to_number('{}')", s),
+            &OracleType::Number(0, 0),
+            &num
+        );
+    }
+
+    let num: OracleNumber = "12345678901234567890.123456789012345678".parse().unwrap();
+    test_to_sql!(
+        &conn,
+        &num,
+        "to_char(:1)",
+        "12345678901234567890.123456789012345678"
+    );
+    let num: OracleNumber = "-0.000123".parse().unwrap();
+    test_to_sql!(&conn, &num, "to_char(:1)", "-.000123");
+}
+
+#[test]
+fn oracle_number_bytes() {
+    let conn = common::connect().unwrap();
+    let mut stmt = conn
+        .prepare(
+            "select utl_raw.cast_from_number(to_number(:1)), utl_raw.cast_to_number(:2) from dual",
+            &[],
+        )
+        .unwrap();
+
+    for s in &[
+        "0",
+        "1",
+        "-1",
+        "123.45",
+        "-0.000123",
+        "12345678901234567890123456789012345678",
+        "-12345678901234567890123456789012345678",
+        "1e125",
+        "-1e-130",
+    ] {
+        let num: OracleNumber = s.parse().unwrap();
+        let bytes = num.to_oracle_bytes().unwrap();
+        let row = stmt.query_row(&[s, &bytes]).unwrap();
+        let (oracle_bytes, oracle_num) = row.get_as::<(Vec<u8>, OracleNumber)>().unwrap();
+        assert_eq!(oracle_bytes, bytes, "number: {}", s);
+        assert_eq!(oracle_num, num, "number: {}", s);
+        assert_eq!(OracleNumber::from_oracle_bytes(&oracle_bytes).unwrap(), num);
+    }
+}
+
 #[test]
 fn raw_from_to_sql() {
     let conn = common::connect().unwrap();
@@ -541,6 +665,189 @@ fn timestamp_to_sql() {
     );
 }
 
+#[test]
+fn timestamp_tz_region() {
+    let conn = common::connect().unwrap();
+
+    let sql = "select to_char(timestamp '2019-07-01 10:00:00.5 Europe/Madrid', \
+               'YYYY-MM-DD HH24:MI:SS.FF TZH:TZM TZR') from dual";
+    let ts = conn.query_row_as::<Timestamp>(sql, &[]).unwrap();
+    assert_eq!(
+        ts,
+        Timestamp::new(2019, 7, 1, 10, 0, 0, 500000000).and_tz_hm_offset(2, 0)
+    );
+    assert_eq!(
+        ts.tz_region().map(|s| s.to_uppercase()),
+        Some("EUROPE/MADRID".to_string())
+    );
+
+    // The region name is bound when the timestamp is bound as a string.
+    let ts = Timestamp::new(2019, 7, 1, 10, 0, 0, 0)
+        .and_tz_region("Europe/Madrid")
+        .unwrap();
+    test_to_sql!(
+        &conn,
+        &(&ts, &OracleType::Varchar2(64)),
+        "UPPER(TO_CHAR(TO_TIMESTAMP_TZ(:1, 'YYYY-MM-DD HH24:MI:SS.FF TZR'), \
+         'YYYY-MM-DD HH24:MI:SS TZH:TZM TZR'))",
+        "2019-07-01 10:00:00 +02:00 EUROPE/MADRID"
+    );
+    let ts = Timestamp::new(2019, 1, 1, 10, 0, 0, 0)
+        .and_tz_region("Europe/Madrid")
+        .unwrap();
+    test_to_sql!(
+        &conn,
+        &(&ts, &OracleType::Varchar2(64)),
+        "TO_CHAR(TO_TIMESTAMP_TZ(:1, 'YYYY-MM-DD HH24:MI:SS.FF TZR'), 'TZH:TZM')",
+        "+01:00"
+    );
+
+    // Only the offset is bound as TIMESTAMP WITH TIME ZONE.
+    let ts = Timestamp::new(2019, 7, 1, 10, 0, 0, 0)
+        .and_tz_hm_offset(2, 0)
+        .and_tz_region("Europe/Madrid")
+        .unwrap();
+    test_to_sql!(
+        &conn,
+        &ts,
+        "TO_CHAR(:1, 'YYYY-MM-DD HH24:MI:SS TZR')",
+        "2019-07-01 10:00:00 +02:00"
+    );
+}
+
+#[test]
+fn timestamp_format() {
+    let conn = common::connect().unwrap();
+    conn.execute(
+        "alter session set nls_date_language = 'AMERICAN' nls_territory = 'AMERICA'",
+        &[],
+    )
+    .unwrap();
+
+    let formats = [
+        "YYYY-MM-DD HH24:MI:SS.FF9 TZH:TZM",
+        "SYYYY Y,YYY YYY YY Y RR RRRR CC SCC AD A.D.",
+        "MON Mon mon MONTH Month FMMonth RM rm",
+        "DAY Day DY Dy D DD DDD Q W WW IW IYYY IYY IY I",
+        "HH HH12 HH24 MI SS SSSSS AM a.m. FF1 FF3 FF6",
+        "FMDD/MM/YYYY HH:MI:SS \"o'clock\"",
+    ];
+    let timestamps = [
+        Timestamp::new(2017, 8, 9, 23, 2, 3, 123456789).and_tz_hm_offset(-8, -30),
+        Timestamp::new(2021, 1, 1, 0, 5, 6, 0).and_tz_hm_offset(9, 0),
+        Timestamp::new(-44, 3, 15, 12, 0, 0, 500000000).and_tz_hm_offset(0, 0),
+        Timestamp::new(1582, 10, 15, 6, 0, 0, 0).and_tz_hm_offset(0, 0),
+    ];
+    for ts in &timestamps {
+        for fmt in &formats {
+            test_to_sql!(
+                &conn,
+                ts,
+                &format!("TO_CHAR(:1, '{}')", fmt.replace("'", "''")),
+                &ts.format(fmt).unwrap()
+            );
+        }
+    }
+
+    let fmt = "YYYY-MM-DD HH24:MI:SS.FF9 TZH:TZM";
+    for &(s, input_fmt) in &[
+        (
+            "09-AUG-2017 11:02:03.5 PM -08:30",
+            "DD-MON-YYYY HH:MI:SS.FF AM TZH:TZM",
+        ),
+        ("Wednesday, August 9, 2017", "Day, Month DD, YYYY"),
+        ("2017 221 82923", "YYYY DDD SSSSS"),
+        ("44-03-15 BC 01:00 +09:00", "YYYY-MM-DD AD HH24:MI TZR"),
+        ("17/8/9", "RR/MM/DD"),
+    ] {
+        let ts = Timestamp::parse_with_format(s, input_fmt).unwrap();
+        test_to_sql!(
+            &conn,
+            &s,
+            &format!("TO_CHAR(TO_TIMESTAMP_TZ(:1, '{}'), '{}')", input_fmt, fmt),
+            &ts.format(fmt).unwrap()
+        );
+    }
+}
+
+#[test]
+fn timestamp_julian_day() {
+    let conn = common::connect().unwrap();
+
+    for &(year, month, day) in &[
+        (-4712, 1, 1),
+        (-44, 3, 15),
+        (-1, 12, 31),
+        (1, 1, 1),
+        (1500, 2, 29),
+        (1582, 10, 4),
+        (1582, 10, 15),
+        (2000, 1, 1),
+        (9999, 12, 31),
+    ] {
+        let ts = Timestamp::try_new(year, month, day, 0, 0, 0, 0).unwrap();
+        let jdn = ts.to_julian_day();
+        assert_eq!(Timestamp::from_julian_day(jdn).unwrap(), ts);
+        test_to_sql!(&conn, &ts, "TO_CHAR(:1, 'J')", &format!("{:07}", jdn));
+        test_to_sql!(
+            &conn,
+            &ts,
+            "TO_CHAR(:1, 'SYYYY-MM-DD')",
+            &ts.format("SYYYY-MM-DD").unwrap()
+        );
+        test_from_sql!(
+            &conn,
+            &format!("TO_DATE('{}', 'J')", jdn),
+            &OracleType::Date,
+            &ts
+        );
+        if year < 0 {
+            // The alternate format uses an era indicator for years BC.
+            test_from_sql!(
+                &conn,
+                &format!(
+                    "TO_DATE('{:#}', 'YYYY-MM-DD HH24:MI:SS BC')",
+                    ts.and_prec(0)
+                ),
+                &OracleType::Date,
+                &ts
+            );
+        }
+    }
+}
+
+#[test]
+fn timestamp_ltz_session_time_zone() {
+    let conn = common::connect().unwrap();
+    // Oracle converts LTZ values to TIMESTAMP WITH TIME ZONE with the session time zone.
+    let sql = "select CAST(CAST(TO_TIMESTAMP_TZ('2012-03-04 05:06:07 +00:00', 'YYYY-MM-DD HH24:MI:SS TZH:TZM') AS TIMESTAMP(0) WITH LOCAL TIME ZONE) AS TIMESTAMP(0) WITH TIME ZONE) from dual";
+
+    conn.execute("alter session set time_zone = '+09:00'", &[])
+        .unwrap();
+    let ts = Timestamp::new(2012, 3, 4, 14, 6, 7, 0).and_tz_hm_offset(9, 0);
+    let mut stmt = conn.prepare(sql, &[]).unwrap();
+    let mut rows = stmt.query_as::<Timestamp>(&[]).unwrap();
+    assert_eq!(
+        rows.column_info()[0].oracle_type(),
+        &OracleType::TimestampTZ(0)
+    );
+    assert_eq!(rows.next().unwrap().unwrap(), ts);
+
+    conn.execute("alter session set time_zone = '-05:00'", &[])
+        .unwrap();
+    let ts = Timestamp::new(2012, 3, 4, 0, 6, 7, 0).and_tz_hm_offset(-5, 0);
+    assert_eq!(conn.query_row_as::<Timestamp>(sql, &[]).unwrap(), ts);
+
+    // LTZ values are displayed in the session time zone.
+    let ts = Timestamp::new(2012, 3, 4, 5, 6, 7, 0).and_tz_hm_offset(0, 0);
+    test_to_sql!(
+        &conn,
+        &(&ts, &OracleType::TimestampTZ(0)),
+        "TO_CHAR(CAST(:1 AS TIMESTAMP(0) WITH LOCAL TIME ZONE), 'YYYY-MM-DD HH24:MI:SS')",
+        "2012-03-04 00:06:07"
+    );
+}
+
 //
 // IntervalDS
 //
@@ -621,6 +928,75 @@ fn interval_ds_to_sql() {
     test_to_sql!(&conn, &it, "TO_CHAR(:1)", "-123456789 02:03:04.123456789");
 }
 
+#[test]
+fn interval_ds_arithmetic() {
+    let conn = common::connect().unwrap();
+
+    // an SLA window computed in Rust is bound back as an interval.
+    let window = IntervalDS::new(0, 20, 0, 0, 0) + IntervalDS::new(0, 5, 30, 0, 0) * 2;
+    test_to_sql!(
+        &conn,
+        &window,
+        "TO_CHAR(:1)",
+        "+000000001 07:00:00.000000000"
+    );
+    test_to_sql!(
+        &conn,
+        &window,
+        "TO_CHAR(:1 - INTERVAL '1 07:00:00' DAY TO SECOND)",
+        "+000000000 00:00:00.000000000"
+    );
+    test_to_sql!(
+        &conn,
+        &-window,
+        "TO_CHAR(:1)",
+        "-000000001 07:00:00.000000000"
+    );
+
+    let it: IntervalDS = conn
+        .query_row_as(
+            "select INTERVAL '20' HOUR + INTERVAL '5:30' HOUR TO MINUTE * 2 from dual",
+            &[],
+        )
+        .unwrap();
+    assert_eq!(it, window);
+
+    let it = IntervalDS::new(1, 2, 3, 4, 500000000);
+    test_to_sql!(
+        &conn,
+        &it.to_iso8601(),
+        "TO_CHAR(TO_DSINTERVAL(:1))",
+        "+000000001 02:03:04.500000000"
+    );
+    let iso: String = conn
+        .query_row_as("select '-P1DT2H3M4.5S' from dual", &[])
+        .unwrap();
+    assert_eq!(iso.parse::<IntervalDS>().unwrap(), -it);
+}
+
+#[test]
+fn duration_from_to_sql() {
+    use std::time::Duration;
+    let conn = common::connect().unwrap();
+
+    let dur = Duration::new(86400 + 7384, 123456789);
+    test_from_sql!(
+        &conn,
+        "INTERVAL '+1 02:03:04.123456789' DAY TO SECOND(9)",
+        &OracleType::IntervalDS(2, 9),
+        &dur
+    );
+    test_to_sql!(&conn, &dur, "TO_CHAR(:1)", "+000000001 02:03:04.123456789");
+
+    let err = conn
+        .query_row_as::<Duration>("select INTERVAL '-1' DAY from dual", &[])
+        .unwrap_err();
+    match err {
+        Error::OutOfRange(_) => (),
+        _ => panic!("Unexpected error: {}", err),
+    }
+}
+
 //
 // IntervalYM
 //
@@ -678,12 +1054,95 @@ fn interval_ym_to_sql() {
     test_to_sql!(&conn, &it, "TO_CHAR(:1)", "-123456789-02");
 }
 
+#[test]
+fn interval_ym_arithmetic() {
+    let conn = common::connect().unwrap();
+
+    let it = IntervalYM::new(1, 8) + IntervalYM::new(0, 5) * 2;
+    test_to_sql!(&conn, &it, "TO_CHAR(:1)", "+000000002-06");
+    test_to_sql!(&conn, &-it, "TO_CHAR(:1)", "-000000002-06");
+
+    test_to_sql!(
+        &conn,
+        &it.to_iso8601(),
+        "TO_CHAR(TO_YMINTERVAL(:1))",
+        "+000000002-06"
+    );
+    assert_eq!("P30M".parse::<IntervalYM>().unwrap(), it);
+}
+
+#[test]
+fn json_from_to_sql() {
+    let conn = common::connect().unwrap();
+
+    let text = r#"{"a":[1,2.5,null],"b":"x","c":{"d":true}}"#;
+    let json: JsonValue = text.parse().unwrap();
+    test_from_sql!(
+        &conn,
+        &format!("to_clob('{}')", text),
+        &OracleType::CLOB,
+        &json
+    );
+    assert_eq!(json.get("b"), Some(&JsonValue::String("x".to_string())));
+    test_to_sql!(&conn, &json, "json_value(:1, '$.b')", "x");
+    test_to_sql!(&conn, &json, "json_value(:1, '$.a[1]')", "2.5");
+    test_to_sql!(&conn, &json, "to_char(:1)", text);
+
+    let json = JsonValue::Object(vec![
+        (
+            "ts".to_string(),
+            JsonValue::Timestamp(Timestamp::new(2012, 3, 4, 5, 6, 7, 0)),
+        ),
+        ("bin".to_string(), JsonValue::Binary(vec![0x01, 0xab])),
+    ]);
+    test_to_sql!(
+        &conn,
+        &json,
+        "json_value(:1, '$.ts')",
+        "2012-03-04T05:06:07"
+    );
+    test_to_sql!(&conn, &json, "json_value(:1, '$.bin')", "01AB");
+
+    let err = conn
+        .query_row_as::<JsonValue>("select to_clob('{\"a\":') from dual", &[])
+        .unwrap_err();
+    match err {
+        Error::ParseError(_) => (),
+        _ => panic!("Unexpected error: {}", err),
+    }
+}
+
+#[test]
+fn xml_from_to_sql() {
+    let conn = common::connect().unwrap();
+
+    test_from_sql!(
+        &conn,
+        "xmltype('<a>1</a>')",
+        &OracleType::Xml,
+        &"<a>1</a>".to_string()
+    );
+
+    conn.execute("delete from TestXml", &[]).unwrap();
+    conn.execute(
+        "insert into TestXml values (1, :1)",
+        &[&(&"<a>2</a>", &OracleType::Xml)],
+    )
+    .unwrap();
+    let xml: String = conn
+        .query_row_as("select XmlCol from TestXml where IntCol = 1", &[])
+        .unwrap();
+    assert_eq!(xml.trim_end(), "<a>2</a>");
+    conn.rollback().unwrap();
+}
+
 #[cfg(feature = "chrono")]
 mod chrono {
     use super::common;
     use super::test_from_sql;
     use super::test_to_sql;
     use chrono::naive::NaiveDate;
+    use chrono::naive::NaiveTime;
     use chrono::prelude::*;
     use chrono::Duration;
     use oracle::sql_type::OracleType;
@@ -1033,6 +1492,69 @@ mod chrono {
         );
     }
 
+    //
+    // chrono::naive::NaiveTime
+    //
+
+    #[test]
+    fn naive_time_from_sql() {
+        let conn = common::connect().unwrap();
+
+        // DATE -> NaiveTime
+        let tm = NaiveTime::from_hms(5, 6, 7);
+        test_from_sql!(
+            &conn,
+            "TO_DATE('2012-03-04 05:06:07', 'YYYY-MM-DD HH24:MI:SS')",
+            &OracleType::Date,
+            &tm
+        );
+
+        // TIMESTAMP -> NaiveTime
+        let tm = NaiveTime::from_hms_nano(5, 6, 7, 123456789);
+        test_from_sql!(
+            &conn,
+            "TO_TIMESTAMP('2012-03-04 05:06:07.123456789', 'YYYY-MM-DD HH24:MI:SS.FF9')",
+            &OracleType::Timestamp(9),
+            &tm
+        );
+
+        // INTERVAL DAY TO SECOND -> NaiveTime
+        let tm = NaiveTime::from_hms_nano(5, 6, 7, 123456789);
+        test_from_sql!(
+            &conn,
+            "INTERVAL '0 05:06:07.123456789' DAY(0) TO SECOND(9)",
+            &OracleType::IntervalDS(0, 9),
+            &tm
+        );
+
+        let err = conn
+            .query_row_as::<NaiveTime>("select INTERVAL '1 00:00:00' DAY TO SECOND from dual", &[])
+            .unwrap_err();
+        match err {
+            Error::OutOfRange(_) => (),
+            _ => panic!("Unexpected error: {}", err),
+        }
+    }
+
+    #[test]
+    fn naive_time_to_sql() {
+        let conn = common::connect().unwrap();
+
+        // NaiveTime -> INTERVAL DAY TO SECOND
+        let tm = NaiveTime::from_hms_nano(5, 6, 7, 123456789);
+        test_to_sql!(&conn, &tm, "TO_CHAR(:1)", "+000000000 05:06:07.123456789");
+        test_to_sql!(
+            &conn,
+            &tm,
+            "TO_CHAR(DATE '2012-03-04' + :1, 'YYYY-MM-DD HH24:MI:SS')",
+            "2012-03-04 05:06:07"
+        );
+
+        // A leap second cannot be bound.
+        let tm = NaiveTime::from_hms_nano(23, 59, 59, 1_500_000_000);
+        assert!(conn.query_row("select :1 from dual", &[&tm]).is_err());
+    }
+
     //
     // chrono::Duration
     //
@@ -1119,3 +1641,347 @@ mod chrono {
         }
     }
 }
+
+#[cfg(feature = "rust_decimal")]
+mod rust_decimal {
+    use super::common;
+    use super::test_from_sql;
+    use super::test_to_sql;
+    use oracle::sql_type::OracleType;
+    use oracle::Error;
+    use rust_decimal::Decimal;
+
+    #[test]
+    fn decimal_from_to_sql() {
+        let conn = common::connect().unwrap();
+
+        let dec: Decimal = "-1234567890123456.789012345678".parse().unwrap();
+        test_from_sql!(
+            &conn,
+            "to_number('-1234567890123456.789012345678')",
+            &OracleType::Number(0, 0),
+            &dec
+        );
+        test_to_sql!(&conn, &dec, "to_char(:1)", "-1234567890123456.789012345678");
+
+        // Decimal cannot hold 38 significant digits.
+        let row = conn
+            .query_row(
+                "select to_number('0.12345678901234567890123456789012345678') from dual",
+                &[],
+            )
+            .unwrap();
+        match row.get::<usize, Decimal>(0) {
+            Err(Error::OutOfRange(_)) => (),
+            result => panic!("Unexpected result: {:?}", result),
+        }
+    }
+}
+
+#[cfg(feature = "serde_json")]
+mod serde_json {
+    use super::common;
+    use super::test_from_sql;
+    use super::test_to_sql;
+    use oracle::sql_type::OracleType;
+    use serde_json::json;
+    use serde_json::Value;
+
+    #[test]
+    fn serde_json_from_to_sql() {
+        let conn = common::connect().unwrap();
+
+        let val = json!({"a": [1, 2.5, null], "b": "x"});
+        test_from_sql!(
+            &conn,
+            r#"to_clob('{"a":[1,2.5,null],"b":"x"}')"#,
+            &OracleType::CLOB,
+            &val
+        );
+        test_to_sql!(&conn, &val, "json_value(:1, '$.b')", "x");
+        test_to_sql!(&conn, &None::<Value>, "nvl(to_char(:1), 'null')", "null");
+    }
+}
+
+#[cfg(feature = "bigdecimal")]
+mod bigdecimal {
+    use super::common;
+    use super::test_from_sql;
+    use super::test_to_sql;
+    use bigdecimal::BigDecimal;
+    use oracle::sql_type::OracleType;
+
+    #[test]
+    fn bigdecimal_from_to_sql() {
+        let conn = common::connect().unwrap();
+
+        let dec: BigDecimal = "12345678901234567890.123456789012345678".parse().unwrap();
+        test_from_sql!(
+            &conn,
+            "to_number('12345678901234567890.123456789012345678')",
+            &OracleType::Number(0, 0),
+            &dec
+        );
+        test_to_sql!(
+            &conn,
+            &dec,
+            "to_char(:1)",
+            "12345678901234567890.123456789012345678"
+        );
+    }
+}
+
+#[cfg(feature = "uuid")]
+mod uuid {
+    use super::common;
+    use super::test_from_sql;
+    use super::test_to_sql;
+    use oracle::sql_type::OracleType;
+    use oracle::Error;
+    use uuid::Uuid;
+
+    #[test]
+    fn uuid_from_to_sql() {
+        let conn = common::connect().unwrap();
+        let uuid = Uuid::parse_str("67e55044-10b1-426f-9247-bb680e5fe0c8").unwrap();
+
+        test_from_sql!(
+            &conn,
+            "hextoraw('67E5504410B1426F9247BB680E5FE0C8')",
+            &OracleType::Raw(16),
+            &uuid
+        );
+        test_from_sql!(
+            &conn,
+            "'67e5504410b1426f9247bb680e5fe0c8'",
+            &OracleType::Char(32),
+            &uuid
+        );
+        test_from_sql!(
+            &conn,
+            "'67E55044-10B1-426F-9247-BB680E5FE0C8'",
+            &OracleType::Char(36),
+            &uuid
+        );
+        test_to_sql!(
+            &conn,
+            &uuid,
+            "rawtohex(:1)",
+            "67E5504410B1426F9247BB680E5FE0C8"
+        );
+        test_to_sql!(
+            &conn,
+            &(&uuid, &OracleType::Varchar2(36)),
+            ":1",
+            "67e55044-10b1-426f-9247-bb680e5fe0c8"
+        );
+
+        let guid: Uuid = conn
+            .query_row_as("select sys_guid() from dual", &[])
+            .unwrap();
+        assert!(!guid.is_nil());
+
+        for sql in &[
+            "select hextoraw('0123456789') from dual",
+            "select '0123456789' from dual",
+        ] {
+            match conn.query_row_as::<Uuid>(sql, &[]).unwrap_err() {
+                Error::InvalidTypeConversion(_, _) => (),
+                err => panic!("Unexpected error: {}", err),
+            }
+        }
+    }
+}
+
+#[cfg(all(feature = "chrono", feature = "chrono-tz"))]
+mod chrono_tz {
+    use super::common;
+    use super::test_from_sql;
+    use super::test_to_sql;
+    use chrono::prelude::*;
+    use chrono_tz::America::New_York;
+    use chrono_tz::Europe::Madrid;
+    use chrono_tz::Tz;
+    use oracle::sql_type::OracleType;
+    use oracle::sql_type::Timestamp;
+    use oracle::Error;
+    use try_from::TryFrom;
+
+    #[test]
+    fn datetime_tz_from_sql() {
+        let conn = common::connect().unwrap();
+
+        let dttm = Madrid.ymd(2019, 7, 1).and_hms(10, 0, 0);
+        test_from_sql!(
+            &conn,
+            "TO_CHAR(TIMESTAMP '2019-07-01 10:00:00 Europe/Madrid', 'YYYY-MM-DD HH24:MI:SS TZH:TZM TZR')",
+            &OracleType::Varchar2(64),
+            &dttm
+        );
+        let dttm: DateTime<Tz> = conn
+            .query_row_as(
+                "select TO_CHAR(TIMESTAMP '2019-11-03 01:30:00 America/New_York EST', \
+                 'YYYY-MM-DD HH24:MI:SS TZH:TZM TZR') from dual",
+                &[],
+            )
+            .unwrap();
+        assert_eq!(dttm.timezone(), New_York);
+        assert_eq!(dttm.offset().fix().local_minus_utc(), -5 * 3600);
+
+        // no region name
+        let dttm = Tz::UTC.ymd(2019, 7, 1).and_hms(10, 0, 0);
+        test_from_sql!(
+            &conn,
+            "TIMESTAMP '2019-07-01 10:00:00 +00:00'",
+            &OracleType::TimestampTZ(9),
+            &dttm
+        );
+        let dttm: DateTime<Tz> = conn
+            .query_row_as(
+                "select TIMESTAMP '2019-07-01 10:00:00 +09:00' from dual",
+                &[],
+            )
+            .unwrap();
+        assert_eq!(dttm.timezone().name(), "Etc/GMT-9");
+
+        // No time zone in the tz database has a fixed offset which isn't
+        // whole hours.
+        match conn.query_row_as::<DateTime<Tz>>(
+            "select TIMESTAMP '2019-07-01 10:00:00 +05:30' from dual",
+            &[],
+        ) {
+            Err(Error::OutOfRange(_)) => (),
+            result => panic!("Unexpected result: {:?}", result),
+        }
+    }
+
+    #[test]
+    fn datetime_tz_to_timestamp() {
+        let conn = common::connect().unwrap();
+        let dttm = Madrid.ymd(2019, 7, 1).and_hms(10, 0, 0);
+        let ts = Timestamp::try_from(&dttm).unwrap();
+        assert_eq!(ts.tz_region(), Some("Europe/Madrid"));
+        assert_eq!(ts.tz_offset(), 2 * 3600);
+        test_to_sql!(
+            &conn,
+            &(&ts, &OracleType::Varchar2(64)),
+            "UPPER(TO_CHAR(TO_TIMESTAMP_TZ(:1, 'YYYY-MM-DD HH24:MI:SS.FF TZR'), 'TZR'))",
+            "EUROPE/MADRID"
+        );
+    }
+}
+
+#[cfg(feature = "time")]
+mod time {
+    use super::common;
+    use super::test_from_sql;
+    use super::test_to_sql;
+    use oracle::sql_type::OracleType;
+    use oracle::Error;
+    use time::Date;
+    use time::Duration;
+    use time::Month;
+    use time::OffsetDateTime;
+    use time::PrimitiveDateTime;
+    use time::Time;
+    use time::UtcOffset;
+
+    fn date(year: i32, month: Month, day: u8) -> Date {
+        Date::from_calendar_date(year, month, day).unwrap()
+    }
+
+    #[test]
+    fn datetime_from_to_sql() {
+        let conn = common::connect().unwrap();
+
+        let dttm = PrimitiveDateTime::new(
+            date(2012, Month::March, 4),
+            Time::from_hms_nano(5, 6, 7, 123456789).unwrap(),
+        );
+        test_from_sql!(
+            &conn,
+            "TO_TIMESTAMP('2012-03-04 05:06:07.123456789', 'YYYY-MM-DD HH24:MI:SS.FF9')",
+            &OracleType::Timestamp(9),
+            &dttm
+        );
+        test_to_sql!(
+            &conn,
+            &dttm,
+            "TO_CHAR(:1, 'YYYY-MM-DD HH24:MI:SS.FF9')",
+            "2012-03-04 05:06:07.123456789"
+        );
+
+        let dttm: OffsetDateTime = dttm.assume_offset(UtcOffset::from_hms(-8, -45, 0).unwrap());
+        test_from_sql!(&conn,
+                       "TO_TIMESTAMP_TZ('2012-03-04 05:06:07.123456789 -08:45', 'YYYY-MM-DD HH24:MI:SS.FF9 TZH:TZM')",
+                       &OracleType::TimestampTZ(9), &dttm);
+        test_to_sql!(
+            &conn,
+            &dttm,
+            "TO_CHAR(:1, 'YYYY-MM-DD HH24:MI:SS.FF9 TZH:TZM')",
+            "2012-03-04 05:06:07.123456789 -08:45"
+        );
+    }
+
+    #[test]
+    fn date_from_to_sql() {
+        let conn = common::connect().unwrap();
+
+        let dt = date(2012, Month::March, 4);
+        test_from_sql!(
+            &conn,
+            "TO_DATE('2012-03-04 05:06:07', 'YYYY-MM-DD HH24:MI:SS')",
+            &OracleType::Date,
+            &dt
+        );
+        test_to_sql!(
+            &conn,
+            &dt,
+            "TO_CHAR(:1, 'YYYY-MM-DD HH24:MI:SS.FF9')",
+            "2012-03-04 00:00:00.000000000"
+        );
+    }
+
+    #[test]
+    fn time_from_to_sql() {
+        let conn = common::connect().unwrap();
+
+        let tm = Time::from_hms(5, 6, 7).unwrap();
+        test_from_sql!(
+            &conn,
+            "TO_DATE('2012-03-04 05:06:07', 'YYYY-MM-DD HH24:MI:SS')",
+            &OracleType::Date,
+            &tm
+        );
+        let tm = Time::from_hms_nano(5, 6, 7, 123456789).unwrap();
+        test_from_sql!(
+            &conn,
+            "INTERVAL '0 05:06:07.123456789' DAY(0) TO SECOND(9)",
+            &OracleType::IntervalDS(0, 9),
+            &tm
+        );
+        test_to_sql!(&conn, &tm, "TO_CHAR(:1)", "+000000000 05:06:07.123456789");
+
+        let err = conn
+            .query_row_as::<Time>("select INTERVAL '-1' SECOND from dual", &[])
+            .unwrap_err();
+        match err {
+            Error::OutOfRange(_) => (),
+            _ => panic!("Unexpected error: {}", err),
+        }
+    }
+
+    #[test]
+    fn duration_from_to_sql() {
+        let conn = common::connect().unwrap();
+
+        let d = Duration::new(-(86400 + 2 * 3600 + 3 * 60 + 4), -123456789);
+        test_from_sql!(
+            &conn,
+            "INTERVAL '-1 02:03:04.123456789' DAY TO SECOND(9)",
+            &OracleType::IntervalDS(2, 9),
+            &d
+        );
+        test_to_sql!(&conn, &d, "TO_CHAR(:1)", "-000000001 02:03:04.123456789");
+    }
+}
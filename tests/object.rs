@@ -14,7 +14,9 @@
 //-----------------------------------------------------------------------------
 
 mod common;
-use oracle::sql_type::{Collection, FromSql, Object, OracleType, Timestamp};
+use oracle::sql_type::{
+    Collection, FromSql, Object, ObjectValue, OracleNumber, OracleType, Ref, Timestamp,
+};
 use oracle::{Result, SqlValue};
 use std::iter::Iterator;
 
@@ -152,6 +154,112 @@ fn udt_array() {
     assert_eq!(obj.get::<i32>(0).unwrap(), 12);
 }
 
+#[test]
+fn collection_iter_and_to_vec() {
+    let conn = common::connect().unwrap();
+    let objtype = conn.object_type("UDT_ARRAY").unwrap();
+    let coll = objtype.collection_from(&[10, 11, 12]).unwrap();
+    let items = coll.iter::<i32>().collect::<Result<Vec<_>>>().unwrap();
+    assert_eq!(items, vec![(0, 10), (1, 11), (2, 12)]);
+    assert_eq!(coll.to_vec::<i32>().unwrap(), vec![10, 11, 12]);
+    assert_eq!(coll.to_vec::<String>().unwrap(), vec!["10", "11", "12"]);
+
+    let coll = objtype.collection_from::<i32>(&[]).unwrap();
+    assert_eq!(coll.iter::<i32>().count(), 0);
+
+    let objtype = conn.object_type("UDT_NESTEDARRAY").unwrap();
+    let subobjtype = conn.object_type("UDT_SUBOBJECT").unwrap();
+    let mut subobjs = Vec::new();
+    for i in 0..3 {
+        let mut subobj = subobjtype.new_object().unwrap();
+        subobj.set("SUBNUMBERVALUE", &i).unwrap();
+        subobjs.push(subobj);
+    }
+    let mut coll = objtype.collection_from(&subobjs).unwrap();
+    coll.remove(1).unwrap();
+    let items = coll
+        .iter::<Object>()
+        .map(|item| {
+            let (idx, subobj) = item?;
+            Ok((idx, subobj.get::<i32>("SUBNUMBERVALUE")?))
+        })
+        .collect::<Result<Vec<_>>>()
+        .unwrap();
+    assert_eq!(items, vec![(0, 0), (2, 2)]);
+}
+
+#[test]
+fn collection_to_vec_from_sql() {
+    let conn = common::connect().unwrap();
+    let nums: Vec<i32> = conn
+        .query_row_as("select UDT_ARRAY(1, 2, 3) from dual", &[])
+        .unwrap();
+    assert_eq!(nums, vec![1, 2, 3]);
+    let nums: Vec<Option<i32>> = conn
+        .query_row_as("select UDT_ARRAY(1, null, 3) from dual", &[])
+        .unwrap();
+    assert_eq!(nums, vec![Some(1), None, Some(3)]);
+    let subobjs: Vec<Object> = conn
+        .query_row_as(
+            "select UDT_NESTEDARRAY(UDT_SUBOBJECT(1, 'a'), UDT_SUBOBJECT(2, 'b')) from dual",
+            &[],
+        )
+        .unwrap();
+    assert_eq!(subobjs.len(), 2);
+    assert_eq!(subobjs[1].get::<String>("SUBSTRINGVALUE").unwrap(), "b");
+}
+
+#[test]
+fn deep_copy_and_eq() {
+    let conn = common::connect().unwrap();
+    let subobjtype = conn.object_type("UDT_SUBOBJECT").unwrap();
+    let mut subobj = subobjtype.new_object().unwrap();
+    subobj.set("SUBNUMBERVALUE", &1).unwrap();
+
+    let mut copy = subobj.deep_copy().unwrap();
+    assert_eq!(copy, subobj);
+    assert!(copy.eq_values(&subobj).unwrap());
+    copy.set("SUBNUMBERVALUE", &2).unwrap();
+    assert_eq!(subobj.get::<i32>("SUBNUMBERVALUE").unwrap(), 1);
+    assert_ne!(copy, subobj);
+    assert!(!copy.eq_values(&subobj).unwrap());
+
+    let mut shallow = subobj.clone();
+    shallow.set("SUBNUMBERVALUE", &3).unwrap();
+    assert_eq!(subobj.get::<i32>("SUBNUMBERVALUE").unwrap(), 3);
+
+    let objtype = conn.object_type("UDT_NESTEDARRAY").unwrap();
+    let coll = objtype.collection_from(&[subobj.clone()]).unwrap();
+    let mut copy = coll.deep_copy().unwrap();
+    assert_eq!(copy, coll);
+    assert!(copy.eq_values(&coll).unwrap());
+    copy.push(&subobj).unwrap();
+    assert_eq!(coll.size().unwrap(), 1);
+    assert_ne!(copy, coll);
+    assert!(!copy.eq_values(&coll).unwrap());
+}
+
+#[test]
+fn object_ref() {
+    let conn = common::connect().unwrap();
+    let objref: Ref = conn
+        .query_row_as(
+            "select reftohex(ref(t)) from TestSubObjects t where SubNumberValue = 2",
+            &[],
+        )
+        .unwrap();
+    let obj = objref.dereference(&conn).unwrap();
+    assert_eq!(obj.get::<String>("SUBSTRINGVALUE").unwrap(), "second");
+
+    let num: i32 = conn
+        .query_row_as(
+            "select SubNumberValue from TestSubObjects t where ref(t) = hextoref(:1)",
+            &[&objref],
+        )
+        .unwrap();
+    assert_eq!(num, 2);
+}
+
 #[test]
 fn udt_object() {
     let conn = common::connect().unwrap();
@@ -233,6 +341,142 @@ fn udt_object() {
     );
 }
 
+#[test]
+fn udt_object_path() {
+    let conn = common::connect().unwrap();
+    let objtype = conn.object_type("UDT_OBJECT").unwrap();
+    let subobjtype = conn.object_type("UDT_SUBOBJECT").unwrap();
+    let objarytype = conn.object_type("UDT_OBJECTARRAY").unwrap();
+    let mut obj = objtype.new_object().unwrap();
+    let mut subobj = subobjtype.new_object().unwrap();
+    let mut objary = objarytype.new_collection().unwrap();
+    let username = common::main_user().to_uppercase();
+
+    subobj.set("SUBNUMBERVALUE", &10).unwrap();
+    subobj.set("SUBSTRINGVALUE", &"SUBSTRVAL:10").unwrap();
+    objary.push(&subobj).unwrap();
+    subobj.set("SUBNUMBERVALUE", &11).unwrap();
+    objary.push(&subobj).unwrap();
+    obj.set("NUMBERVALUE", &1).unwrap();
+    obj.set("SUBOBJECTVALUE", &subobj).unwrap();
+    obj.set("SUBOBJECTARRAY", &objary).unwrap();
+
+    assert_eq!(obj.get_path::<i32>("NUMBERVALUE").unwrap(), 1);
+    assert_eq!(
+        obj.get_path::<i32>("SUBOBJECTVALUE.SUBNUMBERVALUE")
+            .unwrap(),
+        11
+    );
+    assert_eq!(
+        obj.get_path::<String>("SUBOBJECTARRAY[0].SUBSTRINGVALUE")
+            .unwrap(),
+        "SUBSTRVAL:10"
+    );
+    assert_eq!(
+        obj.get_path::<i32>("SUBOBJECTARRAY[1].SUBNUMBERVALUE")
+            .unwrap(),
+        11
+    );
+    assert_eq!(obj.get_path::<Option<String>>("STRINGVALUE").unwrap(), None);
+
+    obj.set_path("SUBOBJECTARRAY[1].SUBNUMBERVALUE", &21)
+        .unwrap();
+    obj.set_path("SUBOBJECTVALUE.SUBSTRINGVALUE", &"PATH")
+        .unwrap();
+    assert_eq!(
+        obj.get_path::<i32>("SUBOBJECTARRAY[1].SUBNUMBERVALUE")
+            .unwrap(),
+        21
+    );
+    assert_eq!(
+        obj.get_path::<String>("SUBOBJECTVALUE.SUBSTRINGVALUE")
+            .unwrap(),
+        "PATH"
+    );
+
+    let err = obj
+        .get_path::<i32>("SUBOBJECTARRAY[1].NOSUCHATTR")
+        .unwrap_err();
+    assert_eq!(
+        err.to_string(),
+        "invalid attribute name: SUBOBJECTARRAY[1].NOSUCHATTR"
+    );
+    let err = obj
+        .get_path::<i32>("SUBOBJECTARRAY[5].SUBNUMBERVALUE")
+        .unwrap_err();
+    assert_eq!(
+        err.to_string(),
+        "out of range: no element at SUBOBJECTARRAY[5]"
+    );
+    let err = obj
+        .get_path::<i32>("NUMBERVALUE.SUBNUMBERVALUE")
+        .unwrap_err();
+    assert_eq!(
+        err.to_string(),
+        "invalid operation: NUMBERVALUE is neither an object nor a collection but NUMBER"
+    );
+    let err = obj.get_path::<i32>("SUBOBJECTVALUE[0]").unwrap_err();
+    assert_eq!(
+        err.to_string(),
+        "invalid operation: SUBOBJECTVALUE is an object, which cannot be indexed"
+    );
+    let err = obj
+        .get_path::<i32>("SUBOBJECTARRAY.SUBNUMBERVALUE")
+        .unwrap_err();
+    assert_eq!(
+        err.to_string(),
+        "invalid operation: SUBOBJECTARRAY is a collection, which doesn't have attributes"
+    );
+    let err = obj.get_path::<i32>("SUBOBJECTARRAY[0].").unwrap_err();
+    assert_eq!(
+        err.to_string(),
+        "invalid operation: invalid attribute path: SUBOBJECTARRAY[0]."
+    );
+
+    let subobj_tree = |num: i32, s: &str| {
+        ObjectValue::Object(
+            format!("{}.UDT_SUBOBJECT", username),
+            vec![
+                (
+                    "SUBNUMBERVALUE".to_string(),
+                    ObjectValue::Number(OracleNumber::from(num)),
+                ),
+                (
+                    "SUBSTRINGVALUE".to_string(),
+                    ObjectValue::String(s.to_string()),
+                ),
+            ],
+        )
+    };
+    assert_eq!(
+        obj.to_value_tree().unwrap(),
+        ObjectValue::Object(
+            format!("{}.UDT_OBJECT", username),
+            vec![
+                (
+                    "NUMBERVALUE".to_string(),
+                    ObjectValue::Number(OracleNumber::from(1))
+                ),
+                ("STRINGVALUE".to_string(), ObjectValue::Null),
+                ("FIXEDCHARVALUE".to_string(), ObjectValue::Null),
+                ("DATEVALUE".to_string(), ObjectValue::Null),
+                ("TIMESTAMPVALUE".to_string(), ObjectValue::Null),
+                ("SUBOBJECTVALUE".to_string(), subobj_tree(11, "PATH")),
+                (
+                    "SUBOBJECTARRAY".to_string(),
+                    ObjectValue::Collection(
+                        format!("{}.UDT_OBJECTARRAY", username),
+                        vec![
+                            subobj_tree(10, "SUBSTRVAL:10"),
+                            subobj_tree(21, "SUBSTRVAL:10")
+                        ]
+                    )
+                ),
+            ]
+        )
+    );
+}
+
 #[test]
 fn udt_stringlist() {
     let conn = common::connect().unwrap();
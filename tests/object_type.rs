@@ -15,8 +15,10 @@
 
 mod common;
 
-use oracle::sql_type::{ObjectType, OracleType};
-use oracle::Version;
+use oracle::codegen::{StructDef, StructSource};
+use oracle::sql_type::{ObjectType, ObjectTypeKind, OracleType};
+use oracle::{Connector, ObjectTypeCache, Version};
+use std::time::Duration;
 
 #[test]
 fn invalid_obj() {
@@ -216,6 +218,22 @@ fn pkg_testnumberarrays_udt_numberlist() {
     assert_eq!(attrs.len(), 0);
 }
 
+#[test]
+fn udt_xmlobject() {
+    let conn = common::connect().unwrap();
+    let objtype = conn.object_type("UDT_XMLOBJECT").unwrap();
+    let attrs = objtype.attributes();
+    assert_eq!(attrs[0].name(), "XMLVALUE");
+    // Only select-list columns of XMLTYPE are OracleType::Xml.
+    match attrs[0].oracle_type() {
+        OracleType::Object(attrtype) => {
+            assert_eq!(attrtype.schema(), "SYS");
+            assert_eq!(attrtype.name(), "XMLTYPE");
+        }
+        oratype => panic!("unexpected type: {}", oratype),
+    }
+}
+
 #[test]
 fn pkg_testrecords_udt_record() {
     let conn = common::connect().unwrap();
@@ -249,6 +267,139 @@ fn pkg_testrecords_udt_record() {
     assert_eq!(attrs[4].oracle_type(), &OracleType::Boolean);
 }
 
+#[test]
+fn object_type_details() {
+    let conn = common::connect().unwrap();
+    let username = common::main_user().to_uppercase();
+
+    let details = conn
+        .object_type("UDT_OBJECT")
+        .unwrap()
+        .details(&conn)
+        .unwrap();
+    assert_eq!(details.kind(), ObjectTypeKind::Object);
+    assert_eq!(details.max_size(), None);
+    assert_eq!(details.supertype(), None);
+    assert_eq!(details.subtypes().len(), 0);
+    assert!(details.is_final());
+    assert!(details.is_instantiable());
+
+    let details = conn
+        .object_type("UDT_ARRAY")
+        .unwrap()
+        .details(&conn)
+        .unwrap();
+    assert_eq!(details.kind(), ObjectTypeKind::Varray);
+    assert_eq!(details.max_size(), Some(10));
+
+    let details = conn
+        .object_type("UDT_NESTEDARRAY")
+        .unwrap()
+        .details(&conn)
+        .unwrap();
+    assert_eq!(details.kind(), ObjectTypeKind::NestedTable);
+    assert_eq!(details.max_size(), None);
+
+    let details = conn
+        .object_type("UDT_BASEOBJECT")
+        .unwrap()
+        .details(&conn)
+        .unwrap();
+    assert_eq!(details.supertype(), None);
+    assert_eq!(
+        details.subtypes(),
+        &[format!("{}.UDT_DERIVEDOBJECT", username)]
+    );
+    assert!(!details.is_final());
+    assert!(!details.is_instantiable());
+
+    let details = conn
+        .object_type("UDT_DERIVEDOBJECT")
+        .unwrap()
+        .details(&conn)
+        .unwrap();
+    assert_eq!(
+        details.supertype(),
+        Some(format!("{}.UDT_BASEOBJECT", username).as_str())
+    );
+    assert_eq!(details.subtypes().len(), 0);
+    assert!(details.is_final());
+    assert!(details.is_instantiable());
+}
+
+#[test]
+fn plsql_object_type_details() {
+    let conn = common::connect().unwrap();
+    if !common::check_oracle_version("plsql_object_type_details", &conn, 12, 1) {
+        return;
+    }
+    let username = common::main_user().to_uppercase();
+
+    let objtype = conn.object_type("PKG_TESTRECORDS.UDT_RECORD").unwrap();
+    assert_eq!(objtype.package_name(), Some("PKG_TESTRECORDS"));
+    assert_eq!(
+        objtype.to_string(),
+        format!("{}.PKG_TESTRECORDS.UDT_RECORD", username)
+    );
+    let details = objtype.details(&conn).unwrap();
+    assert_eq!(details.kind(), ObjectTypeKind::Record);
+
+    let objtype = conn
+        .object_type("PKG_TESTSTRINGARRAYS.UDT_STRINGLIST")
+        .unwrap();
+    let details = objtype.details(&conn).unwrap();
+    assert_eq!(details.kind(), ObjectTypeKind::AssociativeArray);
+    assert_eq!(details.max_size(), None);
+
+    let objtype = conn.object_type("UDT_OBJECT").unwrap();
+    assert_eq!(objtype.package_name(), None);
+}
+
+#[test]
+fn codegen_struct_def() {
+    let conn = common::connect().unwrap();
+    let username = common::main_user().to_uppercase();
+
+    let objtype = conn.object_type("UDT_SUBOBJECT").unwrap();
+    let def = StructDef::from_object_type(&objtype).unwrap();
+    assert_eq!(def.name(), "UdtSubobject");
+    assert_eq!(
+        def.source(),
+        &StructSource::ObjectType(format!("{}.UDT_SUBOBJECT", username))
+    );
+    let fields = def.fields();
+    assert_eq!(fields.len(), 2);
+    assert_eq!(fields[0].name(), "subnumbervalue");
+    assert_eq!(fields[0].oracle_name(), "SUBNUMBERVALUE");
+    assert_eq!(fields[0].rust_type(), "oracle::sql_type::OracleNumber");
+    assert!(fields[0].nullable());
+    assert_eq!(fields[1].name(), "substringvalue");
+    assert_eq!(fields[1].rust_type(), "String");
+    assert!(def
+        .to_rust_code()
+        .contains("impl oracle::sql_type::ToSql for UdtSubobject {"));
+
+    let objtype = conn.object_type("UDT_ARRAY").unwrap();
+    assert!(StructDef::from_object_type(&objtype).is_err());
+
+    let def = StructDef::from_query(
+        &conn,
+        "DataTypes",
+        "select IntCol, StringCol, NullableCol from TestStrings where 1 = 0",
+    )
+    .unwrap();
+    let fields = def.fields();
+    assert_eq!(fields[0].name(), "intcol");
+    assert_eq!(fields[0].rust_type(), "i32");
+    assert!(!fields[0].nullable());
+    assert_eq!(fields[1].rust_type(), "String");
+    assert!(!fields[1].nullable());
+    assert!(fields[2].nullable());
+    assert!(def
+        .to_rust_code()
+        .contains("impl oracle::RowValue for DataTypes {"));
+}
+
 #[test]
 fn object_type_cache() {
     let conn = common::connect().unwrap();
@@ -316,3 +467,57 @@ fn udt_issue19() {
     coll.push(&float_val).unwrap();
     assert_eq!(float_val, coll.get::<f64>(0).unwrap());
 }
+
+#[test]
+fn object_type_cache_settings() {
+    let username = common::main_user().to_uppercase();
+    let cache = ObjectTypeCache::new();
+    cache.set_max_size(Some(2));
+    let mut connector = Connector::new(
+        common::main_user(),
+        common::main_password(),
+        common::connect_string(),
+    );
+    connector.object_type_cache(cache.clone());
+    let conn1 = connector.connect().unwrap();
+    let conn2 = connector.connect().unwrap();
+    assert_eq!(conn1.object_type_cache(), &cache);
+
+    // The least recently used entry is removed.
+    conn1.object_type("UDT_OBJECT").unwrap();
+    conn1.object_type("UDT_SUBOBJECT").unwrap();
+    conn1.object_type("UDT_ARRAY").unwrap();
+    assert_eq!(conn1.object_type_cache_len(), 2);
+
+    // Invalidation affects all connections sharing the cache settings.
+    let objtype1 = conn1.object_type("UDT_SUBOBJECT").unwrap();
+    let objtype2 = conn2.object_type("UDT_SUBOBJECT").unwrap();
+    assert_eq!(conn1.object_type("UDT_SUBOBJECT").unwrap(), objtype1);
+    assert_eq!(conn2.object_type("UDT_SUBOBJECT").unwrap(), objtype2);
+    cache.invalidate_type(&username, "UDT_SUBOBJECT");
+    assert_ne!(conn1.object_type("UDT_SUBOBJECT").unwrap(), objtype1);
+    assert_ne!(conn2.object_type("UDT_SUBOBJECT").unwrap(), objtype2);
+
+    let objtype1 = conn1.object_type("UDT_SUBOBJECT").unwrap();
+    cache.invalidate_schema("OTHER_SCHEMA");
+    assert_eq!(conn1.object_type("UDT_SUBOBJECT").unwrap(), objtype1);
+    cache.invalidate_schema(&username);
+    assert_ne!(conn1.object_type("UDT_SUBOBJECT").unwrap(), objtype1);
+
+    let objtype1 = conn1.object_type("UDT_SUBOBJECT").unwrap();
+    cache.invalidate_all();
+    assert_ne!(conn1.object_type("UDT_SUBOBJECT").unwrap(), objtype1);
+
+    // Entries expire after the time-to-live.
+    cache.set_ttl(Some(Duration::from_millis(100)));
+    let objtype1 = conn1.object_type("UDT_SUBOBJECT").unwrap();
+    assert_eq!(conn1.object_type("UDT_SUBOBJECT").unwrap(), objtype1);
+    std::thread::sleep(Duration::from_millis(200));
+    assert_ne!(conn1.object_type("UDT_SUBOBJECT").unwrap(), objtype1);
+
+    // Connections not sharing the cache settings aren't affected.
+    let conn3 = common::connect().unwrap();
+    let objtype3 = conn3.object_type("UDT_SUBOBJECT").unwrap();
+    cache.invalidate_all();
+    assert_eq!(conn3.object_type("UDT_SUBOBJECT").unwrap(), objtype3);
+}
@@ -16,7 +16,7 @@
 mod common;
 
 use oracle::sql_type::{IntervalDS, Timestamp};
-use oracle::{StatementType, StmtParam};
+use oracle::{ColumnData, StatementType, StmtParam};
 
 #[test]
 fn statement_type() {
@@ -476,3 +476,169 @@ fn iterate_rows_by_ref_and_check_fused() {
     assert!(rows.next().is_none());
     assert!(rows.next().is_none());
 }
+
+#[test]
+fn stmt_cache_with_tag() {
+    let conn = common::connect().unwrap();
+    let sql = "select IntCol from TestStrings where IntCol = :1";
+    let tag = StmtParam::Tag("stmt_cache_with_tag".to_string());
+
+    conn.set_stmt_cache_size(10).unwrap();
+    // put the query of session_stat into the statement cache
+    common::session_stat(&conn, "parse count (total)");
+
+    let mut stmt = conn.prepare(sql, std::slice::from_ref(&tag)).unwrap();
+    assert_eq!(stmt.query_row_as::<i32>(&[&1]).unwrap(), 1);
+    stmt.close_with_tag("stmt_cache_with_tag").unwrap();
+    let parse_count = common::session_stat(&conn, "parse count (total)");
+
+    // The statement returned to the cache is executed without parsing.
+    let mut stmt = conn.prepare(sql, &[tag]).unwrap();
+    assert_eq!(stmt.query_row_as::<i32>(&[&2]).unwrap(), 2);
+    stmt.close_with_tag("stmt_cache_with_tag").unwrap();
+    assert_eq!(
+        common::session_stat(&conn, "parse count (total)"),
+        parse_count
+    );
+}
+
+#[test]
+fn stmt_cache_exclude() {
+    let conn = common::connect().unwrap();
+    let sql = "select IntCol from TestStrings where IntCol = :1 and 'exclude' = 'exclude'";
+
+    conn.set_stmt_cache_size(10).unwrap();
+    common::session_stat(&conn, "parse count (total)");
+
+    let mut stmt = conn.prepare(sql, &[]).unwrap();
+    stmt.exclude_from_cache().unwrap();
+    assert_eq!(stmt.query_row_as::<i32>(&[&1]).unwrap(), 1);
+    stmt.close().unwrap();
+    let parse_count = common::session_stat(&conn, "parse count (total)");
+
+    // The statement excluded from the cache is parsed again.
+    let mut stmt = conn.prepare(sql, &[]).unwrap();
+    assert_eq!(stmt.query_row_as::<i32>(&[&2]).unwrap(), 2);
+    stmt.close().unwrap();
+    assert_eq!(
+        common::session_stat(&conn, "parse count (total)"),
+        parse_count + 1
+    );
+
+    // It is cached when it isn't excluded.
+    let mut stmt = conn.prepare(sql, &[]).unwrap();
+    assert_eq!(stmt.query_row_as::<i32>(&[&3]).unwrap(), 3);
+    stmt.close().unwrap();
+    assert_eq!(
+        common::session_stat(&conn, "parse count (total)"),
+        parse_count + 1
+    );
+}
+
+#[test]
+fn fetch_columnar() {
+    let conn = common::connect().unwrap();
+    let sql = "select IntCol, StringCol, RawCol from TestStrings where IntCol between 1 and 5 order by IntCol";
+    let mut stmt = conn.prepare(sql, &[StmtParam::FetchArraySize(3)]).unwrap();
+    stmt.query(&[]).unwrap();
+    assert!(stmt.fetch_columnar(0).is_err());
+
+    let mut int_values = Vec::new();
+    let mut num_batches = 0;
+    while let Some(batch) = stmt.fetch_columnar(2).unwrap() {
+        assert!(batch.num_rows() <= 2);
+        match *batch.column(0).unwrap() {
+            ColumnData::Int64(ref vec) => int_values.extend(vec.iter().map(|v| v.unwrap())),
+            ref column => panic!("unexpected column data {:?}", column),
+        }
+        match *batch.column("stringcol").unwrap() {
+            ColumnData::String(ref vec) => assert_eq!(vec.len(), batch.num_rows()),
+            ref column => panic!("unexpected column data {:?}", column),
+        }
+        match *batch.column("RAWCOL").unwrap() {
+            ColumnData::Bytes(ref vec) => assert_eq!(vec.len(), batch.num_rows()),
+            ref column => panic!("unexpected column data {:?}", column),
+        }
+        num_batches += 1;
+    }
+    assert_eq!(int_values, vec![1, 2, 3, 4, 5]);
+    assert_eq!(num_batches, 3);
+}
+
+#[test]
+fn prefetch_rows() {
+    let mut conn = common::connect().unwrap();
+    let sql = "select * from TestStrings where IntCol >= :icol order by IntCol";
+
+    for rows in &[0, 1, 2, 20] {
+        let params = [StmtParam::FetchArraySize(3), StmtParam::PrefetchRows(*rows)];
+        let mut stmt = conn.prepare(sql, &params).unwrap();
+        for (idx, row_result) in stmt.query(&[&2]).unwrap().enumerate() {
+            let row = row_result.unwrap();
+            common::assert_test_string_row(idx + 2, &row);
+        }
+    }
+
+    assert_eq!(conn.fetch_array_size(), 100);
+    assert_eq!(conn.prefetch_rows(), None);
+    conn.set_fetch_array_size(3);
+    conn.set_prefetch_rows(Some(5));
+    assert_eq!(conn.fetch_array_size(), 3);
+    assert_eq!(conn.prefetch_rows(), Some(5));
+    let mut stmt = conn.prepare(sql, &[]).unwrap();
+    for (idx, row_result) in stmt.query(&[&2]).unwrap().enumerate() {
+        let row = row_result.unwrap();
+        common::assert_test_string_row(idx + 2, &row);
+    }
+    drop(stmt);
+    conn.set_prefetch_rows(None);
+    assert_eq!(conn.prefetch_rows(), None);
+}
+
+#[test]
+fn prefetch_rows_round_trips() {
+    let mut conn = common::connect().unwrap();
+    // Round trips are counted by another session so that they don't
+    // include ones to get the count.
+    let monitor = common::connect().unwrap();
+    let sid: String = conn
+        .query_row_as("select sys_context('userenv', 'sid') from dual", &[])
+        .unwrap();
+    let round_trips = || -> i64 {
+        monitor
+            .query_row_as(
+                "select s.value from v$sesstat s join v$statname n on s.statistic# = n.statistic# \
+                 where s.sid = :1 and n.name = 'SQL*Net roundtrips to/from client'",
+                &[&sid],
+            )
+            .unwrap()
+    };
+    let sql = "select IntCol from TestStrings where IntCol = :1";
+
+    // execution and a fetch
+    let count = round_trips();
+    let params = [StmtParam::FetchArraySize(1), StmtParam::PrefetchRows(0)];
+    let mut stmt = conn.prepare(sql, &params).unwrap();
+    assert_eq!(stmt.query_row_as::<i32>(&[&1]).unwrap(), 1);
+    drop(stmt);
+    assert_eq!(round_trips() - count, 2);
+
+    // execution with a prefetched row
+    let count = round_trips();
+    let params = [StmtParam::FetchArraySize(1), StmtParam::PrefetchRows(1)];
+    let mut stmt = conn.prepare(sql, &params).unwrap();
+    assert_eq!(stmt.query_row_as::<i32>(&[&1]).unwrap(), 1);
+    drop(stmt);
+    assert_eq!(round_trips() - count, 1);
+
+    // query_row methods prefetch rows by default.
+    let count = round_trips();
+    assert_eq!(conn.query_row_as::<i32>(sql, &[&1]).unwrap(), 1);
+    assert_eq!(round_trips() - count, 1);
+
+    // The connection-level setting is used by query_row methods.
+    conn.set_prefetch_rows(Some(0));
+    let count = round_trips();
+    assert_eq!(conn.query_row_as::<i32>(sql, &[&1]).unwrap(), 1);
+    assert_eq!(round_trips() - count, 2);
+}